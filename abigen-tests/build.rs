@@ -1,4 +1,4 @@
-use substreams_ethereum::Abigen;
+use substreams_ethereum::{Abigen, AbigenSet};
 
 fn main() -> Result<(), anyhow::Error> {
     let abis = vec!["tests"];
@@ -9,9 +9,46 @@ fn main() -> Result<(), anyhow::Error> {
         let out_path = format!("src/abi/{}.rs", abi);
 
         Abigen::new(abi, &in_path)?
+            .enum_variants(
+                "ItemType",
+                &[
+                    "NATIVE",
+                    "ERC20",
+                    "ERC721",
+                    "ERC1155",
+                    "ERC721_WITH_CRITERIA",
+                    "ERC1155_WITH_CRITERIA",
+                ],
+            )
+            .borrowed_events(true)
             .generate()?
             .write_to_file(&out_path)?;
     }
 
+    let erc721 = Abigen::new("erc721", "abi/erc721.json")?
+        .typed_scalars(true)
+        .signature_overload_names(true)
+        .alias(
+            "safeTransferFrom(address,address,uint256,bytes)",
+            "safeTransferFromWithData",
+        )
+        .serde(true)
+        .prost(true)
+        .proto("erc721.v1")
+        .generate()?;
+    erc721.write_to_file("src/abi/erc721.rs")?;
+    erc721.write_proto_to_file("proto/erc721.proto")?;
+
+    // Foundry artifact, the ABI is nested under `abi` next to the bytecode
+    Abigen::new("ownable", "abi/ownable.json")?
+        .generate()?
+        .write_to_file("src/abi/ownable.rs")?;
+
+    // Every ABI of the directory, the events they have in common are generated once
+    AbigenSet::new("abi/set")?
+        .configure(|_, abigen| abigen.borrowed_events(true))
+        .generate()?
+        .write_to_dir("src/abi/set")?;
+
     Ok(())
 }
@@ -0,0 +1,2923 @@
+    #[allow(unused_imports)]
+    pub use self::functions::Erc721;
+    /// Contract's functions.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod functions {
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct Approve {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub to: substreams_ethereum::scalar::Address,
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub token_id: substreams::scalar::BigInt,
+        }
+        impl Approve {
+            pub const METHOD_ID: [u8; 4] = [9u8, 94u8, 167u8, 179u8];
+            pub const SIGNATURE: &'static str = "approve(address,uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["to", "tokenId"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["address", "uint256"];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    to: substreams_ethereum::scalar::Address(decoder.address(0usize)?),
+                    token_id: decoder.big_uint(32usize)?,
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    64usize,
+                    |encoder| {
+                        encoder.address(self.to.as_bytes());
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_uint(&self.token_id),
+                            );
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+        }
+        impl substreams_ethereum::Function for Approve {
+            const NAME: &'static str = "approve";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct BalanceOf {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub owner: substreams_ethereum::scalar::Address,
+        }
+        impl BalanceOf {
+            pub const METHOD_ID: [u8; 4] = [112u8, 160u8, 130u8, 49u8];
+            pub const SIGNATURE: &'static str = "balanceOf(address)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["owner"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["address"];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    owner: substreams_ethereum::scalar::Address(decoder.address(0usize)?),
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder.address(self.owner.as_bytes());
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn output_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                Self::output(call.return_data.as_ref())
+            }
+            pub fn output(
+                data: &[u8],
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                decoder.big_uint(0usize)
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+            pub fn call(&self, address: Vec<u8>) -> Option<substreams::scalar::BigInt> {
+                use substreams_ethereum::pb::eth::rpc;
+                let rpc_calls = rpc::RpcCalls {
+                    calls: vec![
+                        rpc::RpcCall { to_addr : address, data : self.encode(), }
+                    ],
+                };
+                let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+                let response = responses
+                    .get(0)
+                    .expect("one response should have existed");
+                if response.failed {
+                    return None;
+                }
+                match Self::output(response.raw.as_ref()) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        use substreams_ethereum::Function;
+                        substreams::log::info!(
+                            "Call output for function `{}` failed to decode with error: {}",
+                            Self::NAME, err
+                        );
+                        None
+                    }
+                }
+            }
+        }
+        impl substreams_ethereum::Function for BalanceOf {
+            const NAME: &'static str = "balanceOf";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        impl substreams_ethereum::rpc::RPCDecodable<substreams::scalar::BigInt>
+        for BalanceOf {
+            fn output(
+                data: &[u8],
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                Self::output(data)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct GetApproved {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub token_id: substreams::scalar::BigInt,
+        }
+        impl GetApproved {
+            pub const METHOD_ID: [u8; 4] = [8u8, 24u8, 18u8, 252u8];
+            pub const SIGNATURE: &'static str = "getApproved(uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["tokenId"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["uint256"];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    token_id: decoder.big_uint(0usize)?,
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_uint(&self.token_id),
+                            );
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn output_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<
+                substreams_ethereum::scalar::Address,
+                substreams_ethereum::DecodeError,
+            > {
+                Self::output(call.return_data.as_ref())
+            }
+            pub fn output(
+                data: &[u8],
+            ) -> Result<
+                substreams_ethereum::scalar::Address,
+                substreams_ethereum::DecodeError,
+            > {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                Ok(substreams_ethereum::scalar::Address(decoder.address(0usize)?))
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+            pub fn call(
+                &self,
+                address: Vec<u8>,
+            ) -> Option<substreams_ethereum::scalar::Address> {
+                use substreams_ethereum::pb::eth::rpc;
+                let rpc_calls = rpc::RpcCalls {
+                    calls: vec![
+                        rpc::RpcCall { to_addr : address, data : self.encode(), }
+                    ],
+                };
+                let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+                let response = responses
+                    .get(0)
+                    .expect("one response should have existed");
+                if response.failed {
+                    return None;
+                }
+                match Self::output(response.raw.as_ref()) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        use substreams_ethereum::Function;
+                        substreams::log::info!(
+                            "Call output for function `{}` failed to decode with error: {}",
+                            Self::NAME, err
+                        );
+                        None
+                    }
+                }
+            }
+        }
+        impl substreams_ethereum::Function for GetApproved {
+            const NAME: &'static str = "getApproved";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        impl substreams_ethereum::rpc::RPCDecodable<substreams_ethereum::scalar::Address>
+        for GetApproved {
+            fn output(
+                data: &[u8],
+            ) -> Result<
+                substreams_ethereum::scalar::Address,
+                substreams_ethereum::DecodeError,
+            > {
+                Self::output(data)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct IsApprovedForAll {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub owner: substreams_ethereum::scalar::Address,
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub operator: substreams_ethereum::scalar::Address,
+        }
+        impl IsApprovedForAll {
+            pub const METHOD_ID: [u8; 4] = [233u8, 133u8, 233u8, 197u8];
+            pub const SIGNATURE: &'static str = "isApprovedForAll(address,address)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["owner", "operator"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["address", "address"];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    owner: substreams_ethereum::scalar::Address(
+                        decoder.address(0usize)?,
+                    ),
+                    operator: substreams_ethereum::scalar::Address(
+                        decoder.address(32usize)?,
+                    ),
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    64usize,
+                    |encoder| {
+                        encoder.address(self.owner.as_bytes());
+                        encoder.address(self.operator.as_bytes());
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn output_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<bool, substreams_ethereum::DecodeError> {
+                Self::output(call.return_data.as_ref())
+            }
+            pub fn output(
+                data: &[u8],
+            ) -> Result<bool, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                decoder.bool(0usize)
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+            pub fn call(&self, address: Vec<u8>) -> Option<bool> {
+                use substreams_ethereum::pb::eth::rpc;
+                let rpc_calls = rpc::RpcCalls {
+                    calls: vec![
+                        rpc::RpcCall { to_addr : address, data : self.encode(), }
+                    ],
+                };
+                let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+                let response = responses
+                    .get(0)
+                    .expect("one response should have existed");
+                if response.failed {
+                    return None;
+                }
+                match Self::output(response.raw.as_ref()) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        use substreams_ethereum::Function;
+                        substreams::log::info!(
+                            "Call output for function `{}` failed to decode with error: {}",
+                            Self::NAME, err
+                        );
+                        None
+                    }
+                }
+            }
+        }
+        impl substreams_ethereum::Function for IsApprovedForAll {
+            const NAME: &'static str = "isApprovedForAll";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        impl substreams_ethereum::rpc::RPCDecodable<bool> for IsApprovedForAll {
+            fn output(data: &[u8]) -> Result<bool, substreams_ethereum::DecodeError> {
+                Self::output(data)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct Name {}
+        impl Name {
+            pub const METHOD_ID: [u8; 4] = [6u8, 253u8, 222u8, 3u8];
+            pub const SIGNATURE: &'static str = "name()";
+            pub const PARAM_NAMES: &'static [&'static str] = &[];
+            pub const PARAM_TYPES: &'static [&'static str] = &[];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Ok(Self {})
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = Vec::<u8>::new();
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn output_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<String, substreams_ethereum::DecodeError> {
+                Self::output(call.return_data.as_ref())
+            }
+            pub fn output(
+                data: &[u8],
+            ) -> Result<String, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                decoder.string(0usize)
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+            pub fn call(&self, address: Vec<u8>) -> Option<String> {
+                use substreams_ethereum::pb::eth::rpc;
+                let rpc_calls = rpc::RpcCalls {
+                    calls: vec![
+                        rpc::RpcCall { to_addr : address, data : self.encode(), }
+                    ],
+                };
+                let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+                let response = responses
+                    .get(0)
+                    .expect("one response should have existed");
+                if response.failed {
+                    return None;
+                }
+                match Self::output(response.raw.as_ref()) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        use substreams_ethereum::Function;
+                        substreams::log::info!(
+                            "Call output for function `{}` failed to decode with error: {}",
+                            Self::NAME, err
+                        );
+                        None
+                    }
+                }
+            }
+        }
+        impl substreams_ethereum::Function for Name {
+            const NAME: &'static str = "name";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        impl substreams_ethereum::rpc::RPCDecodable<String> for Name {
+            fn output(data: &[u8]) -> Result<String, substreams_ethereum::DecodeError> {
+                Self::output(data)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct OwnerOf {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub token_id: substreams::scalar::BigInt,
+        }
+        impl OwnerOf {
+            pub const METHOD_ID: [u8; 4] = [99u8, 82u8, 33u8, 30u8];
+            pub const SIGNATURE: &'static str = "ownerOf(uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["tokenId"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["uint256"];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    token_id: decoder.big_uint(0usize)?,
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_uint(&self.token_id),
+                            );
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn output_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<
+                substreams_ethereum::scalar::Address,
+                substreams_ethereum::DecodeError,
+            > {
+                Self::output(call.return_data.as_ref())
+            }
+            pub fn output(
+                data: &[u8],
+            ) -> Result<
+                substreams_ethereum::scalar::Address,
+                substreams_ethereum::DecodeError,
+            > {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                Ok(substreams_ethereum::scalar::Address(decoder.address(0usize)?))
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+            pub fn call(
+                &self,
+                address: Vec<u8>,
+            ) -> Option<substreams_ethereum::scalar::Address> {
+                use substreams_ethereum::pb::eth::rpc;
+                let rpc_calls = rpc::RpcCalls {
+                    calls: vec![
+                        rpc::RpcCall { to_addr : address, data : self.encode(), }
+                    ],
+                };
+                let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+                let response = responses
+                    .get(0)
+                    .expect("one response should have existed");
+                if response.failed {
+                    return None;
+                }
+                match Self::output(response.raw.as_ref()) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        use substreams_ethereum::Function;
+                        substreams::log::info!(
+                            "Call output for function `{}` failed to decode with error: {}",
+                            Self::NAME, err
+                        );
+                        None
+                    }
+                }
+            }
+        }
+        impl substreams_ethereum::Function for OwnerOf {
+            const NAME: &'static str = "ownerOf";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        impl substreams_ethereum::rpc::RPCDecodable<substreams_ethereum::scalar::Address>
+        for OwnerOf {
+            fn output(
+                data: &[u8],
+            ) -> Result<
+                substreams_ethereum::scalar::Address,
+                substreams_ethereum::DecodeError,
+            > {
+                Self::output(data)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct SafeTransferFromAddressAddressUint256 {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub from: substreams_ethereum::scalar::Address,
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub to: substreams_ethereum::scalar::Address,
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub token_id: substreams::scalar::BigInt,
+        }
+        impl SafeTransferFromAddressAddressUint256 {
+            pub const METHOD_ID: [u8; 4] = [66u8, 132u8, 46u8, 14u8];
+            pub const SIGNATURE: &'static str = "safeTransferFrom(address,address,uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["from", "to", "tokenId"];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "address",
+                "address",
+                "uint256",
+            ];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    from: substreams_ethereum::scalar::Address(decoder.address(0usize)?),
+                    to: substreams_ethereum::scalar::Address(decoder.address(32usize)?),
+                    token_id: decoder.big_uint(64usize)?,
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    96usize,
+                    |encoder| {
+                        encoder.address(self.from.as_bytes());
+                        encoder.address(self.to.as_bytes());
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_uint(&self.token_id),
+                            );
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+        }
+        impl substreams_ethereum::Function for SafeTransferFromAddressAddressUint256 {
+            const NAME: &'static str = "safeTransferFromAddressAddressUint256";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct SafeTransferFromWithData {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub from: substreams_ethereum::scalar::Address,
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub to: substreams_ethereum::scalar::Address,
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub token_id: substreams::scalar::BigInt,
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub data: Vec<u8>,
+        }
+        impl SafeTransferFromWithData {
+            pub const METHOD_ID: [u8; 4] = [184u8, 141u8, 79u8, 222u8];
+            pub const SIGNATURE: &'static str = "safeTransferFrom(address,address,uint256,bytes)";
+            pub const PARAM_NAMES: &'static [&'static str] = &[
+                "from",
+                "to",
+                "tokenId",
+                "data",
+            ];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "address",
+                "address",
+                "uint256",
+                "bytes",
+            ];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    from: substreams_ethereum::scalar::Address(decoder.address(0usize)?),
+                    to: substreams_ethereum::scalar::Address(decoder.address(32usize)?),
+                    token_id: decoder.big_uint(64usize)?,
+                    data: decoder.bytes(96usize)?.to_vec(),
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    128usize,
+                    |encoder| {
+                        encoder.address(self.from.as_bytes());
+                        encoder.address(self.to.as_bytes());
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_uint(&self.token_id),
+                            );
+                        encoder.bytes(self.data.as_slice());
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+        }
+        impl substreams_ethereum::Function for SafeTransferFromWithData {
+            const NAME: &'static str = "safeTransferFromWithData";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct SetApprovalForAll {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub operator: substreams_ethereum::scalar::Address,
+            pub approved: bool,
+        }
+        impl SetApprovalForAll {
+            pub const METHOD_ID: [u8; 4] = [162u8, 44u8, 180u8, 101u8];
+            pub const SIGNATURE: &'static str = "setApprovalForAll(address,bool)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["operator", "_approved"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["address", "bool"];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    operator: substreams_ethereum::scalar::Address(
+                        decoder.address(0usize)?,
+                    ),
+                    approved: decoder.bool(32usize)?,
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    64usize,
+                    |encoder| {
+                        encoder.address(self.operator.as_bytes());
+                        encoder.bool(self.approved);
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+        }
+        impl substreams_ethereum::Function for SetApprovalForAll {
+            const NAME: &'static str = "setApprovalForAll";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct SupportsInterface {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub interface_id: [u8; 4usize],
+        }
+        impl SupportsInterface {
+            pub const METHOD_ID: [u8; 4] = [1u8, 255u8, 201u8, 167u8];
+            pub const SIGNATURE: &'static str = "supportsInterface(bytes4)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["interfaceId"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["bytes4"];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    interface_id: decoder.fixed_bytes::<4usize>(0usize)?,
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder.fixed_bytes(self.interface_id.as_slice());
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn output_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<bool, substreams_ethereum::DecodeError> {
+                Self::output(call.return_data.as_ref())
+            }
+            pub fn output(
+                data: &[u8],
+            ) -> Result<bool, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                decoder.bool(0usize)
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+            pub fn call(&self, address: Vec<u8>) -> Option<bool> {
+                use substreams_ethereum::pb::eth::rpc;
+                let rpc_calls = rpc::RpcCalls {
+                    calls: vec![
+                        rpc::RpcCall { to_addr : address, data : self.encode(), }
+                    ],
+                };
+                let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+                let response = responses
+                    .get(0)
+                    .expect("one response should have existed");
+                if response.failed {
+                    return None;
+                }
+                match Self::output(response.raw.as_ref()) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        use substreams_ethereum::Function;
+                        substreams::log::info!(
+                            "Call output for function `{}` failed to decode with error: {}",
+                            Self::NAME, err
+                        );
+                        None
+                    }
+                }
+            }
+        }
+        impl substreams_ethereum::Function for SupportsInterface {
+            const NAME: &'static str = "supportsInterface";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        impl substreams_ethereum::rpc::RPCDecodable<bool> for SupportsInterface {
+            fn output(data: &[u8]) -> Result<bool, substreams_ethereum::DecodeError> {
+                Self::output(data)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct Symbol {}
+        impl Symbol {
+            pub const METHOD_ID: [u8; 4] = [149u8, 216u8, 155u8, 65u8];
+            pub const SIGNATURE: &'static str = "symbol()";
+            pub const PARAM_NAMES: &'static [&'static str] = &[];
+            pub const PARAM_TYPES: &'static [&'static str] = &[];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Ok(Self {})
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = Vec::<u8>::new();
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn output_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<String, substreams_ethereum::DecodeError> {
+                Self::output(call.return_data.as_ref())
+            }
+            pub fn output(
+                data: &[u8],
+            ) -> Result<String, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                decoder.string(0usize)
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+            pub fn call(&self, address: Vec<u8>) -> Option<String> {
+                use substreams_ethereum::pb::eth::rpc;
+                let rpc_calls = rpc::RpcCalls {
+                    calls: vec![
+                        rpc::RpcCall { to_addr : address, data : self.encode(), }
+                    ],
+                };
+                let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+                let response = responses
+                    .get(0)
+                    .expect("one response should have existed");
+                if response.failed {
+                    return None;
+                }
+                match Self::output(response.raw.as_ref()) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        use substreams_ethereum::Function;
+                        substreams::log::info!(
+                            "Call output for function `{}` failed to decode with error: {}",
+                            Self::NAME, err
+                        );
+                        None
+                    }
+                }
+            }
+        }
+        impl substreams_ethereum::Function for Symbol {
+            const NAME: &'static str = "symbol";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        impl substreams_ethereum::rpc::RPCDecodable<String> for Symbol {
+            fn output(data: &[u8]) -> Result<String, substreams_ethereum::DecodeError> {
+                Self::output(data)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct TokenByIndex {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub index: substreams::scalar::BigInt,
+        }
+        impl TokenByIndex {
+            pub const METHOD_ID: [u8; 4] = [79u8, 108u8, 204u8, 231u8];
+            pub const SIGNATURE: &'static str = "tokenByIndex(uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["index"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["uint256"];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    index: decoder.big_uint(0usize)?,
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_uint(&self.index),
+                            );
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn output_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                Self::output(call.return_data.as_ref())
+            }
+            pub fn output(
+                data: &[u8],
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                decoder.big_uint(0usize)
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+            pub fn call(&self, address: Vec<u8>) -> Option<substreams::scalar::BigInt> {
+                use substreams_ethereum::pb::eth::rpc;
+                let rpc_calls = rpc::RpcCalls {
+                    calls: vec![
+                        rpc::RpcCall { to_addr : address, data : self.encode(), }
+                    ],
+                };
+                let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+                let response = responses
+                    .get(0)
+                    .expect("one response should have existed");
+                if response.failed {
+                    return None;
+                }
+                match Self::output(response.raw.as_ref()) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        use substreams_ethereum::Function;
+                        substreams::log::info!(
+                            "Call output for function `{}` failed to decode with error: {}",
+                            Self::NAME, err
+                        );
+                        None
+                    }
+                }
+            }
+        }
+        impl substreams_ethereum::Function for TokenByIndex {
+            const NAME: &'static str = "tokenByIndex";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        impl substreams_ethereum::rpc::RPCDecodable<substreams::scalar::BigInt>
+        for TokenByIndex {
+            fn output(
+                data: &[u8],
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                Self::output(data)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct TokenOfOwnerByIndex {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub owner: substreams_ethereum::scalar::Address,
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub index: substreams::scalar::BigInt,
+        }
+        impl TokenOfOwnerByIndex {
+            pub const METHOD_ID: [u8; 4] = [47u8, 116u8, 92u8, 89u8];
+            pub const SIGNATURE: &'static str = "tokenOfOwnerByIndex(address,uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["owner", "index"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["address", "uint256"];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    owner: substreams_ethereum::scalar::Address(
+                        decoder.address(0usize)?,
+                    ),
+                    index: decoder.big_uint(32usize)?,
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    64usize,
+                    |encoder| {
+                        encoder.address(self.owner.as_bytes());
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_uint(&self.index),
+                            );
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn output_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                Self::output(call.return_data.as_ref())
+            }
+            pub fn output(
+                data: &[u8],
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                decoder.big_uint(0usize)
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+            pub fn call(&self, address: Vec<u8>) -> Option<substreams::scalar::BigInt> {
+                use substreams_ethereum::pb::eth::rpc;
+                let rpc_calls = rpc::RpcCalls {
+                    calls: vec![
+                        rpc::RpcCall { to_addr : address, data : self.encode(), }
+                    ],
+                };
+                let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+                let response = responses
+                    .get(0)
+                    .expect("one response should have existed");
+                if response.failed {
+                    return None;
+                }
+                match Self::output(response.raw.as_ref()) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        use substreams_ethereum::Function;
+                        substreams::log::info!(
+                            "Call output for function `{}` failed to decode with error: {}",
+                            Self::NAME, err
+                        );
+                        None
+                    }
+                }
+            }
+        }
+        impl substreams_ethereum::Function for TokenOfOwnerByIndex {
+            const NAME: &'static str = "tokenOfOwnerByIndex";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        impl substreams_ethereum::rpc::RPCDecodable<substreams::scalar::BigInt>
+        for TokenOfOwnerByIndex {
+            fn output(
+                data: &[u8],
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                Self::output(data)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct TokenUri {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub token_id: substreams::scalar::BigInt,
+        }
+        impl TokenUri {
+            pub const METHOD_ID: [u8; 4] = [200u8, 123u8, 86u8, 221u8];
+            pub const SIGNATURE: &'static str = "tokenURI(uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["tokenId"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["uint256"];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    token_id: decoder.big_uint(0usize)?,
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_uint(&self.token_id),
+                            );
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn output_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<String, substreams_ethereum::DecodeError> {
+                Self::output(call.return_data.as_ref())
+            }
+            pub fn output(
+                data: &[u8],
+            ) -> Result<String, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                decoder.string(0usize)
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+            pub fn call(&self, address: Vec<u8>) -> Option<String> {
+                use substreams_ethereum::pb::eth::rpc;
+                let rpc_calls = rpc::RpcCalls {
+                    calls: vec![
+                        rpc::RpcCall { to_addr : address, data : self.encode(), }
+                    ],
+                };
+                let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+                let response = responses
+                    .get(0)
+                    .expect("one response should have existed");
+                if response.failed {
+                    return None;
+                }
+                match Self::output(response.raw.as_ref()) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        use substreams_ethereum::Function;
+                        substreams::log::info!(
+                            "Call output for function `{}` failed to decode with error: {}",
+                            Self::NAME, err
+                        );
+                        None
+                    }
+                }
+            }
+        }
+        impl substreams_ethereum::Function for TokenUri {
+            const NAME: &'static str = "tokenURI";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        impl substreams_ethereum::rpc::RPCDecodable<String> for TokenUri {
+            fn output(data: &[u8]) -> Result<String, substreams_ethereum::DecodeError> {
+                Self::output(data)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct TotalSupply {}
+        impl TotalSupply {
+            pub const METHOD_ID: [u8; 4] = [24u8, 22u8, 13u8, 221u8];
+            pub const SIGNATURE: &'static str = "totalSupply()";
+            pub const PARAM_NAMES: &'static [&'static str] = &[];
+            pub const PARAM_TYPES: &'static [&'static str] = &[];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Ok(Self {})
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = Vec::<u8>::new();
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn output_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                Self::output(call.return_data.as_ref())
+            }
+            pub fn output(
+                data: &[u8],
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                decoder.big_uint(0usize)
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+            pub fn call(&self, address: Vec<u8>) -> Option<substreams::scalar::BigInt> {
+                use substreams_ethereum::pb::eth::rpc;
+                let rpc_calls = rpc::RpcCalls {
+                    calls: vec![
+                        rpc::RpcCall { to_addr : address, data : self.encode(), }
+                    ],
+                };
+                let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+                let response = responses
+                    .get(0)
+                    .expect("one response should have existed");
+                if response.failed {
+                    return None;
+                }
+                match Self::output(response.raw.as_ref()) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        use substreams_ethereum::Function;
+                        substreams::log::info!(
+                            "Call output for function `{}` failed to decode with error: {}",
+                            Self::NAME, err
+                        );
+                        None
+                    }
+                }
+            }
+        }
+        impl substreams_ethereum::Function for TotalSupply {
+            const NAME: &'static str = "totalSupply";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        impl substreams_ethereum::rpc::RPCDecodable<substreams::scalar::BigInt>
+        for TotalSupply {
+            fn output(
+                data: &[u8],
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                Self::output(data)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct TransferFrom {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub from: substreams_ethereum::scalar::Address,
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub to: substreams_ethereum::scalar::Address,
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub token_id: substreams::scalar::BigInt,
+        }
+        impl TransferFrom {
+            pub const METHOD_ID: [u8; 4] = [35u8, 184u8, 114u8, 221u8];
+            pub const SIGNATURE: &'static str = "transferFrom(address,address,uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["from", "to", "tokenId"];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "address",
+                "address",
+                "uint256",
+            ];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    from: substreams_ethereum::scalar::Address(decoder.address(0usize)?),
+                    to: substreams_ethereum::scalar::Address(decoder.address(32usize)?),
+                    token_id: decoder.big_uint(64usize)?,
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    96usize,
+                    |encoder| {
+                        encoder.address(self.from.as_bytes());
+                        encoder.address(self.to.as_bytes());
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_uint(&self.token_id),
+                            );
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+        }
+        impl substreams_ethereum::Function for TransferFrom {
+            const NAME: &'static str = "transferFrom";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        /// Any of the contract's functions, see [Functions::match_and_decode].
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Functions {
+            Approve(Approve),
+            BalanceOf(BalanceOf),
+            GetApproved(GetApproved),
+            IsApprovedForAll(IsApprovedForAll),
+            Name(Name),
+            OwnerOf(OwnerOf),
+            SafeTransferFromAddressAddressUint256(SafeTransferFromAddressAddressUint256),
+            SafeTransferFromWithData(SafeTransferFromWithData),
+            SetApprovalForAll(SetApprovalForAll),
+            SupportsInterface(SupportsInterface),
+            Symbol(Symbol),
+            TokenByIndex(TokenByIndex),
+            TokenOfOwnerByIndex(TokenOfOwnerByIndex),
+            TokenUri(TokenUri),
+            TotalSupply(TotalSupply),
+            TransferFrom(TransferFrom),
+        }
+        impl Functions {
+            /// Decodes `call` into the function whose method id is the first 4 bytes of the call input.
+            pub fn match_and_decode(
+                call: impl AsRef<substreams_ethereum::pb::eth::v2::Call>,
+            ) -> Option<Self> {
+                use substreams_ethereum::Function;
+                let call = call.as_ref();
+                let method_id = <[u8; 4] as std::convert::TryFrom<
+                    &[u8],
+                >>::try_from(call.input.get(0..4)?)
+                    .ok()?;
+                match method_id {
+                    Approve::METHOD_ID => {
+                        Approve::match_and_decode(call).map(Functions::Approve)
+                    }
+                    BalanceOf::METHOD_ID => {
+                        BalanceOf::match_and_decode(call).map(Functions::BalanceOf)
+                    }
+                    GetApproved::METHOD_ID => {
+                        GetApproved::match_and_decode(call).map(Functions::GetApproved)
+                    }
+                    IsApprovedForAll::METHOD_ID => {
+                        IsApprovedForAll::match_and_decode(call)
+                            .map(Functions::IsApprovedForAll)
+                    }
+                    Name::METHOD_ID => Name::match_and_decode(call).map(Functions::Name),
+                    OwnerOf::METHOD_ID => {
+                        OwnerOf::match_and_decode(call).map(Functions::OwnerOf)
+                    }
+                    SafeTransferFromAddressAddressUint256::METHOD_ID => {
+                        SafeTransferFromAddressAddressUint256::match_and_decode(call)
+                            .map(Functions::SafeTransferFromAddressAddressUint256)
+                    }
+                    SafeTransferFromWithData::METHOD_ID => {
+                        SafeTransferFromWithData::match_and_decode(call)
+                            .map(Functions::SafeTransferFromWithData)
+                    }
+                    SetApprovalForAll::METHOD_ID => {
+                        SetApprovalForAll::match_and_decode(call)
+                            .map(Functions::SetApprovalForAll)
+                    }
+                    SupportsInterface::METHOD_ID => {
+                        SupportsInterface::match_and_decode(call)
+                            .map(Functions::SupportsInterface)
+                    }
+                    Symbol::METHOD_ID => {
+                        Symbol::match_and_decode(call).map(Functions::Symbol)
+                    }
+                    TokenByIndex::METHOD_ID => {
+                        TokenByIndex::match_and_decode(call).map(Functions::TokenByIndex)
+                    }
+                    TokenOfOwnerByIndex::METHOD_ID => {
+                        TokenOfOwnerByIndex::match_and_decode(call)
+                            .map(Functions::TokenOfOwnerByIndex)
+                    }
+                    TokenUri::METHOD_ID => {
+                        TokenUri::match_and_decode(call).map(Functions::TokenUri)
+                    }
+                    TotalSupply::METHOD_ID => {
+                        TotalSupply::match_and_decode(call).map(Functions::TotalSupply)
+                    }
+                    TransferFrom::METHOD_ID => {
+                        TransferFrom::match_and_decode(call).map(Functions::TransferFrom)
+                    }
+                    _ => None,
+                }
+            }
+            pub fn name(&self) -> &'static str {
+                match self {
+                    Functions::Approve(_) => "approve",
+                    Functions::BalanceOf(_) => "balanceOf",
+                    Functions::GetApproved(_) => "getApproved",
+                    Functions::IsApprovedForAll(_) => "isApprovedForAll",
+                    Functions::Name(_) => "name",
+                    Functions::OwnerOf(_) => "ownerOf",
+                    Functions::SafeTransferFromAddressAddressUint256(_) => {
+                        "safeTransferFromAddressAddressUint256"
+                    }
+                    Functions::SafeTransferFromWithData(_) => "safeTransferFromWithData",
+                    Functions::SetApprovalForAll(_) => "setApprovalForAll",
+                    Functions::SupportsInterface(_) => "supportsInterface",
+                    Functions::Symbol(_) => "symbol",
+                    Functions::TokenByIndex(_) => "tokenByIndex",
+                    Functions::TokenOfOwnerByIndex(_) => "tokenOfOwnerByIndex",
+                    Functions::TokenUri(_) => "tokenURI",
+                    Functions::TotalSupply(_) => "totalSupply",
+                    Functions::TransferFrom(_) => "transferFrom",
+                }
+            }
+        }
+        impl substreams_ethereum::ContractFunctions for Functions {
+            fn match_and_decode(
+                call: impl AsRef<substreams_ethereum::pb::eth::v2::Call>,
+            ) -> Option<Self> {
+                Self::match_and_decode(call)
+            }
+            fn name(&self) -> &'static str {
+                self.name()
+            }
+        }
+        /// Handle on a deployed `erc721` contract, its view functions are called through RPC.
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Erc721 {
+            pub address: substreams_ethereum::scalar::Address,
+        }
+        impl Erc721 {
+            pub const NAME: &'static str = "erc721";
+            pub fn balance_of(
+                &self,
+                owner: substreams_ethereum::scalar::Address,
+            ) -> Option<substreams::scalar::BigInt> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(BalanceOf { owner }, self.address.to_vec())
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<
+                    _,
+                    BalanceOf,
+                >(responses.get(0)?)
+            }
+            pub fn get_approved(
+                &self,
+                token_id: substreams::scalar::BigInt,
+            ) -> Option<substreams_ethereum::scalar::Address> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(GetApproved { token_id }, self.address.to_vec())
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<
+                    _,
+                    GetApproved,
+                >(responses.get(0)?)
+            }
+            pub fn is_approved_for_all(
+                &self,
+                owner: substreams_ethereum::scalar::Address,
+                operator: substreams_ethereum::scalar::Address,
+            ) -> Option<bool> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(
+                        IsApprovedForAll {
+                            owner,
+                            operator,
+                        },
+                        self.address.to_vec(),
+                    )
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<
+                    _,
+                    IsApprovedForAll,
+                >(responses.get(0)?)
+            }
+            pub fn name(&self) -> Option<String> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(Name {}, self.address.to_vec())
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<_, Name>(responses.get(0)?)
+            }
+            pub fn owner_of(
+                &self,
+                token_id: substreams::scalar::BigInt,
+            ) -> Option<substreams_ethereum::scalar::Address> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(OwnerOf { token_id }, self.address.to_vec())
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<
+                    _,
+                    OwnerOf,
+                >(responses.get(0)?)
+            }
+            pub fn supports_interface(
+                &self,
+                interface_id: [u8; 4usize],
+            ) -> Option<bool> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(SupportsInterface { interface_id }, self.address.to_vec())
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<
+                    _,
+                    SupportsInterface,
+                >(responses.get(0)?)
+            }
+            pub fn symbol(&self) -> Option<String> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(Symbol {}, self.address.to_vec())
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<
+                    _,
+                    Symbol,
+                >(responses.get(0)?)
+            }
+            pub fn token_by_index(
+                &self,
+                index: substreams::scalar::BigInt,
+            ) -> Option<substreams::scalar::BigInt> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(TokenByIndex { index }, self.address.to_vec())
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<
+                    _,
+                    TokenByIndex,
+                >(responses.get(0)?)
+            }
+            pub fn token_of_owner_by_index(
+                &self,
+                owner: substreams_ethereum::scalar::Address,
+                index: substreams::scalar::BigInt,
+            ) -> Option<substreams::scalar::BigInt> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(
+                        TokenOfOwnerByIndex {
+                            owner,
+                            index,
+                        },
+                        self.address.to_vec(),
+                    )
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<
+                    _,
+                    TokenOfOwnerByIndex,
+                >(responses.get(0)?)
+            }
+            pub fn token_uri(
+                &self,
+                token_id: substreams::scalar::BigInt,
+            ) -> Option<String> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(TokenUri { token_id }, self.address.to_vec())
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<
+                    _,
+                    TokenUri,
+                >(responses.get(0)?)
+            }
+            pub fn total_supply(&self) -> Option<substreams::scalar::BigInt> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(TotalSupply {}, self.address.to_vec())
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<
+                    _,
+                    TotalSupply,
+                >(responses.get(0)?)
+            }
+        }
+        /// The `prost::Message` versions of the types of this module.
+        pub mod pb {
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct Approve {
+                #[prost(bytes = "vec", tag = "1")]
+                pub to: Vec<u8>,
+                #[prost(string, tag = "2")]
+                pub token_id: String,
+            }
+            impl From<super::Approve> for Approve {
+                fn from(value: super::Approve) -> Self {
+                    Approve {
+                        to: value.to.to_vec(),
+                        token_id: value.token_id.to_string(),
+                    }
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct BalanceOf {
+                #[prost(bytes = "vec", tag = "1")]
+                pub owner: Vec<u8>,
+            }
+            impl From<super::BalanceOf> for BalanceOf {
+                fn from(value: super::BalanceOf) -> Self {
+                    BalanceOf {
+                        owner: value.owner.to_vec(),
+                    }
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct GetApproved {
+                #[prost(string, tag = "1")]
+                pub token_id: String,
+            }
+            impl From<super::GetApproved> for GetApproved {
+                fn from(value: super::GetApproved) -> Self {
+                    GetApproved {
+                        token_id: value.token_id.to_string(),
+                    }
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct IsApprovedForAll {
+                #[prost(bytes = "vec", tag = "1")]
+                pub owner: Vec<u8>,
+                #[prost(bytes = "vec", tag = "2")]
+                pub operator: Vec<u8>,
+            }
+            impl From<super::IsApprovedForAll> for IsApprovedForAll {
+                fn from(value: super::IsApprovedForAll) -> Self {
+                    IsApprovedForAll {
+                        owner: value.owner.to_vec(),
+                        operator: value.operator.to_vec(),
+                    }
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct Name {}
+            impl From<super::Name> for Name {
+                fn from(value: super::Name) -> Self {
+                    Name {}
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct OwnerOf {
+                #[prost(string, tag = "1")]
+                pub token_id: String,
+            }
+            impl From<super::OwnerOf> for OwnerOf {
+                fn from(value: super::OwnerOf) -> Self {
+                    OwnerOf {
+                        token_id: value.token_id.to_string(),
+                    }
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct SafeTransferFromAddressAddressUint256 {
+                #[prost(bytes = "vec", tag = "1")]
+                pub from: Vec<u8>,
+                #[prost(bytes = "vec", tag = "2")]
+                pub to: Vec<u8>,
+                #[prost(string, tag = "3")]
+                pub token_id: String,
+            }
+            impl From<super::SafeTransferFromAddressAddressUint256>
+            for SafeTransferFromAddressAddressUint256 {
+                fn from(value: super::SafeTransferFromAddressAddressUint256) -> Self {
+                    SafeTransferFromAddressAddressUint256 {
+                        from: value.from.to_vec(),
+                        to: value.to.to_vec(),
+                        token_id: value.token_id.to_string(),
+                    }
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct SafeTransferFromWithData {
+                #[prost(bytes = "vec", tag = "1")]
+                pub from: Vec<u8>,
+                #[prost(bytes = "vec", tag = "2")]
+                pub to: Vec<u8>,
+                #[prost(string, tag = "3")]
+                pub token_id: String,
+                #[prost(bytes = "vec", tag = "4")]
+                pub data: Vec<u8>,
+            }
+            impl From<super::SafeTransferFromWithData> for SafeTransferFromWithData {
+                fn from(value: super::SafeTransferFromWithData) -> Self {
+                    SafeTransferFromWithData {
+                        from: value.from.to_vec(),
+                        to: value.to.to_vec(),
+                        token_id: value.token_id.to_string(),
+                        data: value.data,
+                    }
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct SetApprovalForAll {
+                #[prost(bytes = "vec", tag = "1")]
+                pub operator: Vec<u8>,
+                #[prost(bool, tag = "2")]
+                pub approved: bool,
+            }
+            impl From<super::SetApprovalForAll> for SetApprovalForAll {
+                fn from(value: super::SetApprovalForAll) -> Self {
+                    SetApprovalForAll {
+                        operator: value.operator.to_vec(),
+                        approved: value.approved,
+                    }
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct SupportsInterface {
+                #[prost(bytes = "vec", tag = "1")]
+                pub interface_id: Vec<u8>,
+            }
+            impl From<super::SupportsInterface> for SupportsInterface {
+                fn from(value: super::SupportsInterface) -> Self {
+                    SupportsInterface {
+                        interface_id: value.interface_id.to_vec(),
+                    }
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct Symbol {}
+            impl From<super::Symbol> for Symbol {
+                fn from(value: super::Symbol) -> Self {
+                    Symbol {}
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct TokenByIndex {
+                #[prost(string, tag = "1")]
+                pub index: String,
+            }
+            impl From<super::TokenByIndex> for TokenByIndex {
+                fn from(value: super::TokenByIndex) -> Self {
+                    TokenByIndex {
+                        index: value.index.to_string(),
+                    }
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct TokenOfOwnerByIndex {
+                #[prost(bytes = "vec", tag = "1")]
+                pub owner: Vec<u8>,
+                #[prost(string, tag = "2")]
+                pub index: String,
+            }
+            impl From<super::TokenOfOwnerByIndex> for TokenOfOwnerByIndex {
+                fn from(value: super::TokenOfOwnerByIndex) -> Self {
+                    TokenOfOwnerByIndex {
+                        owner: value.owner.to_vec(),
+                        index: value.index.to_string(),
+                    }
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct TokenUri {
+                #[prost(string, tag = "1")]
+                pub token_id: String,
+            }
+            impl From<super::TokenUri> for TokenUri {
+                fn from(value: super::TokenUri) -> Self {
+                    TokenUri {
+                        token_id: value.token_id.to_string(),
+                    }
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct TotalSupply {}
+            impl From<super::TotalSupply> for TotalSupply {
+                fn from(value: super::TotalSupply) -> Self {
+                    TotalSupply {}
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct TransferFrom {
+                #[prost(bytes = "vec", tag = "1")]
+                pub from: Vec<u8>,
+                #[prost(bytes = "vec", tag = "2")]
+                pub to: Vec<u8>,
+                #[prost(string, tag = "3")]
+                pub token_id: String,
+            }
+            impl From<super::TransferFrom> for TransferFrom {
+                fn from(value: super::TransferFrom) -> Self {
+                    TransferFrom {
+                        from: value.from.to_vec(),
+                        to: value.to.to_vec(),
+                        token_id: value.token_id.to_string(),
+                    }
+                }
+            }
+        }
+    }
+    /// Contract's events.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod events {
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct Approval {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub owner: substreams_ethereum::scalar::Address,
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub approved: substreams_ethereum::scalar::Address,
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub token_id: substreams::scalar::BigInt,
+        }
+        impl Approval {
+            pub const TOPIC_ID: [u8; 32] = [
+                140u8,
+                91u8,
+                225u8,
+                229u8,
+                235u8,
+                236u8,
+                125u8,
+                91u8,
+                209u8,
+                79u8,
+                113u8,
+                66u8,
+                125u8,
+                30u8,
+                132u8,
+                243u8,
+                221u8,
+                3u8,
+                20u8,
+                192u8,
+                247u8,
+                178u8,
+                41u8,
+                30u8,
+                91u8,
+                32u8,
+                10u8,
+                200u8,
+                199u8,
+                195u8,
+                185u8,
+                37u8,
+            ];
+            pub const SIGNATURE: &'static str = "Approval(address,address,uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &[
+                "owner",
+                "approved",
+                "tokenId",
+            ];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "address",
+                "address",
+                "uint256",
+            ];
+            pub fn is_anonymous() -> bool {
+                false
+            }
+            pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                if log.topics.len() != 4usize {
+                    return false;
+                }
+                if log.data.len() != 0usize {
+                    return false;
+                }
+                return log.topics.get(0).expect("bounds already checked").as_ref()
+                    == Self::TOPIC_ID;
+            }
+            pub fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 4usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 4usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self {
+                    owner: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "owner",
+                            "address",
+                            1usize,
+                        );
+                        substreams_ethereum::scalar::Address(topic.address(0usize)?)
+                    },
+                    approved: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[2usize],
+                            "approved",
+                            "address",
+                            2usize,
+                        );
+                        substreams_ethereum::scalar::Address(topic.address(0usize)?)
+                    },
+                    token_id: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[3usize],
+                            "token_id",
+                            "uint256",
+                            3usize,
+                        );
+                        topic.big_uint(0usize)?
+                    },
+                })
+            }
+            /// Decodes only the `owner` parameter of `log`, without decoding the whole event.
+            pub fn decode_owner(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<
+                substreams_ethereum::scalar::Address,
+                substreams_ethereum::DecodeError,
+            > {
+                if log.topics.len() != 4usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 4usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[1usize],
+                    "owner",
+                    "address",
+                    1usize,
+                );
+                Ok(substreams_ethereum::scalar::Address(topic.address(0usize)?))
+            }
+            /// Decodes only the `approved` parameter of `log`, without decoding the whole event.
+            pub fn decode_approved(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<
+                substreams_ethereum::scalar::Address,
+                substreams_ethereum::DecodeError,
+            > {
+                if log.topics.len() != 4usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 4usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[2usize],
+                    "approved",
+                    "address",
+                    2usize,
+                );
+                Ok(substreams_ethereum::scalar::Address(topic.address(0usize)?))
+            }
+            /// Decodes only the `token_id` parameter of `log`, without decoding the whole event.
+            pub fn decode_token_id(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 4usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 4usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[3usize],
+                    "token_id",
+                    "uint256",
+                    3usize,
+                );
+                topic.big_uint(0usize)
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        Self::TOPIC_ID.to_vec(),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.owner.as_bytes()); }),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.approved.as_bytes()); }),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.word(substreams_ethereum::abi::encode_big_uint(& self
+                        .token_id)); })
+                    ],
+                    data: Vec::<u8>::new(),
+                    ..Default::default()
+                }
+            }
+        }
+        impl substreams_ethereum::Event for Approval {
+            const NAME: &'static str = "Approval";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
+            fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                Self::match_log(log)
+            }
+            fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(log)
+            }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct ApprovalForAll {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub owner: substreams_ethereum::scalar::Address,
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub operator: substreams_ethereum::scalar::Address,
+            pub approved: bool,
+        }
+        impl ApprovalForAll {
+            pub const TOPIC_ID: [u8; 32] = [
+                23u8,
+                48u8,
+                126u8,
+                171u8,
+                57u8,
+                171u8,
+                97u8,
+                7u8,
+                232u8,
+                137u8,
+                152u8,
+                69u8,
+                173u8,
+                61u8,
+                89u8,
+                189u8,
+                150u8,
+                83u8,
+                242u8,
+                0u8,
+                242u8,
+                32u8,
+                146u8,
+                4u8,
+                137u8,
+                202u8,
+                43u8,
+                89u8,
+                55u8,
+                105u8,
+                108u8,
+                49u8,
+            ];
+            pub const SIGNATURE: &'static str = "ApprovalForAll(address,address,bool)";
+            pub const PARAM_NAMES: &'static [&'static str] = &[
+                "owner",
+                "operator",
+                "approved",
+            ];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "address",
+                "address",
+                "bool",
+            ];
+            pub fn is_anonymous() -> bool {
+                false
+            }
+            pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                if log.topics.len() != 3usize {
+                    return false;
+                }
+                if log.data.len() != 32usize {
+                    return false;
+                }
+                return log.topics.get(0).expect("bounds already checked").as_ref()
+                    == Self::TOPIC_ID;
+            }
+            pub fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
+                Ok(Self {
+                    owner: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "owner",
+                            "address",
+                            1usize,
+                        );
+                        substreams_ethereum::scalar::Address(topic.address(0usize)?)
+                    },
+                    operator: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[2usize],
+                            "operator",
+                            "address",
+                            2usize,
+                        );
+                        substreams_ethereum::scalar::Address(topic.address(0usize)?)
+                    },
+                    approved: decoder.bool(0usize)?,
+                })
+            }
+            /// Decodes only the `owner` parameter of `log`, without decoding the whole event.
+            pub fn decode_owner(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<
+                substreams_ethereum::scalar::Address,
+                substreams_ethereum::DecodeError,
+            > {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[1usize],
+                    "owner",
+                    "address",
+                    1usize,
+                );
+                Ok(substreams_ethereum::scalar::Address(topic.address(0usize)?))
+            }
+            /// Decodes only the `operator` parameter of `log`, without decoding the whole event.
+            pub fn decode_operator(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<
+                substreams_ethereum::scalar::Address,
+                substreams_ethereum::DecodeError,
+            > {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[2usize],
+                    "operator",
+                    "address",
+                    2usize,
+                );
+                Ok(substreams_ethereum::scalar::Address(topic.address(0usize)?))
+            }
+            /// Decodes only the `approved` parameter of `log`, without decoding the whole event.
+            pub fn decode_approved(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<bool, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data).bool(0usize)
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        Self::TOPIC_ID.to_vec(),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.owner.as_bytes()); }),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.operator.as_bytes()); })
+                    ],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        32usize,
+                        |encoder| {
+                            encoder.bool(self.approved);
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
+        }
+        impl substreams_ethereum::Event for ApprovalForAll {
+            const NAME: &'static str = "ApprovalForAll";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
+            fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                Self::match_log(log)
+            }
+            fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(log)
+            }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct Transfer {
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub from: substreams_ethereum::scalar::Address,
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub to: substreams_ethereum::scalar::Address,
+            #[serde(with = "substreams_ethereum::abi::serde")]
+            pub token_id: substreams::scalar::BigInt,
+        }
+        impl Transfer {
+            pub const TOPIC_ID: [u8; 32] = [
+                221u8,
+                242u8,
+                82u8,
+                173u8,
+                27u8,
+                226u8,
+                200u8,
+                155u8,
+                105u8,
+                194u8,
+                176u8,
+                104u8,
+                252u8,
+                55u8,
+                141u8,
+                170u8,
+                149u8,
+                43u8,
+                167u8,
+                241u8,
+                99u8,
+                196u8,
+                161u8,
+                22u8,
+                40u8,
+                245u8,
+                90u8,
+                77u8,
+                245u8,
+                35u8,
+                179u8,
+                239u8,
+            ];
+            pub const SIGNATURE: &'static str = "Transfer(address,address,uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["from", "to", "tokenId"];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "address",
+                "address",
+                "uint256",
+            ];
+            pub fn is_anonymous() -> bool {
+                false
+            }
+            pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                if log.topics.len() != 4usize {
+                    return false;
+                }
+                if log.data.len() != 0usize {
+                    return false;
+                }
+                return log.topics.get(0).expect("bounds already checked").as_ref()
+                    == Self::TOPIC_ID;
+            }
+            pub fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 4usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 4usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self {
+                    from: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "from",
+                            "address",
+                            1usize,
+                        );
+                        substreams_ethereum::scalar::Address(topic.address(0usize)?)
+                    },
+                    to: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[2usize],
+                            "to",
+                            "address",
+                            2usize,
+                        );
+                        substreams_ethereum::scalar::Address(topic.address(0usize)?)
+                    },
+                    token_id: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[3usize],
+                            "token_id",
+                            "uint256",
+                            3usize,
+                        );
+                        topic.big_uint(0usize)?
+                    },
+                })
+            }
+            /// Decodes only the `from` parameter of `log`, without decoding the whole event.
+            pub fn decode_from(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<
+                substreams_ethereum::scalar::Address,
+                substreams_ethereum::DecodeError,
+            > {
+                if log.topics.len() != 4usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 4usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[1usize],
+                    "from",
+                    "address",
+                    1usize,
+                );
+                Ok(substreams_ethereum::scalar::Address(topic.address(0usize)?))
+            }
+            /// Decodes only the `to` parameter of `log`, without decoding the whole event.
+            pub fn decode_to(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<
+                substreams_ethereum::scalar::Address,
+                substreams_ethereum::DecodeError,
+            > {
+                if log.topics.len() != 4usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 4usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[2usize],
+                    "to",
+                    "address",
+                    2usize,
+                );
+                Ok(substreams_ethereum::scalar::Address(topic.address(0usize)?))
+            }
+            /// Decodes only the `token_id` parameter of `log`, without decoding the whole event.
+            pub fn decode_token_id(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 4usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 4usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[3usize],
+                    "token_id",
+                    "uint256",
+                    3usize,
+                );
+                topic.big_uint(0usize)
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        Self::TOPIC_ID.to_vec(),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.from.as_bytes()); }),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.to.as_bytes()); }),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.word(substreams_ethereum::abi::encode_big_uint(& self
+                        .token_id)); })
+                    ],
+                    data: Vec::<u8>::new(),
+                    ..Default::default()
+                }
+            }
+        }
+        impl substreams_ethereum::Event for Transfer {
+            const NAME: &'static str = "Transfer";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
+            fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                Self::match_log(log)
+            }
+            fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(log)
+            }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Any of the contract's events, see [Events::match_and_decode].
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Events {
+            Approval(Approval),
+            ApprovalForAll(ApprovalForAll),
+            Transfer(Transfer),
+        }
+        impl Events {
+            /// Decodes `log` into the event whose signature is the first topic of the log.
+            pub fn match_and_decode(
+                log: impl AsRef<substreams_ethereum::pb::eth::v2::Log>,
+            ) -> Option<Self> {
+                use substreams_ethereum::Event;
+                let log = log.as_ref();
+                let topic = <[u8; 32] as std::convert::TryFrom<
+                    &[u8],
+                >>::try_from(log.topics.get(0)?.as_ref())
+                    .ok()?;
+                match topic {
+                    Approval::TOPIC_ID => {
+                        Approval::match_and_decode(log).map(Events::Approval)
+                    }
+                    ApprovalForAll::TOPIC_ID => {
+                        ApprovalForAll::match_and_decode(log).map(Events::ApprovalForAll)
+                    }
+                    Transfer::TOPIC_ID => {
+                        Transfer::match_and_decode(log).map(Events::Transfer)
+                    }
+                    _ => None,
+                }
+            }
+            pub fn name(&self) -> &'static str {
+                match self {
+                    Events::Approval(_) => "Approval",
+                    Events::ApprovalForAll(_) => "ApprovalForAll",
+                    Events::Transfer(_) => "Transfer",
+                }
+            }
+        }
+        impl substreams_ethereum::ContractEvents for Events {
+            fn match_and_decode(
+                log: impl AsRef<substreams_ethereum::pb::eth::v2::Log>,
+            ) -> Option<Self> {
+                Self::match_and_decode(log)
+            }
+            fn name(&self) -> &'static str {
+                self.name()
+            }
+        }
+        /// The `prost::Message` versions of the types of this module.
+        pub mod pb {
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct Approval {
+                #[prost(bytes = "vec", tag = "1")]
+                pub owner: Vec<u8>,
+                #[prost(bytes = "vec", tag = "2")]
+                pub approved: Vec<u8>,
+                #[prost(string, tag = "3")]
+                pub token_id: String,
+            }
+            impl From<super::Approval> for Approval {
+                fn from(value: super::Approval) -> Self {
+                    Approval {
+                        owner: value.owner.to_vec(),
+                        approved: value.approved.to_vec(),
+                        token_id: value.token_id.to_string(),
+                    }
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct ApprovalForAll {
+                #[prost(bytes = "vec", tag = "1")]
+                pub owner: Vec<u8>,
+                #[prost(bytes = "vec", tag = "2")]
+                pub operator: Vec<u8>,
+                #[prost(bool, tag = "3")]
+                pub approved: bool,
+            }
+            impl From<super::ApprovalForAll> for ApprovalForAll {
+                fn from(value: super::ApprovalForAll) -> Self {
+                    ApprovalForAll {
+                        owner: value.owner.to_vec(),
+                        operator: value.operator.to_vec(),
+                        approved: value.approved,
+                    }
+                }
+            }
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct Transfer {
+                #[prost(bytes = "vec", tag = "1")]
+                pub from: Vec<u8>,
+                #[prost(bytes = "vec", tag = "2")]
+                pub to: Vec<u8>,
+                #[prost(string, tag = "3")]
+                pub token_id: String,
+            }
+            impl From<super::Transfer> for Transfer {
+                fn from(value: super::Transfer) -> Self {
+                    Transfer {
+                        from: value.from.to_vec(),
+                        to: value.to.to_vec(),
+                        token_id: value.token_id.to_string(),
+                    }
+                }
+            }
+        }
+    }
+    /// Contract's custom errors.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod errors {}
+    /// Contract's enums.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod enums {}
+    /// Contract's structs.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod structs {
+        /// The `prost::Message` versions of the types of this module.
+        pub mod pb {}
+    }
\ No newline at end of file
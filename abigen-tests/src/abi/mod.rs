@@ -2,3 +2,12 @@
 #[allow(dead_code)]
 #[allow(unused_variables)]
 pub mod tests;
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[allow(unused_variables)]
+pub mod erc721;
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[allow(unused_variables)]
+pub mod ownable;
+pub mod set;
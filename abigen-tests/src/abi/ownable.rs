@@ -0,0 +1,452 @@
+    #[allow(unused_imports)]
+    pub use self::functions::Ownable;
+    /// Contract's functions.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod functions {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Constructor {
+            pub initial_owner: Vec<u8>,
+        }
+        impl Constructor {
+            /// Creation bytecode of the contract, taken from its artifact.
+            pub const BYTECODE: &'static [u8] = b"`\x80`@R4\x80\x15`\x0FW`\0\x80\xFD[P";
+            /// Encodes the creation input deploying [Self::BYTECODE] with these constructor
+            /// arguments.
+            pub fn encode_with_bytecode(&self) -> Vec<u8> {
+                self.encode(Self::BYTECODE.to_vec())
+            }
+            /// Decodes the constructor arguments of a contract creation call, they are
+            /// appended to the init code whose length is `code_len`.
+            pub fn decode_from_creation_input(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+                code_len: usize,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if !Self::match_call(call) {
+                    return Err(substreams_ethereum::DecodeError::NotContractCreation);
+                }
+                let data = call
+                    .input
+                    .get(code_len..)
+                    .ok_or(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: code_len,
+                        actual: call.input.len(),
+                    })?;
+                Self::decode_at(data, code_len)
+            }
+            /// Decodes the ABI encoded constructor arguments, without the init code.
+            pub fn decode(
+                data: &[u8],
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode_at(data, 0)
+            }
+            /// Decodes the constructor arguments found at byte `offset` of the input, the
+            /// offset reported in errors.
+            fn decode_at(
+                data: &[u8],
+                offset: usize,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    data,
+                    offset,
+                );
+                Ok(Self {
+                    initial_owner: decoder.address(0usize)?.to_vec(),
+                })
+            }
+            /// Encodes the creation input deploying `code` with these constructor arguments.
+            pub fn encode(&self, code: Vec<u8>) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder.address(self.initial_owner.as_slice());
+                    },
+                );
+                let mut encoded = Vec::with_capacity(code.len() + data.len());
+                encoded.extend(code);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                call.call_type
+                    == substreams_ethereum::pb::eth::v2::CallType::Create as i32
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Owner {}
+        impl Owner {
+            pub const METHOD_ID: [u8; 4] = [141u8, 165u8, 203u8, 91u8];
+            pub const SIGNATURE: &'static str = "owner()";
+            pub const PARAM_NAMES: &'static [&'static str] = &[];
+            pub const PARAM_TYPES: &'static [&'static str] = &[];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Ok(Self {})
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = Vec::<u8>::new();
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn output_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                Self::output(call.return_data.as_ref())
+            }
+            pub fn output(
+                data: &[u8],
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                Ok(decoder.address(0usize)?.to_vec())
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+            pub fn call(&self, address: Vec<u8>) -> Option<Vec<u8>> {
+                use substreams_ethereum::pb::eth::rpc;
+                let rpc_calls = rpc::RpcCalls {
+                    calls: vec![
+                        rpc::RpcCall { to_addr : address, data : self.encode(), }
+                    ],
+                };
+                let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+                let response = responses
+                    .get(0)
+                    .expect("one response should have existed");
+                if response.failed {
+                    return None;
+                }
+                match Self::output(response.raw.as_ref()) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        use substreams_ethereum::Function;
+                        substreams::log::info!(
+                            "Call output for function `{}` failed to decode with error: {}",
+                            Self::NAME, err
+                        );
+                        None
+                    }
+                }
+            }
+        }
+        impl substreams_ethereum::Function for Owner {
+            const NAME: &'static str = "owner";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        impl substreams_ethereum::rpc::RPCDecodable<Vec<u8>> for Owner {
+            fn output(data: &[u8]) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                Self::output(data)
+            }
+        }
+        /// Any of the contract's functions, see [Functions::match_and_decode].
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Functions {
+            Owner(Owner),
+        }
+        impl Functions {
+            /// Decodes `call` into the function whose method id is the first 4 bytes of the call input.
+            pub fn match_and_decode(
+                call: impl AsRef<substreams_ethereum::pb::eth::v2::Call>,
+            ) -> Option<Self> {
+                use substreams_ethereum::Function;
+                let call = call.as_ref();
+                let method_id = <[u8; 4] as std::convert::TryFrom<
+                    &[u8],
+                >>::try_from(call.input.get(0..4)?)
+                    .ok()?;
+                match method_id {
+                    Owner::METHOD_ID => {
+                        Owner::match_and_decode(call).map(Functions::Owner)
+                    }
+                    _ => None,
+                }
+            }
+            pub fn name(&self) -> &'static str {
+                match self {
+                    Functions::Owner(_) => "owner",
+                }
+            }
+        }
+        impl substreams_ethereum::ContractFunctions for Functions {
+            fn match_and_decode(
+                call: impl AsRef<substreams_ethereum::pb::eth::v2::Call>,
+            ) -> Option<Self> {
+                Self::match_and_decode(call)
+            }
+            fn name(&self) -> &'static str {
+                self.name()
+            }
+        }
+        /// Handle on a deployed `ownable` contract, its view functions are called through RPC.
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Ownable {
+            pub address: Vec<u8>,
+        }
+        impl Ownable {
+            pub const NAME: &'static str = "ownable";
+            pub fn owner(&self) -> Option<Vec<u8>> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(Owner {}, self.address.to_vec())
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<_, Owner>(responses.get(0)?)
+            }
+        }
+    }
+    /// Contract's events.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod events {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct OwnershipTransferred {
+            pub previous_owner: Vec<u8>,
+            pub new_owner: Vec<u8>,
+        }
+        impl OwnershipTransferred {
+            pub const TOPIC_ID: [u8; 32] = [
+                139u8,
+                224u8,
+                7u8,
+                156u8,
+                83u8,
+                22u8,
+                89u8,
+                20u8,
+                19u8,
+                68u8,
+                205u8,
+                31u8,
+                208u8,
+                164u8,
+                242u8,
+                132u8,
+                25u8,
+                73u8,
+                127u8,
+                151u8,
+                34u8,
+                163u8,
+                218u8,
+                175u8,
+                227u8,
+                180u8,
+                24u8,
+                111u8,
+                107u8,
+                100u8,
+                87u8,
+                224u8,
+            ];
+            pub const SIGNATURE: &'static str = "OwnershipTransferred(address,address)";
+            pub const PARAM_NAMES: &'static [&'static str] = &[
+                "previousOwner",
+                "newOwner",
+            ];
+            pub const PARAM_TYPES: &'static [&'static str] = &["address", "address"];
+            pub fn is_anonymous() -> bool {
+                false
+            }
+            pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                if log.topics.len() != 3usize {
+                    return false;
+                }
+                if log.data.len() != 0usize {
+                    return false;
+                }
+                return log.topics.get(0).expect("bounds already checked").as_ref()
+                    == Self::TOPIC_ID;
+            }
+            pub fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self {
+                    previous_owner: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "previous_owner",
+                            "address",
+                            1usize,
+                        );
+                        topic.address(0usize)?.to_vec()
+                    },
+                    new_owner: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[2usize],
+                            "new_owner",
+                            "address",
+                            2usize,
+                        );
+                        topic.address(0usize)?.to_vec()
+                    },
+                })
+            }
+            /// Decodes only the `previous_owner` parameter of `log`, without decoding the whole event.
+            pub fn decode_previous_owner(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[1usize],
+                    "previous_owner",
+                    "address",
+                    1usize,
+                );
+                Ok(topic.address(0usize)?.to_vec())
+            }
+            /// Decodes only the `new_owner` parameter of `log`, without decoding the whole event.
+            pub fn decode_new_owner(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[2usize],
+                    "new_owner",
+                    "address",
+                    2usize,
+                );
+                Ok(topic.address(0usize)?.to_vec())
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        Self::TOPIC_ID.to_vec(),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.previous_owner.as_slice()); }),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.new_owner.as_slice()); })
+                    ],
+                    data: Vec::<u8>::new(),
+                    ..Default::default()
+                }
+            }
+        }
+        impl substreams_ethereum::Event for OwnershipTransferred {
+            const NAME: &'static str = "OwnershipTransferred";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
+            fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                Self::match_log(log)
+            }
+            fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(log)
+            }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Any of the contract's events, see [Events::match_and_decode].
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Events {
+            OwnershipTransferred(OwnershipTransferred),
+        }
+        impl Events {
+            /// Decodes `log` into the event whose signature is the first topic of the log.
+            pub fn match_and_decode(
+                log: impl AsRef<substreams_ethereum::pb::eth::v2::Log>,
+            ) -> Option<Self> {
+                use substreams_ethereum::Event;
+                let log = log.as_ref();
+                let topic = <[u8; 32] as std::convert::TryFrom<
+                    &[u8],
+                >>::try_from(log.topics.get(0)?.as_ref())
+                    .ok()?;
+                match topic {
+                    OwnershipTransferred::TOPIC_ID => {
+                        OwnershipTransferred::match_and_decode(log)
+                            .map(Events::OwnershipTransferred)
+                    }
+                    _ => None,
+                }
+            }
+            pub fn name(&self) -> &'static str {
+                match self {
+                    Events::OwnershipTransferred(_) => "OwnershipTransferred",
+                }
+            }
+        }
+        impl substreams_ethereum::ContractEvents for Events {
+            fn match_and_decode(
+                log: impl AsRef<substreams_ethereum::pb::eth::v2::Log>,
+            ) -> Option<Self> {
+                Self::match_and_decode(log)
+            }
+            fn name(&self) -> &'static str {
+                self.name()
+            }
+        }
+    }
+    /// Contract's custom errors.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod errors {}
+    /// Contract's enums.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod enums {}
+    /// Contract's structs.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod structs {}
\ No newline at end of file
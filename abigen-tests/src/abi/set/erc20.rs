@@ -0,0 +1,237 @@
+    #[allow(unused_imports)]
+    pub use self::functions::Erc20;
+    /// Contract's functions.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod functions {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct BalanceOf {
+            pub owner: Vec<u8>,
+        }
+        impl BalanceOf {
+            pub const METHOD_ID: [u8; 4] = [112u8, 160u8, 130u8, 49u8];
+            pub const SIGNATURE: &'static str = "balanceOf(address)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["owner"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["address"];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    owner: decoder.address(0usize)?.to_vec(),
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder.address(self.owner.as_slice());
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn output_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                Self::output(call.return_data.as_ref())
+            }
+            pub fn output(
+                data: &[u8],
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                decoder.big_uint(0usize)
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+            pub fn call(&self, address: Vec<u8>) -> Option<substreams::scalar::BigInt> {
+                use substreams_ethereum::pb::eth::rpc;
+                let rpc_calls = rpc::RpcCalls {
+                    calls: vec![
+                        rpc::RpcCall { to_addr : address, data : self.encode(), }
+                    ],
+                };
+                let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+                let response = responses
+                    .get(0)
+                    .expect("one response should have existed");
+                if response.failed {
+                    return None;
+                }
+                match Self::output(response.raw.as_ref()) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        use substreams_ethereum::Function;
+                        substreams::log::info!(
+                            "Call output for function `{}` failed to decode with error: {}",
+                            Self::NAME, err
+                        );
+                        None
+                    }
+                }
+            }
+        }
+        impl substreams_ethereum::Function for BalanceOf {
+            const NAME: &'static str = "balanceOf";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        impl substreams_ethereum::rpc::RPCDecodable<substreams::scalar::BigInt>
+        for BalanceOf {
+            fn output(
+                data: &[u8],
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                Self::output(data)
+            }
+        }
+        /// Any of the contract's functions, see [Functions::match_and_decode].
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Functions {
+            BalanceOf(BalanceOf),
+        }
+        impl Functions {
+            /// Decodes `call` into the function whose method id is the first 4 bytes of the call input.
+            pub fn match_and_decode(
+                call: impl AsRef<substreams_ethereum::pb::eth::v2::Call>,
+            ) -> Option<Self> {
+                use substreams_ethereum::Function;
+                let call = call.as_ref();
+                let method_id = <[u8; 4] as std::convert::TryFrom<
+                    &[u8],
+                >>::try_from(call.input.get(0..4)?)
+                    .ok()?;
+                match method_id {
+                    BalanceOf::METHOD_ID => {
+                        BalanceOf::match_and_decode(call).map(Functions::BalanceOf)
+                    }
+                    _ => None,
+                }
+            }
+            pub fn name(&self) -> &'static str {
+                match self {
+                    Functions::BalanceOf(_) => "balanceOf",
+                }
+            }
+        }
+        impl substreams_ethereum::ContractFunctions for Functions {
+            fn match_and_decode(
+                call: impl AsRef<substreams_ethereum::pb::eth::v2::Call>,
+            ) -> Option<Self> {
+                Self::match_and_decode(call)
+            }
+            fn name(&self) -> &'static str {
+                self.name()
+            }
+        }
+        /// Handle on a deployed `erc20` contract, its view functions are called through RPC.
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Erc20 {
+            pub address: Vec<u8>,
+        }
+        impl Erc20 {
+            pub const NAME: &'static str = "erc20";
+            pub fn balance_of(
+                &self,
+                owner: Vec<u8>,
+            ) -> Option<substreams::scalar::BigInt> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(BalanceOf { owner }, self.address.to_vec())
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<
+                    _,
+                    BalanceOf,
+                >(responses.get(0)?)
+            }
+        }
+    }
+    /// Contract's events.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod events {
+        pub use super::super::shared::events::Approval;
+        pub use super::super::shared::events::ApprovalRef;
+        pub use super::super::shared::events::Transfer;
+        pub use super::super::shared::events::TransferRef;
+        /// Any of the contract's events, see [Events::match_and_decode].
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Events {
+            Approval(Approval),
+            Transfer(Transfer),
+        }
+        impl Events {
+            /// Decodes `log` into the event whose signature is the first topic of the log.
+            pub fn match_and_decode(
+                log: impl AsRef<substreams_ethereum::pb::eth::v2::Log>,
+            ) -> Option<Self> {
+                use substreams_ethereum::Event;
+                let log = log.as_ref();
+                let topic = <[u8; 32] as std::convert::TryFrom<
+                    &[u8],
+                >>::try_from(log.topics.get(0)?.as_ref())
+                    .ok()?;
+                match topic {
+                    Approval::TOPIC_ID => {
+                        Approval::match_and_decode(log).map(Events::Approval)
+                    }
+                    Transfer::TOPIC_ID => {
+                        Transfer::match_and_decode(log).map(Events::Transfer)
+                    }
+                    _ => None,
+                }
+            }
+            pub fn name(&self) -> &'static str {
+                match self {
+                    Events::Approval(_) => "Approval",
+                    Events::Transfer(_) => "Transfer",
+                }
+            }
+        }
+        impl substreams_ethereum::ContractEvents for Events {
+            fn match_and_decode(
+                log: impl AsRef<substreams_ethereum::pb::eth::v2::Log>,
+            ) -> Option<Self> {
+                Self::match_and_decode(log)
+            }
+            fn name(&self) -> &'static str {
+                self.name()
+            }
+        }
+    }
+    /// Contract's custom errors.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod errors {}
+    /// Contract's enums.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod enums {}
+    /// Contract's structs.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod structs {}
\ No newline at end of file
@@ -0,0 +1,12 @@
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[allow(unused_variables)]
+pub mod erc20;
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[allow(unused_variables)]
+pub mod weth;
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[allow(unused_variables)]
+pub mod shared;
@@ -0,0 +1,572 @@
+    /// Events shared by the contracts.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod events {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Approval {
+            pub owner: Vec<u8>,
+            pub spender: Vec<u8>,
+            pub value: substreams::scalar::BigInt,
+        }
+        impl Approval {
+            pub const TOPIC_ID: [u8; 32] = [
+                140u8,
+                91u8,
+                225u8,
+                229u8,
+                235u8,
+                236u8,
+                125u8,
+                91u8,
+                209u8,
+                79u8,
+                113u8,
+                66u8,
+                125u8,
+                30u8,
+                132u8,
+                243u8,
+                221u8,
+                3u8,
+                20u8,
+                192u8,
+                247u8,
+                178u8,
+                41u8,
+                30u8,
+                91u8,
+                32u8,
+                10u8,
+                200u8,
+                199u8,
+                195u8,
+                185u8,
+                37u8,
+            ];
+            pub const SIGNATURE: &'static str = "Approval(address,address,uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &[
+                "owner",
+                "spender",
+                "value",
+            ];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "address",
+                "address",
+                "uint256",
+            ];
+            pub fn is_anonymous() -> bool {
+                false
+            }
+            pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                if log.topics.len() != 3usize {
+                    return false;
+                }
+                if log.data.len() != 32usize {
+                    return false;
+                }
+                return log.topics.get(0).expect("bounds already checked").as_ref()
+                    == Self::TOPIC_ID;
+            }
+            pub fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
+                Ok(Self {
+                    owner: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "owner",
+                            "address",
+                            1usize,
+                        );
+                        topic.address(0usize)?.to_vec()
+                    },
+                    spender: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[2usize],
+                            "spender",
+                            "address",
+                            2usize,
+                        );
+                        topic.address(0usize)?.to_vec()
+                    },
+                    value: decoder.big_uint(0usize)?,
+                })
+            }
+            /// Decodes only the `owner` parameter of `log`, without decoding the whole event.
+            pub fn decode_owner(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[1usize],
+                    "owner",
+                    "address",
+                    1usize,
+                );
+                Ok(topic.address(0usize)?.to_vec())
+            }
+            /// Decodes only the `spender` parameter of `log`, without decoding the whole event.
+            pub fn decode_spender(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[2usize],
+                    "spender",
+                    "address",
+                    2usize,
+                );
+                Ok(topic.address(0usize)?.to_vec())
+            }
+            /// Decodes only the `value` parameter of `log`, without decoding the whole event.
+            pub fn decode_value(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data).big_uint(0usize)
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        Self::TOPIC_ID.to_vec(),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.owner.as_slice()); }),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.spender.as_slice()); })
+                    ],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        32usize,
+                        |encoder| {
+                            encoder
+                                .word(
+                                    substreams_ethereum::abi::encode_big_uint(&self.value),
+                                );
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
+        }
+        impl substreams_ethereum::Event for Approval {
+            const NAME: &'static str = "Approval";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
+            fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                Self::match_log(log)
+            }
+            fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(log)
+            }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [Approval] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct ApprovalRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> ApprovalRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if Approval::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn owner(&self) -> Result<&'a [u8], substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[1usize],
+                    "owner",
+                    "address",
+                    1usize,
+                );
+                topic.word(0usize).map(|word| &word[12..])
+            }
+            pub fn spender(&self) -> Result<&'a [u8], substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[2usize],
+                    "spender",
+                    "address",
+                    2usize,
+                );
+                topic.word(0usize).map(|word| &word[12..])
+            }
+            pub fn value(
+                &self,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data).big_uint(0usize)
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(self) -> Result<Approval, substreams_ethereum::DecodeError> {
+                Ok(Approval {
+                    owner: self.owner()?.to_vec(),
+                    spender: self.spender()?.to_vec(),
+                    value: self.value()?,
+                })
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Transfer {
+            pub from: Vec<u8>,
+            pub to: Vec<u8>,
+            pub value: substreams::scalar::BigInt,
+        }
+        impl Transfer {
+            pub const TOPIC_ID: [u8; 32] = [
+                221u8,
+                242u8,
+                82u8,
+                173u8,
+                27u8,
+                226u8,
+                200u8,
+                155u8,
+                105u8,
+                194u8,
+                176u8,
+                104u8,
+                252u8,
+                55u8,
+                141u8,
+                170u8,
+                149u8,
+                43u8,
+                167u8,
+                241u8,
+                99u8,
+                196u8,
+                161u8,
+                22u8,
+                40u8,
+                245u8,
+                90u8,
+                77u8,
+                245u8,
+                35u8,
+                179u8,
+                239u8,
+            ];
+            pub const SIGNATURE: &'static str = "Transfer(address,address,uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["from", "to", "value"];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "address",
+                "address",
+                "uint256",
+            ];
+            pub fn is_anonymous() -> bool {
+                false
+            }
+            pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                if log.topics.len() != 3usize {
+                    return false;
+                }
+                if log.data.len() != 32usize {
+                    return false;
+                }
+                return log.topics.get(0).expect("bounds already checked").as_ref()
+                    == Self::TOPIC_ID;
+            }
+            pub fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
+                Ok(Self {
+                    from: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "from",
+                            "address",
+                            1usize,
+                        );
+                        topic.address(0usize)?.to_vec()
+                    },
+                    to: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[2usize],
+                            "to",
+                            "address",
+                            2usize,
+                        );
+                        topic.address(0usize)?.to_vec()
+                    },
+                    value: decoder.big_uint(0usize)?,
+                })
+            }
+            /// Decodes only the `from` parameter of `log`, without decoding the whole event.
+            pub fn decode_from(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[1usize],
+                    "from",
+                    "address",
+                    1usize,
+                );
+                Ok(topic.address(0usize)?.to_vec())
+            }
+            /// Decodes only the `to` parameter of `log`, without decoding the whole event.
+            pub fn decode_to(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[2usize],
+                    "to",
+                    "address",
+                    2usize,
+                );
+                Ok(topic.address(0usize)?.to_vec())
+            }
+            /// Decodes only the `value` parameter of `log`, without decoding the whole event.
+            pub fn decode_value(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data).big_uint(0usize)
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        Self::TOPIC_ID.to_vec(),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.from.as_slice()); }),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.to.as_slice()); })
+                    ],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        32usize,
+                        |encoder| {
+                            encoder
+                                .word(
+                                    substreams_ethereum::abi::encode_big_uint(&self.value),
+                                );
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
+        }
+        impl substreams_ethereum::Event for Transfer {
+            const NAME: &'static str = "Transfer";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
+            fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                Self::match_log(log)
+            }
+            fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(log)
+            }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [Transfer] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct TransferRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> TransferRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if Transfer::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn from(&self) -> Result<&'a [u8], substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[1usize],
+                    "from",
+                    "address",
+                    1usize,
+                );
+                topic.word(0usize).map(|word| &word[12..])
+            }
+            pub fn to(&self) -> Result<&'a [u8], substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[2usize],
+                    "to",
+                    "address",
+                    2usize,
+                );
+                topic.word(0usize).map(|word| &word[12..])
+            }
+            pub fn value(
+                &self,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data).big_uint(0usize)
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(self) -> Result<Transfer, substreams_ethereum::DecodeError> {
+                Ok(Transfer {
+                    from: self.from()?.to_vec(),
+                    to: self.to()?.to_vec(),
+                    value: self.value()?,
+                })
+            }
+        }
+    }
+    /// Enums shared by the contracts.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod enums {}
+    /// Structs shared by the contracts.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod structs {}
\ No newline at end of file
@@ -0,0 +1,514 @@
+    #[allow(unused_imports)]
+    pub use self::functions::Weth;
+    /// Contract's functions.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod functions {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct BalanceOf {
+            pub owner: Vec<u8>,
+        }
+        impl BalanceOf {
+            pub const METHOD_ID: [u8; 4] = [112u8, 160u8, 130u8, 49u8];
+            pub const SIGNATURE: &'static str = "balanceOf(address)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["owner"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["address"];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    owner: decoder.address(0usize)?.to_vec(),
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder.address(self.owner.as_slice());
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn output_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                Self::output(call.return_data.as_ref())
+            }
+            pub fn output(
+                data: &[u8],
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                decoder.big_uint(0usize)
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+            pub fn call(&self, address: Vec<u8>) -> Option<substreams::scalar::BigInt> {
+                use substreams_ethereum::pb::eth::rpc;
+                let rpc_calls = rpc::RpcCalls {
+                    calls: vec![
+                        rpc::RpcCall { to_addr : address, data : self.encode(), }
+                    ],
+                };
+                let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+                let response = responses
+                    .get(0)
+                    .expect("one response should have existed");
+                if response.failed {
+                    return None;
+                }
+                match Self::output(response.raw.as_ref()) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        use substreams_ethereum::Function;
+                        substreams::log::info!(
+                            "Call output for function `{}` failed to decode with error: {}",
+                            Self::NAME, err
+                        );
+                        None
+                    }
+                }
+            }
+        }
+        impl substreams_ethereum::Function for BalanceOf {
+            const NAME: &'static str = "balanceOf";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        impl substreams_ethereum::rpc::RPCDecodable<substreams::scalar::BigInt>
+        for BalanceOf {
+            fn output(
+                data: &[u8],
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                Self::output(data)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Deposit {}
+        impl Deposit {
+            pub const METHOD_ID: [u8; 4] = [208u8, 227u8, 13u8, 176u8];
+            pub const SIGNATURE: &'static str = "deposit()";
+            pub const PARAM_NAMES: &'static [&'static str] = &[];
+            pub const PARAM_TYPES: &'static [&'static str] = &[];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Ok(Self {})
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = Vec::<u8>::new();
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+        }
+        impl substreams_ethereum::Function for Deposit {
+            const NAME: &'static str = "deposit";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        /// Any of the contract's functions, see [Functions::match_and_decode].
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Functions {
+            BalanceOf(BalanceOf),
+            Deposit(Deposit),
+        }
+        impl Functions {
+            /// Decodes `call` into the function whose method id is the first 4 bytes of the call input.
+            pub fn match_and_decode(
+                call: impl AsRef<substreams_ethereum::pb::eth::v2::Call>,
+            ) -> Option<Self> {
+                use substreams_ethereum::Function;
+                let call = call.as_ref();
+                let method_id = <[u8; 4] as std::convert::TryFrom<
+                    &[u8],
+                >>::try_from(call.input.get(0..4)?)
+                    .ok()?;
+                match method_id {
+                    BalanceOf::METHOD_ID => {
+                        BalanceOf::match_and_decode(call).map(Functions::BalanceOf)
+                    }
+                    Deposit::METHOD_ID => {
+                        Deposit::match_and_decode(call).map(Functions::Deposit)
+                    }
+                    _ => None,
+                }
+            }
+            pub fn name(&self) -> &'static str {
+                match self {
+                    Functions::BalanceOf(_) => "balanceOf",
+                    Functions::Deposit(_) => "deposit",
+                }
+            }
+        }
+        impl substreams_ethereum::ContractFunctions for Functions {
+            fn match_and_decode(
+                call: impl AsRef<substreams_ethereum::pb::eth::v2::Call>,
+            ) -> Option<Self> {
+                Self::match_and_decode(call)
+            }
+            fn name(&self) -> &'static str {
+                self.name()
+            }
+        }
+        /// Handle on a deployed `weth` contract, its view functions are called through RPC.
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Weth {
+            pub address: Vec<u8>,
+        }
+        impl Weth {
+            pub const NAME: &'static str = "weth";
+            pub fn balance_of(
+                &self,
+                owner: Vec<u8>,
+            ) -> Option<substreams::scalar::BigInt> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(BalanceOf { owner }, self.address.to_vec())
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<
+                    _,
+                    BalanceOf,
+                >(responses.get(0)?)
+            }
+        }
+    }
+    /// Contract's events.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod events {
+        pub use super::super::shared::events::Approval;
+        pub use super::super::shared::events::ApprovalRef;
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Deposit {
+            pub dst: Vec<u8>,
+            pub wad: substreams::scalar::BigInt,
+        }
+        impl Deposit {
+            pub const TOPIC_ID: [u8; 32] = [
+                225u8,
+                255u8,
+                252u8,
+                196u8,
+                146u8,
+                61u8,
+                4u8,
+                181u8,
+                89u8,
+                244u8,
+                210u8,
+                154u8,
+                139u8,
+                252u8,
+                108u8,
+                218u8,
+                4u8,
+                235u8,
+                91u8,
+                13u8,
+                60u8,
+                70u8,
+                7u8,
+                81u8,
+                194u8,
+                64u8,
+                44u8,
+                92u8,
+                92u8,
+                201u8,
+                16u8,
+                156u8,
+            ];
+            pub const SIGNATURE: &'static str = "Deposit(address,uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["dst", "wad"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["address", "uint256"];
+            pub fn is_anonymous() -> bool {
+                false
+            }
+            pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                if log.topics.len() != 2usize {
+                    return false;
+                }
+                if log.data.len() != 32usize {
+                    return false;
+                }
+                return log.topics.get(0).expect("bounds already checked").as_ref()
+                    == Self::TOPIC_ID;
+            }
+            pub fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
+                Ok(Self {
+                    dst: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "dst",
+                            "address",
+                            1usize,
+                        );
+                        topic.address(0usize)?.to_vec()
+                    },
+                    wad: decoder.big_uint(0usize)?,
+                })
+            }
+            /// Decodes only the `dst` parameter of `log`, without decoding the whole event.
+            pub fn decode_dst(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[1usize],
+                    "dst",
+                    "address",
+                    1usize,
+                );
+                Ok(topic.address(0usize)?.to_vec())
+            }
+            /// Decodes only the `wad` parameter of `log`, without decoding the whole event.
+            pub fn decode_wad(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data).big_uint(0usize)
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        Self::TOPIC_ID.to_vec(),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.dst.as_slice()); })
+                    ],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        32usize,
+                        |encoder| {
+                            encoder
+                                .word(substreams_ethereum::abi::encode_big_uint(&self.wad));
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
+        }
+        impl substreams_ethereum::Event for Deposit {
+            const NAME: &'static str = "Deposit";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
+            fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                Self::match_log(log)
+            }
+            fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(log)
+            }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [Deposit] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct DepositRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> DepositRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if Deposit::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn dst(&self) -> Result<&'a [u8], substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[1usize],
+                    "dst",
+                    "address",
+                    1usize,
+                );
+                topic.word(0usize).map(|word| &word[12..])
+            }
+            pub fn wad(
+                &self,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data).big_uint(0usize)
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(self) -> Result<Deposit, substreams_ethereum::DecodeError> {
+                Ok(Deposit {
+                    dst: self.dst()?.to_vec(),
+                    wad: self.wad()?,
+                })
+            }
+        }
+        pub use super::super::shared::events::Transfer;
+        pub use super::super::shared::events::TransferRef;
+        /// Any of the contract's events, see [Events::match_and_decode].
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Events {
+            Approval(Approval),
+            Deposit(Deposit),
+            Transfer(Transfer),
+        }
+        impl Events {
+            /// Decodes `log` into the event whose signature is the first topic of the log.
+            pub fn match_and_decode(
+                log: impl AsRef<substreams_ethereum::pb::eth::v2::Log>,
+            ) -> Option<Self> {
+                use substreams_ethereum::Event;
+                let log = log.as_ref();
+                let topic = <[u8; 32] as std::convert::TryFrom<
+                    &[u8],
+                >>::try_from(log.topics.get(0)?.as_ref())
+                    .ok()?;
+                match topic {
+                    Approval::TOPIC_ID => {
+                        Approval::match_and_decode(log).map(Events::Approval)
+                    }
+                    Deposit::TOPIC_ID => {
+                        Deposit::match_and_decode(log).map(Events::Deposit)
+                    }
+                    Transfer::TOPIC_ID => {
+                        Transfer::match_and_decode(log).map(Events::Transfer)
+                    }
+                    _ => None,
+                }
+            }
+            pub fn name(&self) -> &'static str {
+                match self {
+                    Events::Approval(_) => "Approval",
+                    Events::Deposit(_) => "Deposit",
+                    Events::Transfer(_) => "Transfer",
+                }
+            }
+        }
+        impl substreams_ethereum::ContractEvents for Events {
+            fn match_and_decode(
+                log: impl AsRef<substreams_ethereum::pb::eth::v2::Log>,
+            ) -> Option<Self> {
+                Self::match_and_decode(log)
+            }
+            fn name(&self) -> &'static str {
+                self.name()
+            }
+        }
+    }
+    /// Contract's custom errors.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod errors {}
+    /// Contract's enums.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod enums {}
+    /// Contract's structs.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod structs {}
\ No newline at end of file
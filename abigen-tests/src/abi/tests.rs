@@ -1,106 +1,138 @@
-    const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+    #[allow(unused_imports)]
+    pub use self::functions::Tests;
     /// Contract's functions.
     #[allow(dead_code, unused_imports, unused_variables)]
     pub mod functions {
-        use super::INTERNAL_ERR;
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Constructor {
+            pub owner: Vec<u8>,
+            pub supply: substreams::scalar::BigInt,
+        }
+        impl Constructor {
+            /// Decodes the constructor arguments of a contract creation call, they are
+            /// appended to the init code whose length is `code_len`.
+            pub fn decode_from_creation_input(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+                code_len: usize,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if !Self::match_call(call) {
+                    return Err(substreams_ethereum::DecodeError::NotContractCreation);
+                }
+                let data = call
+                    .input
+                    .get(code_len..)
+                    .ok_or(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: code_len,
+                        actual: call.input.len(),
+                    })?;
+                Self::decode_at(data, code_len)
+            }
+            /// Decodes the ABI encoded constructor arguments, without the init code.
+            pub fn decode(
+                data: &[u8],
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode_at(data, 0)
+            }
+            /// Decodes the constructor arguments found at byte `offset` of the input, the
+            /// offset reported in errors.
+            fn decode_at(
+                data: &[u8],
+                offset: usize,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    data,
+                    offset,
+                );
+                Ok(Self {
+                    owner: decoder.address(0usize)?.to_vec(),
+                    supply: decoder.big_uint(32usize)?,
+                })
+            }
+            /// Encodes the creation input deploying `code` with these constructor arguments.
+            pub fn encode(&self, code: Vec<u8>) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    64usize,
+                    |encoder| {
+                        encoder.address(self.owner.as_slice());
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_uint(&self.supply),
+                            );
+                    },
+                );
+                let mut encoded = Vec::with_capacity(code.len() + data.len());
+                encoded.extend(code);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                call.call_type
+                    == substreams_ethereum::pb::eth::v2::CallType::Create as i32
+            }
+        }
         #[derive(Debug, Clone, PartialEq)]
         pub struct FixedArrayAddressArrayUint256ReturnsUint256String {
             pub param0: [Vec<u8>; 2usize],
             pub param1: Vec<substreams::scalar::BigInt>,
         }
         impl FixedArrayAddressArrayUint256ReturnsUint256String {
-            const METHOD_ID: [u8; 4] = [136u8, 229u8, 164u8, 109u8];
+            pub const METHOD_ID: [u8; 4] = [136u8, 229u8, 164u8, 109u8];
+            pub const SIGNATURE: &'static str = "FixedArrayAddressArrayUint256ReturnsUint256String(address[2],uint256[])";
+            pub const PARAM_NAMES: &'static [&'static str] = &["", ""];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "address[2]",
+                "uint256[]",
+            ];
             pub fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 let maybe_data = call.input.get(4..);
                 if maybe_data.is_none() {
-                    return Err("no data to decode".to_string());
-                }
-                let mut values = ethabi::decode(
-                        &[
-                            ethabi::ParamType::FixedArray(
-                                Box::new(ethabi::ParamType::Address),
-                                2usize,
-                            ),
-                            ethabi::ParamType::Array(
-                                Box::new(ethabi::ParamType::Uint(256usize)),
-                            ),
-                        ],
-                        maybe_data.unwrap(),
-                    )
-                    .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
-                values.reverse();
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
                 Ok(Self {
-                    param0: {
-                        let mut iter = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_fixed_array()
-                            .expect(INTERNAL_ERR)
-                            .into_iter()
-                            .map(|inner| {
-                                inner
-                                    .into_address()
-                                    .expect(INTERNAL_ERR)
-                                    .as_bytes()
-                                    .to_vec()
-                            });
-                        [
-                            iter.next().expect(INTERNAL_ERR),
-                            iter.next().expect(INTERNAL_ERR),
-                        ]
+                    param0: [
+                        decoder.address(0usize)?.to_vec(),
+                        decoder.address(32usize)?.to_vec(),
+                    ],
+                    param1: {
+                        let (len, elements) = decoder.array(64usize, 32usize)?;
+                        (0..len)
+                            .map(|i| -> Result<_, substreams_ethereum::DecodeError> {
+                                elements.big_uint(i * 32usize)
+                            })
+                            .collect::<
+                                Result<Vec<_>, substreams_ethereum::DecodeError>,
+                            >()?
                     },
-                    param1: values
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_array()
-                        .expect(INTERNAL_ERR)
-                        .into_iter()
-                        .map(|inner| {
-                            let mut v = [0 as u8; 32];
-                            inner
-                                .into_uint()
-                                .expect(INTERNAL_ERR)
-                                .to_big_endian(v.as_mut_slice());
-                            substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
-                        })
-                        .collect(),
                 })
             }
             pub fn encode(&self) -> Vec<u8> {
-                let data = ethabi::encode(
-                    &[
-                        {
-                            let v = self
-                                .param0
-                                .iter()
-                                .map(|inner| ethabi::Token::Address(
-                                    ethabi::Address::from_slice(&inner),
-                                ))
-                                .collect();
-                            ethabi::Token::FixedArray(v)
-                        },
-                        {
-                            let v = self
-                                .param1
-                                .iter()
-                                .map(|inner| ethabi::Token::Uint(
-                                    ethabi::Uint::from_big_endian(
-                                        match inner.clone().to_bytes_be() {
-                                            (num_bigint::Sign::Plus, bytes) => bytes,
-                                            (num_bigint::Sign::NoSign, bytes) => bytes,
-                                            (num_bigint::Sign::Minus, _) => {
-                                                panic!("negative numbers are not supported")
-                                            }
-                                        }
-                                            .as_slice(),
-                                    ),
-                                ))
-                                .collect();
-                            ethabi::Token::Array(v)
-                        },
-                    ],
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    96usize,
+                    |encoder| {
+                        for inner in self.param0.iter() {
+                            encoder.address(inner.as_slice());
+                        }
+                        encoder
+                            .array(
+                                self.param1.len(),
+                                32usize,
+                                |elements| {
+                                    for inner in self.param1.iter() {
+                                        elements
+                                            .word(substreams_ethereum::abi::encode_big_uint(&inner));
+                                    }
+                                },
+                            );
+                    },
                 );
                 let mut encoded = Vec::with_capacity(4 + data.len());
                 encoded.extend(Self::METHOD_ID);
@@ -109,31 +141,20 @@
             }
             pub fn output_call(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<(substreams::scalar::BigInt, String), String> {
+            ) -> Result<
+                (substreams::scalar::BigInt, String),
+                substreams_ethereum::DecodeError,
+            > {
                 Self::output(call.return_data.as_ref())
             }
             pub fn output(
                 data: &[u8],
-            ) -> Result<(substreams::scalar::BigInt, String), String> {
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::Uint(256usize), ethabi::ParamType::String],
-                        data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode output data: {:?}", e))?;
-                values.reverse();
-                Ok((
-                    {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_uint()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
-                    },
-                    values.pop().expect(INTERNAL_ERR).into_string().expect(INTERNAL_ERR),
-                ))
+            ) -> Result<
+                (substreams::scalar::BigInt, String),
+                substreams_ethereum::DecodeError,
+            > {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                Ok((decoder.big_uint(0usize)?, decoder.string(32usize)?))
             }
             pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 match call.input.get(0..4) {
@@ -174,12 +195,16 @@
         impl substreams_ethereum::Function
         for FixedArrayAddressArrayUint256ReturnsUint256String {
             const NAME: &'static str = "FixedArrayAddressArrayUint256ReturnsUint256String";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
             fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 Self::match_call(call)
             }
             fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(call)
             }
             fn encode(&self) -> Vec<u8> {
@@ -190,7 +215,10 @@
         for FixedArrayAddressArrayUint256ReturnsUint256String {
             fn output(
                 data: &[u8],
-            ) -> Result<(substreams::scalar::BigInt, String), String> {
+            ) -> Result<
+                (substreams::scalar::BigInt, String),
+                substreams_ethereum::DecodeError,
+            > {
                 Self::output(data)
             }
         }
@@ -200,84 +228,62 @@
             pub param1: Vec<Vec<u8>>,
         }
         impl FixedArrayAddressArrayAddressReturnsUint256String {
-            const METHOD_ID: [u8; 4] = [222u8, 196u8, 49u8, 26u8];
+            pub const METHOD_ID: [u8; 4] = [222u8, 196u8, 49u8, 26u8];
+            pub const SIGNATURE: &'static str = "fixedArrayAddressArrayAddressReturnsUint256String(address[2],address[])";
+            pub const PARAM_NAMES: &'static [&'static str] = &["", ""];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "address[2]",
+                "address[]",
+            ];
             pub fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 let maybe_data = call.input.get(4..);
                 if maybe_data.is_none() {
-                    return Err("no data to decode".to_string());
-                }
-                let mut values = ethabi::decode(
-                        &[
-                            ethabi::ParamType::FixedArray(
-                                Box::new(ethabi::ParamType::Address),
-                                2usize,
-                            ),
-                            ethabi::ParamType::Array(
-                                Box::new(ethabi::ParamType::Address),
-                            ),
-                        ],
-                        maybe_data.unwrap(),
-                    )
-                    .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
-                values.reverse();
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
                 Ok(Self {
-                    param0: {
-                        let mut iter = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_fixed_array()
-                            .expect(INTERNAL_ERR)
-                            .into_iter()
-                            .map(|inner| {
-                                inner
-                                    .into_address()
-                                    .expect(INTERNAL_ERR)
-                                    .as_bytes()
-                                    .to_vec()
-                            });
-                        [
-                            iter.next().expect(INTERNAL_ERR),
-                            iter.next().expect(INTERNAL_ERR),
-                        ]
+                    param0: [
+                        decoder.address(0usize)?.to_vec(),
+                        decoder.address(32usize)?.to_vec(),
+                    ],
+                    param1: {
+                        let (len, elements) = decoder.array(64usize, 32usize)?;
+                        (0..len)
+                            .map(|i| -> Result<_, substreams_ethereum::DecodeError> {
+                                Ok(elements.address(i * 32usize)?.to_vec())
+                            })
+                            .collect::<
+                                Result<Vec<_>, substreams_ethereum::DecodeError>,
+                            >()?
                     },
-                    param1: values
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_array()
-                        .expect(INTERNAL_ERR)
-                        .into_iter()
-                        .map(|inner| {
-                            inner.into_address().expect(INTERNAL_ERR).as_bytes().to_vec()
-                        })
-                        .collect(),
                 })
             }
             pub fn encode(&self) -> Vec<u8> {
-                let data = ethabi::encode(
-                    &[
-                        {
-                            let v = self
-                                .param0
-                                .iter()
-                                .map(|inner| ethabi::Token::Address(
-                                    ethabi::Address::from_slice(&inner),
-                                ))
-                                .collect();
-                            ethabi::Token::FixedArray(v)
-                        },
-                        {
-                            let v = self
-                                .param1
-                                .iter()
-                                .map(|inner| ethabi::Token::Address(
-                                    ethabi::Address::from_slice(&inner),
-                                ))
-                                .collect();
-                            ethabi::Token::Array(v)
-                        },
-                    ],
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    96usize,
+                    |encoder| {
+                        for inner in self.param0.iter() {
+                            encoder.address(inner.as_slice());
+                        }
+                        encoder
+                            .array(
+                                self.param1.len(),
+                                32usize,
+                                |elements| {
+                                    for inner in self.param1.iter() {
+                                        elements.address(inner.as_slice());
+                                    }
+                                },
+                            );
+                    },
                 );
                 let mut encoded = Vec::with_capacity(4 + data.len());
                 encoded.extend(Self::METHOD_ID);
@@ -286,31 +292,20 @@
             }
             pub fn output_call(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<(substreams::scalar::BigInt, String), String> {
+            ) -> Result<
+                (substreams::scalar::BigInt, String),
+                substreams_ethereum::DecodeError,
+            > {
                 Self::output(call.return_data.as_ref())
             }
             pub fn output(
                 data: &[u8],
-            ) -> Result<(substreams::scalar::BigInt, String), String> {
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::Uint(256usize), ethabi::ParamType::String],
-                        data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode output data: {:?}", e))?;
-                values.reverse();
-                Ok((
-                    {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_uint()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
-                    },
-                    values.pop().expect(INTERNAL_ERR).into_string().expect(INTERNAL_ERR),
-                ))
+            ) -> Result<
+                (substreams::scalar::BigInt, String),
+                substreams_ethereum::DecodeError,
+            > {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                Ok((decoder.big_uint(0usize)?, decoder.string(32usize)?))
             }
             pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 match call.input.get(0..4) {
@@ -351,12 +346,16 @@
         impl substreams_ethereum::Function
         for FixedArrayAddressArrayAddressReturnsUint256String {
             const NAME: &'static str = "fixedArrayAddressArrayAddressReturnsUint256String";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
             fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 Self::match_call(call)
             }
             fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(call)
             }
             fn encode(&self) -> Vec<u8> {
@@ -367,7 +366,10 @@
         for FixedArrayAddressArrayAddressReturnsUint256String {
             fn output(
                 data: &[u8],
-            ) -> Result<(substreams::scalar::BigInt, String), String> {
+            ) -> Result<
+                (substreams::scalar::BigInt, String),
+                substreams_ethereum::DecodeError,
+            > {
                 Self::output(data)
             }
         }
@@ -385,186 +387,103 @@
             pub param9: Vec<Vec<u8>>,
         }
         impl FunAll {
-            const METHOD_ID: [u8; 4] = [26u8, 249u8, 60u8, 49u8];
+            pub const METHOD_ID: [u8; 4] = [26u8, 249u8, 60u8, 49u8];
+            pub const SIGNATURE: &'static str = "funAll(address,bytes,bytes8,bytes32,int256,uint256,bool,string,address[2],address[])";
+            pub const PARAM_NAMES: &'static [&'static str] = &[
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+            ];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "address",
+                "bytes",
+                "bytes8",
+                "bytes32",
+                "int256",
+                "uint256",
+                "bool",
+                "string",
+                "address[2]",
+                "address[]",
+            ];
             pub fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 let maybe_data = call.input.get(4..);
                 if maybe_data.is_none() {
-                    return Err("no data to decode".to_string());
-                }
-                let mut values = ethabi::decode(
-                        &[
-                            ethabi::ParamType::Address,
-                            ethabi::ParamType::Bytes,
-                            ethabi::ParamType::FixedBytes(8usize),
-                            ethabi::ParamType::FixedBytes(32usize),
-                            ethabi::ParamType::Int(256usize),
-                            ethabi::ParamType::Uint(256usize),
-                            ethabi::ParamType::Bool,
-                            ethabi::ParamType::String,
-                            ethabi::ParamType::FixedArray(
-                                Box::new(ethabi::ParamType::Address),
-                                2usize,
-                            ),
-                            ethabi::ParamType::Array(
-                                Box::new(ethabi::ParamType::Address),
-                            ),
-                        ],
-                        maybe_data.unwrap(),
-                    )
-                    .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
-                values.reverse();
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
                 Ok(Self {
-                    param0: values
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_address()
-                        .expect(INTERNAL_ERR)
-                        .as_bytes()
-                        .to_vec(),
-                    param1: values
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_bytes()
-                        .expect(INTERNAL_ERR),
-                    param2: {
-                        let mut result = [0u8; 8];
-                        let v = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_fixed_bytes()
-                            .expect(INTERNAL_ERR);
-                        result.copy_from_slice(&v);
-                        result
-                    },
-                    param3: {
-                        let mut result = [0u8; 32];
-                        let v = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_fixed_bytes()
-                            .expect(INTERNAL_ERR);
-                        result.copy_from_slice(&v);
-                        result
-                    },
-                    param4: {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_int()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_signed_bytes_be(&v)
-                    },
-                    param5: {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_uint()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
-                    },
-                    param6: values
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_bool()
-                        .expect(INTERNAL_ERR),
-                    param7: values
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_string()
-                        .expect(INTERNAL_ERR),
-                    param8: {
-                        let mut iter = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_fixed_array()
-                            .expect(INTERNAL_ERR)
-                            .into_iter()
-                            .map(|inner| {
-                                inner
-                                    .into_address()
-                                    .expect(INTERNAL_ERR)
-                                    .as_bytes()
-                                    .to_vec()
-                            });
-                        [
-                            iter.next().expect(INTERNAL_ERR),
-                            iter.next().expect(INTERNAL_ERR),
-                        ]
+                    param0: decoder.address(0usize)?.to_vec(),
+                    param1: decoder.bytes(32usize)?.to_vec(),
+                    param2: decoder.fixed_bytes::<8usize>(64usize)?,
+                    param3: decoder.fixed_bytes::<32usize>(96usize)?,
+                    param4: decoder.big_int(128usize)?,
+                    param5: decoder.big_uint(160usize)?,
+                    param6: decoder.bool(192usize)?,
+                    param7: decoder.string(224usize)?,
+                    param8: [
+                        decoder.address(256usize)?.to_vec(),
+                        decoder.address(288usize)?.to_vec(),
+                    ],
+                    param9: {
+                        let (len, elements) = decoder.array(320usize, 32usize)?;
+                        (0..len)
+                            .map(|i| -> Result<_, substreams_ethereum::DecodeError> {
+                                Ok(elements.address(i * 32usize)?.to_vec())
+                            })
+                            .collect::<
+                                Result<Vec<_>, substreams_ethereum::DecodeError>,
+                            >()?
                     },
-                    param9: values
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_array()
-                        .expect(INTERNAL_ERR)
-                        .into_iter()
-                        .map(|inner| {
-                            inner.into_address().expect(INTERNAL_ERR).as_bytes().to_vec()
-                        })
-                        .collect(),
                 })
             }
             pub fn encode(&self) -> Vec<u8> {
-                let data = ethabi::encode(
-                    &[
-                        ethabi::Token::Address(
-                            ethabi::Address::from_slice(&self.param0),
-                        ),
-                        ethabi::Token::Bytes(self.param1.clone()),
-                        ethabi::Token::FixedBytes(self.param2.as_ref().to_vec()),
-                        ethabi::Token::FixedBytes(self.param3.as_ref().to_vec()),
-                        {
-                            let non_full_signed_bytes = self.param4.to_signed_bytes_be();
-                            let mut full_signed_bytes = [0xff as u8; 32];
-                            non_full_signed_bytes
-                                .into_iter()
-                                .rev()
-                                .enumerate()
-                                .for_each(|(i, byte)| full_signed_bytes[31 - i] = byte);
-                            ethabi::Token::Int(
-                                ethabi::Int::from_big_endian(full_signed_bytes.as_ref()),
-                            )
-                        },
-                        ethabi::Token::Uint(
-                            ethabi::Uint::from_big_endian(
-                                match self.param5.clone().to_bytes_be() {
-                                    (num_bigint::Sign::Plus, bytes) => bytes,
-                                    (num_bigint::Sign::NoSign, bytes) => bytes,
-                                    (num_bigint::Sign::Minus, _) => {
-                                        panic!("negative numbers are not supported")
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    352usize,
+                    |encoder| {
+                        encoder.address(self.param0.as_slice());
+                        encoder.bytes(self.param1.as_slice());
+                        encoder.fixed_bytes(self.param2.as_slice());
+                        encoder.fixed_bytes(self.param3.as_slice());
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_int(&self.param4),
+                            );
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_uint(&self.param5),
+                            );
+                        encoder.bool(self.param6);
+                        encoder.string(self.param7.as_str());
+                        for inner in self.param8.iter() {
+                            encoder.address(inner.as_slice());
+                        }
+                        encoder
+                            .array(
+                                self.param9.len(),
+                                32usize,
+                                |elements| {
+                                    for inner in self.param9.iter() {
+                                        elements.address(inner.as_slice());
                                     }
-                                }
-                                    .as_slice(),
-                            ),
-                        ),
-                        ethabi::Token::Bool(self.param6.clone()),
-                        ethabi::Token::String(self.param7.clone()),
-                        {
-                            let v = self
-                                .param8
-                                .iter()
-                                .map(|inner| ethabi::Token::Address(
-                                    ethabi::Address::from_slice(&inner),
-                                ))
-                                .collect();
-                            ethabi::Token::FixedArray(v)
-                        },
-                        {
-                            let v = self
-                                .param9
-                                .iter()
-                                .map(|inner| ethabi::Token::Address(
-                                    ethabi::Address::from_slice(&inner),
-                                ))
-                                .collect();
-                            ethabi::Token::Array(v)
-                        },
-                    ],
+                                },
+                            );
+                    },
                 );
                 let mut encoded = Vec::with_capacity(4 + data.len());
                 encoded.extend(Self::METHOD_ID);
@@ -580,12 +499,16 @@
         }
         impl substreams_ethereum::Function for FunAll {
             const NAME: &'static str = "funAll";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
             fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 Self::match_call(call)
             }
             fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(call)
             }
             fn encode(&self) -> Vec<u8> {
@@ -597,43 +520,52 @@
             pub param0: Vec<bool>,
         }
         impl FunDynamicBoolArray {
-            const METHOD_ID: [u8; 4] = [176u8, 230u8, 21u8, 120u8];
+            pub const METHOD_ID: [u8; 4] = [176u8, 230u8, 21u8, 120u8];
+            pub const SIGNATURE: &'static str = "funDynamicBoolArray(bool[])";
+            pub const PARAM_NAMES: &'static [&'static str] = &[""];
+            pub const PARAM_TYPES: &'static [&'static str] = &["bool[]"];
             pub fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 let maybe_data = call.input.get(4..);
                 if maybe_data.is_none() {
-                    return Err("no data to decode".to_string());
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
                 }
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bool))],
-                        maybe_data.unwrap(),
-                    )
-                    .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
-                values.reverse();
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
                 Ok(Self {
-                    param0: values
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_array()
-                        .expect(INTERNAL_ERR)
-                        .into_iter()
-                        .map(|inner| inner.into_bool().expect(INTERNAL_ERR))
-                        .collect(),
+                    param0: {
+                        let (len, elements) = decoder.array(0usize, 32usize)?;
+                        (0..len)
+                            .map(|i| -> Result<_, substreams_ethereum::DecodeError> {
+                                elements.bool(i * 32usize)
+                            })
+                            .collect::<
+                                Result<Vec<_>, substreams_ethereum::DecodeError>,
+                            >()?
+                    },
                 })
             }
             pub fn encode(&self) -> Vec<u8> {
-                let data = ethabi::encode(
-                    &[
-                        {
-                            let v = self
-                                .param0
-                                .iter()
-                                .map(|inner| ethabi::Token::Bool(inner.clone()))
-                                .collect();
-                            ethabi::Token::Array(v)
-                        },
-                    ],
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder
+                            .array(
+                                self.param0.len(),
+                                32usize,
+                                |elements| {
+                                    for inner in self.param0.iter() {
+                                        elements.bool(*inner);
+                                    }
+                                },
+                            );
+                    },
                 );
                 let mut encoded = Vec::with_capacity(4 + data.len());
                 encoded.extend(Self::METHOD_ID);
@@ -649,12 +581,16 @@
         }
         impl substreams_ethereum::Function for FunDynamicBoolArray {
             const NAME: &'static str = "funDynamicBoolArray";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
             fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 Self::match_call(call)
             }
             fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(call)
             }
             fn encode(&self) -> Vec<u8> {
@@ -666,49 +602,37 @@
             pub param0: substreams::scalar::BigInt,
         }
         impl FunInt256 {
-            const METHOD_ID: [u8; 4] = [247u8, 10u8, 247u8, 59u8];
+            pub const METHOD_ID: [u8; 4] = [247u8, 10u8, 247u8, 59u8];
+            pub const SIGNATURE: &'static str = "funInt256(int256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &[""];
+            pub const PARAM_TYPES: &'static [&'static str] = &["int256"];
             pub fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 let maybe_data = call.input.get(4..);
                 if maybe_data.is_none() {
-                    return Err("no data to decode".to_string());
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
                 }
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::Int(256usize)],
-                        maybe_data.unwrap(),
-                    )
-                    .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
-                values.reverse();
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
                 Ok(Self {
-                    param0: {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_int()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_signed_bytes_be(&v)
-                    },
+                    param0: decoder.big_int(0usize)?,
                 })
             }
             pub fn encode(&self) -> Vec<u8> {
-                let data = ethabi::encode(
-                    &[
-                        {
-                            let non_full_signed_bytes = self.param0.to_signed_bytes_be();
-                            let mut full_signed_bytes = [0xff as u8; 32];
-                            non_full_signed_bytes
-                                .into_iter()
-                                .rev()
-                                .enumerate()
-                                .for_each(|(i, byte)| full_signed_bytes[31 - i] = byte);
-                            ethabi::Token::Int(
-                                ethabi::Int::from_big_endian(full_signed_bytes.as_ref()),
-                            )
-                        },
-                    ],
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_int(&self.param0),
+                            );
+                    },
                 );
                 let mut encoded = Vec::with_capacity(4 + data.len());
                 encoded.extend(Self::METHOD_ID);
@@ -724,12 +648,16 @@
         }
         impl substreams_ethereum::Function for FunInt256 {
             const NAME: &'static str = "funInt256";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
             fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 Self::match_call(call)
             }
             fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(call)
             }
             fn encode(&self) -> Vec<u8> {
@@ -738,52 +666,37 @@
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct FunInt32 {
-            pub param0: substreams::scalar::BigInt,
+            pub param0: i32,
         }
         impl FunInt32 {
-            const METHOD_ID: [u8; 4] = [215u8, 140u8, 170u8, 179u8];
+            pub const METHOD_ID: [u8; 4] = [215u8, 140u8, 170u8, 179u8];
+            pub const SIGNATURE: &'static str = "funInt32(int32)";
+            pub const PARAM_NAMES: &'static [&'static str] = &[""];
+            pub const PARAM_TYPES: &'static [&'static str] = &["int32"];
             pub fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 let maybe_data = call.input.get(4..);
                 if maybe_data.is_none() {
-                    return Err("no data to decode".to_string());
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
                 }
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::Int(32usize)],
-                        maybe_data.unwrap(),
-                    )
-                    .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
-                values.reverse();
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
                 Ok(Self {
-                    param0: {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_int()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_signed_bytes_be(&v)
-                    },
+                    param0: decoder.int::<i32>(0usize, 32usize)?,
                 })
             }
             pub fn encode(&self) -> Vec<u8> {
-                let data = ethabi::encode(
-                    &[
-                        {
-                            let non_full_signed_bytes = self.param0.to_signed_bytes_be();
-                            let mut full_signed_bytes = [0xff as u8; 32];
-                            non_full_signed_bytes
-                                .into_iter()
-                                .rev()
-                                .enumerate()
-                                .for_each(|(i, byte)| full_signed_bytes[31 - i] = byte);
-                            ethabi::Token::Int(
-                                ethabi::Int::from_big_endian(full_signed_bytes.as_ref()),
-                            )
-                        },
-                    ],
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder.word(substreams_ethereum::abi::encode_int(self.param0));
+                    },
                 );
                 let mut encoded = Vec::with_capacity(4 + data.len());
                 encoded.extend(Self::METHOD_ID);
@@ -799,12 +712,16 @@
         }
         impl substreams_ethereum::Function for FunInt32 {
             const NAME: &'static str = "funInt32";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
             fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 Self::match_call(call)
             }
             fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(call)
             }
             fn encode(&self) -> Vec<u8> {
@@ -813,52 +730,37 @@
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct FunInt8 {
-            pub param0: substreams::scalar::BigInt,
+            pub param0: i8,
         }
         impl FunInt8 {
-            const METHOD_ID: [u8; 4] = [48u8, 54u8, 230u8, 135u8];
+            pub const METHOD_ID: [u8; 4] = [48u8, 54u8, 230u8, 135u8];
+            pub const SIGNATURE: &'static str = "funInt8(int8)";
+            pub const PARAM_NAMES: &'static [&'static str] = &[""];
+            pub const PARAM_TYPES: &'static [&'static str] = &["int8"];
             pub fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 let maybe_data = call.input.get(4..);
                 if maybe_data.is_none() {
-                    return Err("no data to decode".to_string());
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
                 }
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::Int(8usize)],
-                        maybe_data.unwrap(),
-                    )
-                    .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
-                values.reverse();
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
                 Ok(Self {
-                    param0: {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_int()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_signed_bytes_be(&v)
-                    },
+                    param0: decoder.int::<i8>(0usize, 8usize)?,
                 })
             }
             pub fn encode(&self) -> Vec<u8> {
-                let data = ethabi::encode(
-                    &[
-                        {
-                            let non_full_signed_bytes = self.param0.to_signed_bytes_be();
-                            let mut full_signed_bytes = [0xff as u8; 32];
-                            non_full_signed_bytes
-                                .into_iter()
-                                .rev()
-                                .enumerate()
-                                .for_each(|(i, byte)| full_signed_bytes[31 - i] = byte);
-                            ethabi::Token::Int(
-                                ethabi::Int::from_big_endian(full_signed_bytes.as_ref()),
-                            )
-                        },
-                    ],
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder.word(substreams_ethereum::abi::encode_int(self.param0));
+                    },
                 );
                 let mut encoded = Vec::with_capacity(4 + data.len());
                 encoded.extend(Self::METHOD_ID);
@@ -874,12 +776,16 @@
         }
         impl substreams_ethereum::Function for FunInt8 {
             const NAME: &'static str = "funInt8";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
             fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 Self::match_call(call)
             }
             fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(call)
             }
             fn encode(&self) -> Vec<u8> {
@@ -888,126 +794,54 @@
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct FunInt8Int32Int64Int256 {
-            pub param0: substreams::scalar::BigInt,
-            pub param1: substreams::scalar::BigInt,
-            pub param2: substreams::scalar::BigInt,
+            pub param0: i8,
+            pub param1: i32,
+            pub param2: i64,
             pub param3: substreams::scalar::BigInt,
         }
         impl FunInt8Int32Int64Int256 {
-            const METHOD_ID: [u8; 4] = [219u8, 97u8, 126u8, 143u8];
+            pub const METHOD_ID: [u8; 4] = [219u8, 97u8, 126u8, 143u8];
+            pub const SIGNATURE: &'static str = "funInt8Int32Int64Int256(int8,int32,int64,int256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["", "", "", ""];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "int8",
+                "int32",
+                "int64",
+                "int256",
+            ];
             pub fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 let maybe_data = call.input.get(4..);
                 if maybe_data.is_none() {
-                    return Err("no data to decode".to_string());
-                }
-                let mut values = ethabi::decode(
-                        &[
-                            ethabi::ParamType::Int(8usize),
-                            ethabi::ParamType::Int(32usize),
-                            ethabi::ParamType::Int(64usize),
-                            ethabi::ParamType::Int(256usize),
-                        ],
-                        maybe_data.unwrap(),
-                    )
-                    .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
-                values.reverse();
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
                 Ok(Self {
-                    param0: {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_int()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_signed_bytes_be(&v)
-                    },
-                    param1: {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_int()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_signed_bytes_be(&v)
-                    },
-                    param2: {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_int()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_signed_bytes_be(&v)
-                    },
-                    param3: {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_int()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_signed_bytes_be(&v)
-                    },
+                    param0: decoder.int::<i8>(0usize, 8usize)?,
+                    param1: decoder.int::<i32>(32usize, 32usize)?,
+                    param2: decoder.int::<i64>(64usize, 64usize)?,
+                    param3: decoder.big_int(96usize)?,
                 })
             }
             pub fn encode(&self) -> Vec<u8> {
-                let data = ethabi::encode(
-                    &[
-                        {
-                            let non_full_signed_bytes = self.param0.to_signed_bytes_be();
-                            let mut full_signed_bytes = [0xff as u8; 32];
-                            non_full_signed_bytes
-                                .into_iter()
-                                .rev()
-                                .enumerate()
-                                .for_each(|(i, byte)| full_signed_bytes[31 - i] = byte);
-                            ethabi::Token::Int(
-                                ethabi::Int::from_big_endian(full_signed_bytes.as_ref()),
-                            )
-                        },
-                        {
-                            let non_full_signed_bytes = self.param1.to_signed_bytes_be();
-                            let mut full_signed_bytes = [0xff as u8; 32];
-                            non_full_signed_bytes
-                                .into_iter()
-                                .rev()
-                                .enumerate()
-                                .for_each(|(i, byte)| full_signed_bytes[31 - i] = byte);
-                            ethabi::Token::Int(
-                                ethabi::Int::from_big_endian(full_signed_bytes.as_ref()),
-                            )
-                        },
-                        {
-                            let non_full_signed_bytes = self.param2.to_signed_bytes_be();
-                            let mut full_signed_bytes = [0xff as u8; 32];
-                            non_full_signed_bytes
-                                .into_iter()
-                                .rev()
-                                .enumerate()
-                                .for_each(|(i, byte)| full_signed_bytes[31 - i] = byte);
-                            ethabi::Token::Int(
-                                ethabi::Int::from_big_endian(full_signed_bytes.as_ref()),
-                            )
-                        },
-                        {
-                            let non_full_signed_bytes = self.param3.to_signed_bytes_be();
-                            let mut full_signed_bytes = [0xff as u8; 32];
-                            non_full_signed_bytes
-                                .into_iter()
-                                .rev()
-                                .enumerate()
-                                .for_each(|(i, byte)| full_signed_bytes[31 - i] = byte);
-                            ethabi::Token::Int(
-                                ethabi::Int::from_big_endian(full_signed_bytes.as_ref()),
-                            )
-                        },
-                    ],
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    128usize,
+                    |encoder| {
+                        encoder.word(substreams_ethereum::abi::encode_int(self.param0));
+                        encoder.word(substreams_ethereum::abi::encode_int(self.param1));
+                        encoder.word(substreams_ethereum::abi::encode_int(self.param2));
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_int(&self.param3),
+                            );
+                    },
                 );
                 let mut encoded = Vec::with_capacity(4 + data.len());
                 encoded.extend(Self::METHOD_ID);
@@ -1023,12 +857,16 @@
         }
         impl substreams_ethereum::Function for FunInt8Int32Int64Int256 {
             const NAME: &'static str = "funInt8Int32Int64Int256";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
             fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 Self::match_call(call)
             }
             fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(call)
             }
             fn encode(&self) -> Vec<u8> {
@@ -1038,14 +876,17 @@
         #[derive(Debug, Clone, PartialEq)]
         pub struct FunReturnsString1 {}
         impl FunReturnsString1 {
-            const METHOD_ID: [u8; 4] = [122u8, 55u8, 25u8, 240u8];
+            pub const METHOD_ID: [u8; 4] = [122u8, 55u8, 25u8, 240u8];
+            pub const SIGNATURE: &'static str = "funReturnsString()";
+            pub const PARAM_NAMES: &'static [&'static str] = &[];
+            pub const PARAM_TYPES: &'static [&'static str] = &[];
             pub fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Ok(Self {})
             }
             pub fn encode(&self) -> Vec<u8> {
-                let data = ethabi::encode(&[]);
+                let data = Vec::<u8>::new();
                 let mut encoded = Vec::with_capacity(4 + data.len());
                 encoded.extend(Self::METHOD_ID);
                 encoded.extend(data);
@@ -1053,22 +894,14 @@
             }
             pub fn output_call(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<String, String> {
+            ) -> Result<String, substreams_ethereum::DecodeError> {
                 Self::output(call.return_data.as_ref())
             }
-            pub fn output(data: &[u8]) -> Result<String, String> {
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::String],
-                        data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode output data: {:?}", e))?;
-                Ok(
-                    values
-                        .pop()
-                        .expect("one output data should have existed")
-                        .into_string()
-                        .expect(INTERNAL_ERR),
-                )
+            pub fn output(
+                data: &[u8],
+            ) -> Result<String, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                decoder.string(0usize)
             }
             pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 match call.input.get(0..4) {
@@ -1105,12 +938,16 @@
         }
         impl substreams_ethereum::Function for FunReturnsString1 {
             const NAME: &'static str = "funReturnsString1";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
             fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 Self::match_call(call)
             }
             fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(call)
             }
             fn encode(&self) -> Vec<u8> {
@@ -1118,21 +955,24 @@
             }
         }
         impl substreams_ethereum::rpc::RPCDecodable<String> for FunReturnsString1 {
-            fn output(data: &[u8]) -> Result<String, String> {
+            fn output(data: &[u8]) -> Result<String, substreams_ethereum::DecodeError> {
                 Self::output(data)
             }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct FunReturnsString2 {}
         impl FunReturnsString2 {
-            const METHOD_ID: [u8; 4] = [122u8, 55u8, 25u8, 240u8];
+            pub const METHOD_ID: [u8; 4] = [122u8, 55u8, 25u8, 240u8];
+            pub const SIGNATURE: &'static str = "funReturnsString()";
+            pub const PARAM_NAMES: &'static [&'static str] = &[];
+            pub const PARAM_TYPES: &'static [&'static str] = &[];
             pub fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Ok(Self {})
             }
             pub fn encode(&self) -> Vec<u8> {
-                let data = ethabi::encode(&[]);
+                let data = Vec::<u8>::new();
                 let mut encoded = Vec::with_capacity(4 + data.len());
                 encoded.extend(Self::METHOD_ID);
                 encoded.extend(data);
@@ -1140,22 +980,14 @@
             }
             pub fn output_call(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<String, String> {
+            ) -> Result<String, substreams_ethereum::DecodeError> {
                 Self::output(call.return_data.as_ref())
             }
-            pub fn output(data: &[u8]) -> Result<String, String> {
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::String],
-                        data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode output data: {:?}", e))?;
-                Ok(
-                    values
-                        .pop()
-                        .expect("one output data should have existed")
-                        .into_string()
-                        .expect(INTERNAL_ERR),
-                )
+            pub fn output(
+                data: &[u8],
+            ) -> Result<String, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                decoder.string(0usize)
             }
             pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 match call.input.get(0..4) {
@@ -1192,12 +1024,16 @@
         }
         impl substreams_ethereum::Function for FunReturnsString2 {
             const NAME: &'static str = "funReturnsString2";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
             fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 Self::match_call(call)
             }
             fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(call)
             }
             fn encode(&self) -> Vec<u8> {
@@ -1205,21 +1041,24 @@
             }
         }
         impl substreams_ethereum::rpc::RPCDecodable<String> for FunReturnsString2 {
-            fn output(data: &[u8]) -> Result<String, String> {
+            fn output(data: &[u8]) -> Result<String, substreams_ethereum::DecodeError> {
                 Self::output(data)
             }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct FunReturnsStringString {}
         impl FunReturnsStringString {
-            const METHOD_ID: [u8; 4] = [133u8, 3u8, 47u8, 124u8];
+            pub const METHOD_ID: [u8; 4] = [133u8, 3u8, 47u8, 124u8];
+            pub const SIGNATURE: &'static str = "funReturnsStringString()";
+            pub const PARAM_NAMES: &'static [&'static str] = &[];
+            pub const PARAM_TYPES: &'static [&'static str] = &[];
             pub fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Ok(Self {})
             }
             pub fn encode(&self) -> Vec<u8> {
-                let data = ethabi::encode(&[]);
+                let data = Vec::<u8>::new();
                 let mut encoded = Vec::with_capacity(4 + data.len());
                 encoded.extend(Self::METHOD_ID);
                 encoded.extend(data);
@@ -1227,20 +1066,14 @@
             }
             pub fn output_call(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<(String, String), String> {
+            ) -> Result<(String, String), substreams_ethereum::DecodeError> {
                 Self::output(call.return_data.as_ref())
             }
-            pub fn output(data: &[u8]) -> Result<(String, String), String> {
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::String, ethabi::ParamType::String],
-                        data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode output data: {:?}", e))?;
-                values.reverse();
-                Ok((
-                    values.pop().expect(INTERNAL_ERR).into_string().expect(INTERNAL_ERR),
-                    values.pop().expect(INTERNAL_ERR).into_string().expect(INTERNAL_ERR),
-                ))
+            pub fn output(
+                data: &[u8],
+            ) -> Result<(String, String), substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                Ok((decoder.string(0usize)?, decoder.string(32usize)?))
             }
             pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 match call.input.get(0..4) {
@@ -1277,12 +1110,16 @@
         }
         impl substreams_ethereum::Function for FunReturnsStringString {
             const NAME: &'static str = "funReturnsStringString";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
             fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 Self::match_call(call)
             }
             fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(call)
             }
             fn encode(&self) -> Vec<u8> {
@@ -1291,7 +1128,9 @@
         }
         impl substreams_ethereum::rpc::RPCDecodable<(String, String)>
         for FunReturnsStringString {
-            fn output(data: &[u8]) -> Result<(String, String), String> {
+            fn output(
+                data: &[u8],
+            ) -> Result<(String, String), substreams_ethereum::DecodeError> {
                 Self::output(data)
             }
         }
@@ -1300,30 +1139,35 @@
             pub first: String,
         }
         impl FunString {
-            const METHOD_ID: [u8; 4] = [176u8, 217u8, 68u8, 25u8];
+            pub const METHOD_ID: [u8; 4] = [176u8, 217u8, 68u8, 25u8];
+            pub const SIGNATURE: &'static str = "funString(string)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["first"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["string"];
             pub fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 let maybe_data = call.input.get(4..);
                 if maybe_data.is_none() {
-                    return Err("no data to decode".to_string());
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
                 }
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::String],
-                        maybe_data.unwrap(),
-                    )
-                    .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
-                values.reverse();
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
                 Ok(Self {
-                    first: values
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_string()
-                        .expect(INTERNAL_ERR),
+                    first: decoder.string(0usize)?,
                 })
             }
             pub fn encode(&self) -> Vec<u8> {
-                let data = ethabi::encode(&[ethabi::Token::String(self.first.clone())]);
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder.string(self.first.as_str());
+                    },
+                );
                 let mut encoded = Vec::with_capacity(4 + data.len());
                 encoded.extend(Self::METHOD_ID);
                 encoded.extend(data);
@@ -1338,12 +1182,16 @@
         }
         impl substreams_ethereum::Function for FunString {
             const NAME: &'static str = "funString";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
             fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 Self::match_call(call)
             }
             fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(call)
             }
             fn encode(&self) -> Vec<u8> {
@@ -1356,39 +1204,36 @@
             pub second: String,
         }
         impl FunStringString {
-            const METHOD_ID: [u8; 4] = [16u8, 173u8, 235u8, 27u8];
+            pub const METHOD_ID: [u8; 4] = [16u8, 173u8, 235u8, 27u8];
+            pub const SIGNATURE: &'static str = "funStringString(string,string)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["first", "second"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["string", "string"];
             pub fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 let maybe_data = call.input.get(4..);
                 if maybe_data.is_none() {
-                    return Err("no data to decode".to_string());
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
                 }
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::String, ethabi::ParamType::String],
-                        maybe_data.unwrap(),
-                    )
-                    .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
-                values.reverse();
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
                 Ok(Self {
-                    first: values
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_string()
-                        .expect(INTERNAL_ERR),
-                    second: values
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_string()
-                        .expect(INTERNAL_ERR),
+                    first: decoder.string(0usize)?,
+                    second: decoder.string(32usize)?,
                 })
             }
             pub fn encode(&self) -> Vec<u8> {
-                let data = ethabi::encode(
-                    &[
-                        ethabi::Token::String(self.first.clone()),
-                        ethabi::Token::String(self.second.clone()),
-                    ],
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    64usize,
+                    |encoder| {
+                        encoder.string(self.first.as_str());
+                        encoder.string(self.second.as_str());
+                    },
                 );
                 let mut encoded = Vec::with_capacity(4 + data.len());
                 encoded.extend(Self::METHOD_ID);
@@ -1404,65 +1249,166 @@
         }
         impl substreams_ethereum::Function for FunStringString {
             const NAME: &'static str = "funStringString";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                Self::match_call(call)
+            }
+            fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct FunStructOrder {
+            pub order: super::structs::Order,
+        }
+        impl FunStructOrder {
+            pub const METHOD_ID: [u8; 4] = [186u8, 247u8, 140u8, 25u8];
+            pub const SIGNATURE: &'static str = "funStructOrder((address,(uint8,uint256)[]))";
+            pub const PARAM_NAMES: &'static [&'static str] = &["order"];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "(address,(uint8,uint256)[])",
+            ];
+            pub fn decode(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = call.input.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    order: super::structs::Order::decode_tuple(decoder, 0usize)?,
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        self.order.encode_tuple(encoder);
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::METHOD_ID);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn output_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<super::structs::SpentItem, substreams_ethereum::DecodeError> {
+                Self::output(call.return_data.as_ref())
+            }
+            pub fn output(
+                data: &[u8],
+            ) -> Result<super::structs::SpentItem, substreams_ethereum::DecodeError> {
+                let decoder = substreams_ethereum::abi::Decoder::new(data);
+                super::structs::SpentItem::decode_tuple(decoder, 0usize)
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                match call.input.get(0..4) {
+                    Some(signature) => Self::METHOD_ID == signature,
+                    None => false,
+                }
+            }
+            pub fn call(&self, address: Vec<u8>) -> Option<super::structs::SpentItem> {
+                use substreams_ethereum::pb::eth::rpc;
+                let rpc_calls = rpc::RpcCalls {
+                    calls: vec![
+                        rpc::RpcCall { to_addr : address, data : self.encode(), }
+                    ],
+                };
+                let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+                let response = responses
+                    .get(0)
+                    .expect("one response should have existed");
+                if response.failed {
+                    return None;
+                }
+                match Self::output(response.raw.as_ref()) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        use substreams_ethereum::Function;
+                        substreams::log::info!(
+                            "Call output for function `{}` failed to decode with error: {}",
+                            Self::NAME, err
+                        );
+                        None
+                    }
+                }
+            }
+        }
+        impl substreams_ethereum::Function for FunStructOrder {
+            const NAME: &'static str = "funStructOrder";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
             fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 Self::match_call(call)
             }
             fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(call)
             }
             fn encode(&self) -> Vec<u8> {
                 self.encode()
             }
         }
+        impl substreams_ethereum::rpc::RPCDecodable<super::structs::SpentItem>
+        for FunStructOrder {
+            fn output(
+                data: &[u8],
+            ) -> Result<super::structs::SpentItem, substreams_ethereum::DecodeError> {
+                Self::output(data)
+            }
+        }
         #[derive(Debug, Clone, PartialEq)]
         pub struct FunTupleAddress {
             pub param0: (Vec<u8>,),
         }
         impl FunTupleAddress {
-            const METHOD_ID: [u8; 4] = [163u8, 105u8, 163u8, 201u8];
+            pub const METHOD_ID: [u8; 4] = [163u8, 105u8, 163u8, 201u8];
+            pub const SIGNATURE: &'static str = "funTupleAddress((address))";
+            pub const PARAM_NAMES: &'static [&'static str] = &[""];
+            pub const PARAM_TYPES: &'static [&'static str] = &["(address)"];
             pub fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 let maybe_data = call.input.get(4..);
                 if maybe_data.is_none() {
-                    return Err("no data to decode".to_string());
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
                 }
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::Tuple(vec![ethabi::ParamType::Address])],
-                        maybe_data.unwrap(),
-                    )
-                    .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
-                values.reverse();
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
                 Ok(Self {
-                    param0: {
-                        let tuple_elements = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_tuple()
-                            .expect(INTERNAL_ERR);
-                        (
-                            tuple_elements[0usize]
-                                .clone()
-                                .into_address()
-                                .expect(INTERNAL_ERR)
-                                .as_bytes()
-                                .to_vec(),
-                        )
-                    },
+                    param0: (decoder.address(0usize)?.to_vec(),),
                 })
             }
             pub fn encode(&self) -> Vec<u8> {
-                let data = ethabi::encode(
-                    &[
-                        ethabi::Token::Tuple(
-                            vec![
-                                ethabi::Token::Address(ethabi::Address::from_slice(& self
-                                .param0.0))
-                            ],
-                        ),
-                    ],
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder.address(self.param0.0.as_slice());
+                    },
                 );
                 let mut encoded = Vec::with_capacity(4 + data.len());
                 encoded.extend(Self::METHOD_ID);
@@ -1478,12 +1424,16 @@
         }
         impl substreams_ethereum::Function for FunTupleAddress {
             const NAME: &'static str = "funTupleAddress";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
             fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 Self::match_call(call)
             }
             fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(call)
             }
             fn encode(&self) -> Vec<u8> {
@@ -1495,49 +1445,37 @@
             pub param0: substreams::scalar::BigInt,
         }
         impl FunUint256 {
-            const METHOD_ID: [u8; 4] = [43u8, 21u8, 33u8, 111u8];
+            pub const METHOD_ID: [u8; 4] = [43u8, 21u8, 33u8, 111u8];
+            pub const SIGNATURE: &'static str = "funUint256(uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &[""];
+            pub const PARAM_TYPES: &'static [&'static str] = &["uint256"];
             pub fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 let maybe_data = call.input.get(4..);
                 if maybe_data.is_none() {
-                    return Err("no data to decode".to_string());
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
                 }
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::Uint(256usize)],
-                        maybe_data.unwrap(),
-                    )
-                    .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
-                values.reverse();
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
                 Ok(Self {
-                    param0: {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_uint()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
-                    },
+                    param0: decoder.big_uint(0usize)?,
                 })
             }
             pub fn encode(&self) -> Vec<u8> {
-                let data = ethabi::encode(
-                    &[
-                        ethabi::Token::Uint(
-                            ethabi::Uint::from_big_endian(
-                                match self.param0.clone().to_bytes_be() {
-                                    (num_bigint::Sign::Plus, bytes) => bytes,
-                                    (num_bigint::Sign::NoSign, bytes) => bytes,
-                                    (num_bigint::Sign::Minus, _) => {
-                                        panic!("negative numbers are not supported")
-                                    }
-                                }
-                                    .as_slice(),
-                            ),
-                        ),
-                    ],
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| {
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_uint(&self.param0),
+                            );
+                    },
                 );
                 let mut encoded = Vec::with_capacity(4 + data.len());
                 encoded.extend(Self::METHOD_ID);
@@ -1553,30 +1491,237 @@
         }
         impl substreams_ethereum::Function for FunUint256 {
             const NAME: &'static str = "funUint256";
+            const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
             fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                 Self::match_call(call)
             }
             fn decode(
                 call: &substreams_ethereum::pb::eth::v2::Call,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(call)
             }
             fn encode(&self) -> Vec<u8> {
                 self.encode()
             }
         }
-    }
-    /// Contract's events.
-    #[allow(dead_code, unused_imports, unused_variables)]
-    pub mod events {
-        use super::INTERNAL_ERR;
+        /// Any of the contract's functions, see [Functions::match_and_decode].
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Functions {
+            FixedArrayAddressArrayUint256ReturnsUint256String(
+                FixedArrayAddressArrayUint256ReturnsUint256String,
+            ),
+            FixedArrayAddressArrayAddressReturnsUint256String(
+                FixedArrayAddressArrayAddressReturnsUint256String,
+            ),
+            FunAll(FunAll),
+            FunDynamicBoolArray(FunDynamicBoolArray),
+            FunInt256(FunInt256),
+            FunInt32(FunInt32),
+            FunInt8(FunInt8),
+            FunInt8Int32Int64Int256(FunInt8Int32Int64Int256),
+            FunReturnsString1(FunReturnsString1),
+            FunReturnsString2(FunReturnsString2),
+            FunReturnsStringString(FunReturnsStringString),
+            FunString(FunString),
+            FunStringString(FunStringString),
+            FunStructOrder(FunStructOrder),
+            FunTupleAddress(FunTupleAddress),
+            FunUint256(FunUint256),
+        }
+        impl Functions {
+            /// Decodes `call` into the function whose method id is the first 4 bytes of the call input.
+            pub fn match_and_decode(
+                call: impl AsRef<substreams_ethereum::pb::eth::v2::Call>,
+            ) -> Option<Self> {
+                use substreams_ethereum::Function;
+                let call = call.as_ref();
+                let method_id = <[u8; 4] as std::convert::TryFrom<
+                    &[u8],
+                >>::try_from(call.input.get(0..4)?)
+                    .ok()?;
+                match method_id {
+                    FixedArrayAddressArrayUint256ReturnsUint256String::METHOD_ID => {
+                        FixedArrayAddressArrayUint256ReturnsUint256String::match_and_decode(
+                                call,
+                            )
+                            .map(
+                                Functions::FixedArrayAddressArrayUint256ReturnsUint256String,
+                            )
+                    }
+                    FixedArrayAddressArrayAddressReturnsUint256String::METHOD_ID => {
+                        FixedArrayAddressArrayAddressReturnsUint256String::match_and_decode(
+                                call,
+                            )
+                            .map(
+                                Functions::FixedArrayAddressArrayAddressReturnsUint256String,
+                            )
+                    }
+                    FunAll::METHOD_ID => {
+                        FunAll::match_and_decode(call).map(Functions::FunAll)
+                    }
+                    FunDynamicBoolArray::METHOD_ID => {
+                        FunDynamicBoolArray::match_and_decode(call)
+                            .map(Functions::FunDynamicBoolArray)
+                    }
+                    FunInt256::METHOD_ID => {
+                        FunInt256::match_and_decode(call).map(Functions::FunInt256)
+                    }
+                    FunInt32::METHOD_ID => {
+                        FunInt32::match_and_decode(call).map(Functions::FunInt32)
+                    }
+                    FunInt8::METHOD_ID => {
+                        FunInt8::match_and_decode(call).map(Functions::FunInt8)
+                    }
+                    FunInt8Int32Int64Int256::METHOD_ID => {
+                        FunInt8Int32Int64Int256::match_and_decode(call)
+                            .map(Functions::FunInt8Int32Int64Int256)
+                    }
+                    FunReturnsString1::METHOD_ID => {
+                        FunReturnsString1::match_and_decode(call)
+                            .map(Functions::FunReturnsString1)
+                            .or_else(|| {
+                                FunReturnsString2::match_and_decode(call)
+                                    .map(Functions::FunReturnsString2)
+                            })
+                    }
+                    FunReturnsStringString::METHOD_ID => {
+                        FunReturnsStringString::match_and_decode(call)
+                            .map(Functions::FunReturnsStringString)
+                    }
+                    FunString::METHOD_ID => {
+                        FunString::match_and_decode(call).map(Functions::FunString)
+                    }
+                    FunStringString::METHOD_ID => {
+                        FunStringString::match_and_decode(call)
+                            .map(Functions::FunStringString)
+                    }
+                    FunStructOrder::METHOD_ID => {
+                        FunStructOrder::match_and_decode(call)
+                            .map(Functions::FunStructOrder)
+                    }
+                    FunTupleAddress::METHOD_ID => {
+                        FunTupleAddress::match_and_decode(call)
+                            .map(Functions::FunTupleAddress)
+                    }
+                    FunUint256::METHOD_ID => {
+                        FunUint256::match_and_decode(call).map(Functions::FunUint256)
+                    }
+                    _ => None,
+                }
+            }
+            pub fn name(&self) -> &'static str {
+                match self {
+                    Functions::FixedArrayAddressArrayUint256ReturnsUint256String(_) => {
+                        "FixedArrayAddressArrayUint256ReturnsUint256String"
+                    }
+                    Functions::FixedArrayAddressArrayAddressReturnsUint256String(_) => {
+                        "fixedArrayAddressArrayAddressReturnsUint256String"
+                    }
+                    Functions::FunAll(_) => "funAll",
+                    Functions::FunDynamicBoolArray(_) => "funDynamicBoolArray",
+                    Functions::FunInt256(_) => "funInt256",
+                    Functions::FunInt32(_) => "funInt32",
+                    Functions::FunInt8(_) => "funInt8",
+                    Functions::FunInt8Int32Int64Int256(_) => "funInt8Int32Int64Int256",
+                    Functions::FunReturnsString1(_) => "funReturnsString1",
+                    Functions::FunReturnsString2(_) => "funReturnsString2",
+                    Functions::FunReturnsStringString(_) => "funReturnsStringString",
+                    Functions::FunString(_) => "funString",
+                    Functions::FunStringString(_) => "funStringString",
+                    Functions::FunStructOrder(_) => "funStructOrder",
+                    Functions::FunTupleAddress(_) => "funTupleAddress",
+                    Functions::FunUint256(_) => "funUint256",
+                }
+            }
+        }
+        impl substreams_ethereum::ContractFunctions for Functions {
+            fn match_and_decode(
+                call: impl AsRef<substreams_ethereum::pb::eth::v2::Call>,
+            ) -> Option<Self> {
+                Self::match_and_decode(call)
+            }
+            fn name(&self) -> &'static str {
+                self.name()
+            }
+        }
+        /// Handle on a deployed `tests` contract, its view functions are called through RPC.
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Tests {
+            pub address: Vec<u8>,
+        }
+        impl Tests {
+            pub const NAME: &'static str = "tests";
+            pub fn fixed_array_address_array_uint256_returns_uint256_string(
+                &self,
+                param0: [Vec<u8>; 2usize],
+                param1: Vec<substreams::scalar::BigInt>,
+            ) -> Option<(substreams::scalar::BigInt, String)> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(
+                        FixedArrayAddressArrayUint256ReturnsUint256String {
+                            param0,
+                            param1,
+                        },
+                        self.address.to_vec(),
+                    )
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<
+                    _,
+                    FixedArrayAddressArrayUint256ReturnsUint256String,
+                >(responses.get(0)?)
+            }
+            pub fn fixed_array_address_array_address_returns_uint256_string(
+                &self,
+                param0: [Vec<u8>; 2usize],
+                param1: Vec<Vec<u8>>,
+            ) -> Option<(substreams::scalar::BigInt, String)> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(
+                        FixedArrayAddressArrayAddressReturnsUint256String {
+                            param0,
+                            param1,
+                        },
+                        self.address.to_vec(),
+                    )
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<
+                    _,
+                    FixedArrayAddressArrayAddressReturnsUint256String,
+                >(responses.get(0)?)
+            }
+            pub fn fun_struct_order(
+                &self,
+                order: super::structs::Order,
+            ) -> Option<super::structs::SpentItem> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(FunStructOrder { order }, self.address.to_vec())
+                    .execute()
+                    .ok()?
+                    .responses;
+                substreams_ethereum::rpc::RpcBatch::decode::<
+                    _,
+                    FunStructOrder,
+                >(responses.get(0)?)
+            }
+        }
+    }
+    /// Contract's events.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod events {
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventAddressIdxString {
             pub first: Vec<u8>,
             pub second: String,
         }
         impl EventAddressIdxString {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 60u8,
                 219u8,
                 49u8,
@@ -1610,6 +1755,12 @@
                 70u8,
                 168u8,
             ];
+            pub const SIGNATURE: &'static str = "EventAddressIdxString(address,string)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["first", "second"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["address", "string"];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 2usize {
                     return false;
@@ -1622,48 +1773,173 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::String],
-                        log.data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
-                values.reverse();
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
                 Ok(Self {
-                    first: ethabi::decode(
-                            &[ethabi::ParamType::Address],
-                            log.topics[1usize].as_ref(),
-                        )
-                        .map_err(|e| {
-                            format!(
-                                "unable to decode param 'first' from topic of type 'address': {:?}",
-                                e
-                            )
-                        })?
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_address()
-                        .expect(INTERNAL_ERR)
-                        .as_bytes()
-                        .to_vec(),
-                    second: values
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_string()
-                        .expect(INTERNAL_ERR),
+                    first: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "first",
+                            "address",
+                            1usize,
+                        );
+                        topic.address(0usize)?.to_vec()
+                    },
+                    second: decoder.string(0usize)?,
                 })
             }
+            /// Decodes only the `first` parameter of `log`, without decoding the whole event.
+            pub fn decode_first(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[1usize],
+                    "first",
+                    "address",
+                    1usize,
+                );
+                Ok(topic.address(0usize)?.to_vec())
+            }
+            /// Decodes only the `second` parameter of `log`, without decoding the whole event.
+            pub fn decode_second(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<String, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data).string(0usize)
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        Self::TOPIC_ID.to_vec(),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.first.as_slice()); })
+                    ],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        32usize,
+                        |encoder| {
+                            encoder.string(self.second.as_str());
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventAddressIdxString {
             const NAME: &'static str = "EventAddressIdxString";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventAddressIdxString] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventAddressIdxStringRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventAddressIdxStringRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventAddressIdxString::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn first(&self) -> Result<&'a [u8], substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[1usize],
+                    "first",
+                    "address",
+                    1usize,
+                );
+                topic.word(0usize).map(|word| &word[12..])
+            }
+            pub fn second(
+                &self,
+            ) -> Result<std::borrow::Cow<'a, str>, substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data)
+                    .bytes(0usize)
+                    .map(String::from_utf8_lossy)
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventAddressIdxString, substreams_ethereum::DecodeError> {
+                Ok(EventAddressIdxString {
+                    first: self.first()?.to_vec(),
+                    second: self.second()?.into_owned(),
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventAddressIdxStringUint256IdxBytes {
@@ -1673,7 +1949,7 @@
             pub fourth: Vec<u8>,
         }
         impl EventAddressIdxStringUint256IdxBytes {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 19u8,
                 200u8,
                 39u8,
@@ -1707,6 +1983,22 @@
                 51u8,
                 169u8,
             ];
+            pub const SIGNATURE: &'static str = "EventAddressIdxStringUint256IdxBytes(address,string,uint256,bytes)";
+            pub const PARAM_NAMES: &'static [&'static str] = &[
+                "first",
+                "second",
+                "third",
+                "fourth",
+            ];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "address",
+                "string",
+                "uint256",
+                "bytes",
+            ];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 3usize {
                     return false;
@@ -1719,72 +2011,260 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::String, ethabi::ParamType::Bytes],
-                        log.data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
-                values.reverse();
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
                 Ok(Self {
-                    first: ethabi::decode(
-                            &[ethabi::ParamType::Address],
-                            log.topics[1usize].as_ref(),
-                        )
-                        .map_err(|e| {
-                            format!(
-                                "unable to decode param 'first' from topic of type 'address': {:?}",
-                                e
-                            )
-                        })?
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_address()
-                        .expect(INTERNAL_ERR)
-                        .as_bytes()
-                        .to_vec(),
+                    first: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "first",
+                            "address",
+                            1usize,
+                        );
+                        topic.address(0usize)?.to_vec()
+                    },
                     third: {
-                        let mut v = [0 as u8; 32];
-                        ethabi::decode(
-                                &[ethabi::ParamType::Uint(256usize)],
-                                log.topics[2usize].as_ref(),
-                            )
-                            .map_err(|e| {
-                                format!(
-                                    "unable to decode param 'third' from topic of type 'uint256': {:?}",
-                                    e
-                                )
-                            })?
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_uint()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[2usize],
+                            "third",
+                            "uint256",
+                            2usize,
+                        );
+                        topic.big_uint(0usize)?
                     },
-                    second: values
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_string()
-                        .expect(INTERNAL_ERR),
-                    fourth: values
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_bytes()
-                        .expect(INTERNAL_ERR),
+                    second: decoder.string(0usize)?,
+                    fourth: decoder.bytes(32usize)?.to_vec(),
                 })
             }
+            /// Decodes only the `first` parameter of `log`, without decoding the whole event.
+            pub fn decode_first(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[1usize],
+                    "first",
+                    "address",
+                    1usize,
+                );
+                Ok(topic.address(0usize)?.to_vec())
+            }
+            /// Decodes only the `second` parameter of `log`, without decoding the whole event.
+            pub fn decode_second(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<String, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data).string(0usize)
+            }
+            /// Decodes only the `third` parameter of `log`, without decoding the whole event.
+            pub fn decode_third(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[2usize],
+                    "third",
+                    "uint256",
+                    2usize,
+                );
+                topic.big_uint(0usize)
+            }
+            /// Decodes only the `fourth` parameter of `log`, without decoding the whole event.
+            pub fn decode_fourth(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                Ok(
+                    substreams_ethereum::abi::Decoder::new(&log.data)
+                        .bytes(32usize)?
+                        .to_vec(),
+                )
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        Self::TOPIC_ID.to_vec(),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.first.as_slice()); }),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.word(substreams_ethereum::abi::encode_big_uint(& self
+                        .third)); })
+                    ],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        64usize,
+                        |encoder| {
+                            encoder.string(self.second.as_str());
+                            encoder.bytes(self.fourth.as_slice());
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventAddressIdxStringUint256IdxBytes {
             const NAME: &'static str = "EventAddressIdxStringUint256IdxBytes";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventAddressIdxStringUint256IdxBytes] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventAddressIdxStringUint256IdxBytesRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventAddressIdxStringUint256IdxBytesRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventAddressIdxStringUint256IdxBytes::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn first(&self) -> Result<&'a [u8], substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[1usize],
+                    "first",
+                    "address",
+                    1usize,
+                );
+                topic.word(0usize).map(|word| &word[12..])
+            }
+            pub fn second(
+                &self,
+            ) -> Result<std::borrow::Cow<'a, str>, substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data)
+                    .bytes(0usize)
+                    .map(String::from_utf8_lossy)
+            }
+            pub fn third(
+                &self,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[2usize],
+                    "third",
+                    "uint256",
+                    2usize,
+                );
+                topic.big_uint(0usize)
+            }
+            pub fn fourth(&self) -> Result<&'a [u8], substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data).bytes(32usize)
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<
+                EventAddressIdxStringUint256IdxBytes,
+                substreams_ethereum::DecodeError,
+            > {
+                Ok(EventAddressIdxStringUint256IdxBytes {
+                    first: self.first()?.to_vec(),
+                    second: self.second()?.into_owned(),
+                    third: self.third()?,
+                    fourth: self.fourth()?.to_vec(),
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventAddressIdxUint256Uint256AddressIdx {
@@ -1794,7 +2274,7 @@
             pub fourth: Vec<u8>,
         }
         impl EventAddressIdxUint256Uint256AddressIdx {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 186u8,
                 209u8,
                 95u8,
@@ -1828,6 +2308,22 @@
                 217u8,
                 125u8,
             ];
+            pub const SIGNATURE: &'static str = "EventAddressIdxUint256Uint256AddressIdx(address,uint256,uint256,address)";
+            pub const PARAM_NAMES: &'static [&'static str] = &[
+                "first",
+                "second",
+                "third",
+                "fourth",
+            ];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "address",
+                "uint256",
+                "uint256",
+                "address",
+            ];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 3usize {
                     return false;
@@ -1840,111 +2336,493 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
-                let mut values = ethabi::decode(
-                        &[
-                            ethabi::ParamType::Uint(256usize),
-                            ethabi::ParamType::Uint(256usize),
-                        ],
-                        log.data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
-                values.reverse();
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
                 Ok(Self {
-                    first: ethabi::decode(
-                            &[ethabi::ParamType::Address],
-                            log.topics[1usize].as_ref(),
-                        )
-                        .map_err(|e| {
-                            format!(
-                                "unable to decode param 'first' from topic of type 'address': {:?}",
-                                e
-                            )
-                        })?
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_address()
-                        .expect(INTERNAL_ERR)
-                        .as_bytes()
-                        .to_vec(),
-                    fourth: ethabi::decode(
-                            &[ethabi::ParamType::Address],
-                            log.topics[2usize].as_ref(),
-                        )
-                        .map_err(|e| {
-                            format!(
-                                "unable to decode param 'fourth' from topic of type 'address': {:?}",
-                                e
-                            )
-                        })?
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_address()
-                        .expect(INTERNAL_ERR)
-                        .as_bytes()
-                        .to_vec(),
-                    second: {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_uint()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                    first: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "first",
+                            "address",
+                            1usize,
+                        );
+                        topic.address(0usize)?.to_vec()
                     },
-                    third: {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_uint()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                    fourth: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[2usize],
+                            "fourth",
+                            "address",
+                            2usize,
+                        );
+                        topic.address(0usize)?.to_vec()
                     },
+                    second: decoder.big_uint(0usize)?,
+                    third: decoder.big_uint(32usize)?,
                 })
             }
+            /// Decodes only the `first` parameter of `log`, without decoding the whole event.
+            pub fn decode_first(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[1usize],
+                    "first",
+                    "address",
+                    1usize,
+                );
+                Ok(topic.address(0usize)?.to_vec())
+            }
+            /// Decodes only the `second` parameter of `log`, without decoding the whole event.
+            pub fn decode_second(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data).big_uint(0usize)
+            }
+            /// Decodes only the `third` parameter of `log`, without decoding the whole event.
+            pub fn decode_third(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data).big_uint(32usize)
+            }
+            /// Decodes only the `fourth` parameter of `log`, without decoding the whole event.
+            pub fn decode_fourth(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[2usize],
+                    "fourth",
+                    "address",
+                    2usize,
+                );
+                Ok(topic.address(0usize)?.to_vec())
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        Self::TOPIC_ID.to_vec(),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.first.as_slice()); }),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.fourth.as_slice()); })
+                    ],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        64usize,
+                        |encoder| {
+                            encoder
+                                .word(
+                                    substreams_ethereum::abi::encode_big_uint(&self.second),
+                                );
+                            encoder
+                                .word(
+                                    substreams_ethereum::abi::encode_big_uint(&self.third),
+                                );
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventAddressIdxUint256Uint256AddressIdx {
             const NAME: &'static str = "EventAddressIdxUint256Uint256AddressIdx";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventAddressIdxUint256Uint256AddressIdx] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventAddressIdxUint256Uint256AddressIdxRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventAddressIdxUint256Uint256AddressIdxRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 3usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 3usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventAddressIdxUint256Uint256AddressIdx::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn first(&self) -> Result<&'a [u8], substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[1usize],
+                    "first",
+                    "address",
+                    1usize,
+                );
+                topic.word(0usize).map(|word| &word[12..])
+            }
+            pub fn second(
+                &self,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data).big_uint(0usize)
+            }
+            pub fn third(
+                &self,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data).big_uint(32usize)
+            }
+            pub fn fourth(&self) -> Result<&'a [u8], substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[2usize],
+                    "fourth",
+                    "address",
+                    2usize,
+                );
+                topic.word(0usize).map(|word| &word[12..])
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<
+                EventAddressIdxUint256Uint256AddressIdx,
+                substreams_ethereum::DecodeError,
+            > {
+                Ok(EventAddressIdxUint256Uint256AddressIdx {
+                    first: self.first()?.to_vec(),
+                    second: self.second()?,
+                    third: self.third()?,
+                    fourth: self.fourth()?.to_vec(),
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
-        pub struct EventBytes20UintAddressIdx {
-            pub first: [u8; 20usize],
-            pub second: substreams::scalar::BigInt,
-            pub third: Vec<u8>,
+        pub struct EventAnonymous {
+            pub owner: Vec<u8>,
+            pub amount: substreams::scalar::BigInt,
         }
-        impl EventBytes20UintAddressIdx {
-            const TOPIC_ID: [u8; 32] = [
-                130u8,
-                252u8,
-                100u8,
-                31u8,
-                27u8,
-                89u8,
-                229u8,
-                170u8,
-                29u8,
-                114u8,
-                181u8,
-                106u8,
-                121u8,
+        impl EventAnonymous {
+            pub const TOPIC_ID: [u8; 32] = [
                 91u8,
-                106u8,
-                55u8,
-                182u8,
-                124u8,
-                76u8,
-                74u8,
+                243u8,
+                195u8,
+                1u8,
+                105u8,
+                255u8,
+                152u8,
+                211u8,
+                91u8,
+                147u8,
+                153u8,
+                51u8,
+                238u8,
+                102u8,
+                169u8,
+                230u8,
+                165u8,
+                136u8,
+                145u8,
+                24u8,
+                79u8,
+                158u8,
+                16u8,
+                102u8,
+                175u8,
+                227u8,
+                82u8,
+                214u8,
+                91u8,
+                21u8,
+                243u8,
+                247u8,
+            ];
+            pub const SIGNATURE: &'static str = "EventAnonymous(address,uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["owner", "amount"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["address", "uint256"];
+            pub fn is_anonymous() -> bool {
+                true
+            }
+            pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                if log.topics.len() != 1usize {
+                    return false;
+                }
+                if log.data.len() != 32usize {
+                    return false;
+                }
+                return true;
+            }
+            pub fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
+                Ok(Self {
+                    owner: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[0usize],
+                            "owner",
+                            "address",
+                            0usize,
+                        );
+                        topic.address(0usize)?.to_vec()
+                    },
+                    amount: decoder.big_uint(0usize)?,
+                })
+            }
+            /// Decodes only the `owner` parameter of `log`, without decoding the whole event.
+            pub fn decode_owner(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[0usize],
+                    "owner",
+                    "address",
+                    0usize,
+                );
+                Ok(topic.address(0usize)?.to_vec())
+            }
+            /// Decodes only the `amount` parameter of `log`, without decoding the whole event.
+            pub fn decode_amount(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data).big_uint(0usize)
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.owner.as_slice()); })
+                    ],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        32usize,
+                        |encoder| {
+                            encoder
+                                .word(
+                                    substreams_ethereum::abi::encode_big_uint(&self.amount),
+                                );
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
+        }
+        impl substreams_ethereum::Event for EventAnonymous {
+            const NAME: &'static str = "EventAnonymous";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
+            fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                Self::match_log(log)
+            }
+            fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(log)
+            }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventAnonymous] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventAnonymousRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventAnonymousRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventAnonymous::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn owner(&self) -> Result<&'a [u8], substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[0usize],
+                    "owner",
+                    "address",
+                    0usize,
+                );
+                topic.word(0usize).map(|word| &word[12..])
+            }
+            pub fn amount(
+                &self,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data).big_uint(0usize)
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventAnonymous, substreams_ethereum::DecodeError> {
+                Ok(EventAnonymous {
+                    owner: self.owner()?.to_vec(),
+                    amount: self.amount()?,
+                })
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct EventBytes20UintAddressIdx {
+            pub first: [u8; 20usize],
+            pub second: substreams::scalar::BigInt,
+            pub third: Vec<u8>,
+        }
+        impl EventBytes20UintAddressIdx {
+            pub const TOPIC_ID: [u8; 32] = [
+                130u8,
+                252u8,
+                100u8,
+                31u8,
+                27u8,
+                89u8,
+                229u8,
+                170u8,
+                29u8,
+                114u8,
+                181u8,
+                106u8,
+                121u8,
+                91u8,
+                106u8,
+                55u8,
+                182u8,
+                124u8,
+                76u8,
+                74u8,
                 112u8,
                 156u8,
                 148u8,
@@ -1958,6 +2836,20 @@
                 147u8,
                 225u8,
             ];
+            pub const SIGNATURE: &'static str = "EventBytes20UintAddressIdx(bytes20,uint256,address)";
+            pub const PARAM_NAMES: &'static [&'static str] = &[
+                "first",
+                "second",
+                "third",
+            ];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "bytes20",
+                "uint256",
+                "address",
+            ];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 2usize {
                     return false;
@@ -1970,66 +2862,206 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
-                let mut values = ethabi::decode(
-                        &[
-                            ethabi::ParamType::FixedBytes(20usize),
-                            ethabi::ParamType::Uint(256usize),
-                        ],
-                        log.data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
-                values.reverse();
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
                 Ok(Self {
-                    third: ethabi::decode(
-                            &[ethabi::ParamType::Address],
-                            log.topics[1usize].as_ref(),
-                        )
-                        .map_err(|e| {
-                            format!(
-                                "unable to decode param 'third' from topic of type 'address': {:?}",
-                                e
-                            )
-                        })?
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_address()
-                        .expect(INTERNAL_ERR)
-                        .as_bytes()
-                        .to_vec(),
-                    first: {
-                        let mut result = [0u8; 20];
-                        let v = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_fixed_bytes()
-                            .expect(INTERNAL_ERR);
-                        result.copy_from_slice(&v);
-                        result
-                    },
-                    second: {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_uint()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                    third: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "third",
+                            "address",
+                            1usize,
+                        );
+                        topic.address(0usize)?.to_vec()
                     },
+                    first: decoder.fixed_bytes::<20usize>(0usize)?,
+                    second: decoder.big_uint(32usize)?,
                 })
             }
+            /// Decodes only the `first` parameter of `log`, without decoding the whole event.
+            pub fn decode_first(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<[u8; 20usize], substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data)
+                    .fixed_bytes::<20usize>(0usize)
+            }
+            /// Decodes only the `second` parameter of `log`, without decoding the whole event.
+            pub fn decode_second(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data).big_uint(32usize)
+            }
+            /// Decodes only the `third` parameter of `log`, without decoding the whole event.
+            pub fn decode_third(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[1usize],
+                    "third",
+                    "address",
+                    1usize,
+                );
+                Ok(topic.address(0usize)?.to_vec())
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        Self::TOPIC_ID.to_vec(),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.third.as_slice()); })
+                    ],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        64usize,
+                        |encoder| {
+                            encoder.fixed_bytes(self.first.as_slice());
+                            encoder
+                                .word(
+                                    substreams_ethereum::abi::encode_big_uint(&self.second),
+                                );
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventBytes20UintAddressIdx {
             const NAME: &'static str = "EventBytes20UintAddressIdx";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventBytes20UintAddressIdx] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventBytes20UintAddressIdxRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventBytes20UintAddressIdxRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventBytes20UintAddressIdx::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn first(
+                &self,
+            ) -> Result<[u8; 20usize], substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data)
+                    .fixed_bytes::<20usize>(0usize)
+            }
+            pub fn second(
+                &self,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data).big_uint(32usize)
+            }
+            pub fn third(&self) -> Result<&'a [u8], substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[1usize],
+                    "third",
+                    "address",
+                    1usize,
+                );
+                topic.word(0usize).map(|word| &word[12..])
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventBytes20UintAddressIdx, substreams_ethereum::DecodeError> {
+                Ok(EventBytes20UintAddressIdx {
+                    first: self.first()?,
+                    second: self.second()?,
+                    third: self.third()?.to_vec(),
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventBytes32UintAddressIdx {
@@ -2038,7 +3070,7 @@
             pub third: Vec<u8>,
         }
         impl EventBytes32UintAddressIdx {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 168u8,
                 98u8,
                 190u8,
@@ -2072,6 +3104,20 @@
                 176u8,
                 179u8,
             ];
+            pub const SIGNATURE: &'static str = "EventBytes32UintAddressIdx(bytes32,uint256,address)";
+            pub const PARAM_NAMES: &'static [&'static str] = &[
+                "first",
+                "second",
+                "third",
+            ];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "bytes32",
+                "uint256",
+                "address",
+            ];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 2usize {
                     return false;
@@ -2084,73 +3130,213 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
-                let mut values = ethabi::decode(
-                        &[
-                            ethabi::ParamType::FixedBytes(32usize),
-                            ethabi::ParamType::Uint(256usize),
-                        ],
-                        log.data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
-                values.reverse();
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
                 Ok(Self {
-                    third: ethabi::decode(
-                            &[ethabi::ParamType::Address],
-                            log.topics[1usize].as_ref(),
-                        )
-                        .map_err(|e| {
-                            format!(
-                                "unable to decode param 'third' from topic of type 'address': {:?}",
-                                e
-                            )
-                        })?
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_address()
-                        .expect(INTERNAL_ERR)
-                        .as_bytes()
-                        .to_vec(),
-                    first: {
-                        let mut result = [0u8; 32];
-                        let v = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_fixed_bytes()
-                            .expect(INTERNAL_ERR);
-                        result.copy_from_slice(&v);
-                        result
-                    },
-                    second: {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_uint()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                    third: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "third",
+                            "address",
+                            1usize,
+                        );
+                        topic.address(0usize)?.to_vec()
                     },
+                    first: decoder.fixed_bytes::<32usize>(0usize)?,
+                    second: decoder.big_uint(32usize)?,
                 })
             }
+            /// Decodes only the `first` parameter of `log`, without decoding the whole event.
+            pub fn decode_first(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<[u8; 32usize], substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data)
+                    .fixed_bytes::<32usize>(0usize)
+            }
+            /// Decodes only the `second` parameter of `log`, without decoding the whole event.
+            pub fn decode_second(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data).big_uint(32usize)
+            }
+            /// Decodes only the `third` parameter of `log`, without decoding the whole event.
+            pub fn decode_third(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[1usize],
+                    "third",
+                    "address",
+                    1usize,
+                );
+                Ok(topic.address(0usize)?.to_vec())
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        Self::TOPIC_ID.to_vec(),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.third.as_slice()); })
+                    ],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        64usize,
+                        |encoder| {
+                            encoder.fixed_bytes(self.first.as_slice());
+                            encoder
+                                .word(
+                                    substreams_ethereum::abi::encode_big_uint(&self.second),
+                                );
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventBytes32UintAddressIdx {
             const NAME: &'static str = "EventBytes32UintAddressIdx";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventBytes32UintAddressIdx] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventBytes32UintAddressIdxRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventBytes32UintAddressIdxRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventBytes32UintAddressIdx::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn first(
+                &self,
+            ) -> Result<[u8; 32usize], substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data)
+                    .fixed_bytes::<32usize>(0usize)
+            }
+            pub fn second(
+                &self,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data).big_uint(32usize)
+            }
+            pub fn third(&self) -> Result<&'a [u8], substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[1usize],
+                    "third",
+                    "address",
+                    1usize,
+                );
+                topic.word(0usize).map(|word| &word[12..])
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventBytes32UintAddressIdx, substreams_ethereum::DecodeError> {
+                Ok(EventBytes32UintAddressIdx {
+                    first: self.first()?,
+                    second: self.second()?,
+                    third: self.third()?.to_vec(),
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventInt256 {
             pub param0: substreams::scalar::BigInt,
         }
         impl EventInt256 {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 160u8,
                 188u8,
                 122u8,
@@ -2184,6 +3370,12 @@
                 237u8,
                 69u8,
             ];
+            pub const SIGNATURE: &'static str = "EventInt256(int256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["param0"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["int256"];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 1usize {
                     return false;
@@ -2196,44 +3388,130 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::Int(256usize)],
-                        log.data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
-                values.reverse();
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
                 Ok(Self {
-                    param0: {
-                        let mut v = [0 as u8; 32];
-                        values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_int()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_signed_bytes_be(&v)
-                    },
+                    param0: decoder.big_int(0usize)?,
                 })
             }
+            /// Decodes only the `param0` parameter of `log`, without decoding the whole event.
+            pub fn decode_param0(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data).big_int(0usize)
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![Self::TOPIC_ID.to_vec(),],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        32usize,
+                        |encoder| {
+                            encoder
+                                .word(
+                                    substreams_ethereum::abi::encode_big_int(&self.param0),
+                                );
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventInt256 {
             const NAME: &'static str = "EventInt256";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventInt256] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventInt256Ref<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventInt256Ref<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventInt256::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn param0(
+                &self,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data).big_int(0usize)
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventInt256, substreams_ethereum::DecodeError> {
+                Ok(EventInt256 {
+                    param0: self.param0()?,
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventInt256Idx {
             pub param0: substreams::scalar::BigInt,
         }
         impl EventInt256Idx {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 8u8,
                 77u8,
                 106u8,
@@ -2267,6 +3545,12 @@
                 71u8,
                 39u8,
             ];
+            pub const SIGNATURE: &'static str = "EventInt256Idx(int256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["param0"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["int256"];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 2usize {
                     return false;
@@ -2279,31 +3563,407 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
                 Ok(Self {
-                    param0: substreams::scalar::BigInt::from_signed_bytes_be(
-                        log.topics[1usize].as_ref(),
-                    ),
+                    param0: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "param0",
+                            "int256",
+                            1usize,
+                        );
+                        topic.big_int(0usize)?
+                    },
                 })
             }
+            /// Decodes only the `param0` parameter of `log`, without decoding the whole event.
+            pub fn decode_param0(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[1usize],
+                    "param0",
+                    "int256",
+                    1usize,
+                );
+                topic.big_int(0usize)
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        Self::TOPIC_ID.to_vec(),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.word(substreams_ethereum::abi::encode_big_int(& self
+                        .param0)); })
+                    ],
+                    data: Vec::<u8>::new(),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventInt256Idx {
             const NAME: &'static str = "EventInt256Idx";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventInt256Idx] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventInt256IdxRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventInt256IdxRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventInt256Idx::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn param0(
+                &self,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[1usize],
+                    "param0",
+                    "int256",
+                    1usize,
+                );
+                topic.big_int(0usize)
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventInt256Idx, substreams_ethereum::DecodeError> {
+                Ok(EventInt256Idx {
+                    param0: self.param0()?,
+                })
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct EventOrderFulfilled {
+            pub order_hash: [u8; 32usize],
+            pub offer: Vec<super::structs::SpentItem>,
+        }
+        impl EventOrderFulfilled {
+            pub const TOPIC_ID: [u8; 32] = [
+                186u8,
+                142u8,
+                84u8,
+                187u8,
+                64u8,
+                85u8,
+                185u8,
+                49u8,
+                233u8,
+                66u8,
+                6u8,
+                119u8,
+                123u8,
+                121u8,
+                133u8,
+                119u8,
+                96u8,
+                105u8,
+                151u8,
+                134u8,
+                122u8,
+                88u8,
+                8u8,
+                0u8,
+                233u8,
+                37u8,
+                221u8,
+                45u8,
+                182u8,
+                192u8,
+                27u8,
+                117u8,
+            ];
+            pub const SIGNATURE: &'static str = "EventOrderFulfilled(bytes32,(uint8,uint256)[])";
+            pub const PARAM_NAMES: &'static [&'static str] = &["orderHash", "offer"];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "bytes32",
+                "(uint8,uint256)[]",
+            ];
+            pub fn is_anonymous() -> bool {
+                false
+            }
+            pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                if log.topics.len() != 1usize {
+                    return false;
+                }
+                if log.data.len() < 96usize {
+                    return false;
+                }
+                return log.topics.get(0).expect("bounds already checked").as_ref()
+                    == Self::TOPIC_ID;
+            }
+            pub fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
+                Ok(Self {
+                    order_hash: decoder.fixed_bytes::<32usize>(0usize)?,
+                    offer: {
+                        let (len, elements) = decoder.array(32usize, 64usize)?;
+                        (0..len)
+                            .map(|i| -> Result<_, substreams_ethereum::DecodeError> {
+                                super::structs::SpentItem::decode_tuple(
+                                    elements,
+                                    i * 64usize,
+                                )
+                            })
+                            .collect::<
+                                Result<Vec<_>, substreams_ethereum::DecodeError>,
+                            >()?
+                    },
+                })
+            }
+            /// Decodes only the `order_hash` parameter of `log`, without decoding the whole event.
+            pub fn decode_order_hash(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<[u8; 32usize], substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data)
+                    .fixed_bytes::<32usize>(0usize)
+            }
+            /// Decodes only the `offer` parameter of `log`, without decoding the whole event.
+            pub fn decode_offer(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<
+                Vec<super::structs::SpentItem>,
+                substreams_ethereum::DecodeError,
+            > {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                Ok({
+                    let (len, elements) = substreams_ethereum::abi::Decoder::new(
+                            &log.data,
+                        )
+                        .array(32usize, 64usize)?;
+                    (0..len)
+                        .map(|i| -> Result<_, substreams_ethereum::DecodeError> {
+                            super::structs::SpentItem::decode_tuple(
+                                elements,
+                                i * 64usize,
+                            )
+                        })
+                        .collect::<Result<Vec<_>, substreams_ethereum::DecodeError>>()?
+                })
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![Self::TOPIC_ID.to_vec(),],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        64usize,
+                        |encoder| {
+                            encoder.fixed_bytes(self.order_hash.as_slice());
+                            encoder
+                                .array(
+                                    self.offer.len(),
+                                    64usize,
+                                    |elements| {
+                                        for inner in self.offer.iter() {
+                                            inner.encode_tuple(elements);
+                                        }
+                                    },
+                                );
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
+        }
+        impl substreams_ethereum::Event for EventOrderFulfilled {
+            const NAME: &'static str = "EventOrderFulfilled";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
+            fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                Self::match_log(log)
+            }
+            fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(log)
+            }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventOrderFulfilled] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventOrderFulfilledRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventOrderFulfilledRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventOrderFulfilled::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn order_hash(
+                &self,
+            ) -> Result<[u8; 32usize], substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data)
+                    .fixed_bytes::<32usize>(0usize)
+            }
+            pub fn offer(
+                &self,
+            ) -> Result<
+                Vec<super::structs::SpentItem>,
+                substreams_ethereum::DecodeError,
+            > {
+                Ok({
+                    let (len, elements) = substreams_ethereum::abi::Decoder::new(
+                            &self.log.data,
+                        )
+                        .array(32usize, 64usize)?;
+                    (0..len)
+                        .map(|i| -> Result<_, substreams_ethereum::DecodeError> {
+                            super::structs::SpentItem::decode_tuple(
+                                elements,
+                                i * 64usize,
+                            )
+                        })
+                        .collect::<Result<Vec<_>, substreams_ethereum::DecodeError>>()?
+                })
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventOrderFulfilled, substreams_ethereum::DecodeError> {
+                Ok(EventOrderFulfilled {
+                    order_hash: self.order_hash()?,
+                    offer: self.offer()?,
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventUArrayBool {
             pub param0: Vec<bool>,
         }
         impl EventUArrayBool {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 238u8,
                 12u8,
                 208u8,
@@ -2337,47 +3997,174 @@
                 175u8,
                 182u8,
             ];
+            pub const SIGNATURE: &'static str = "EventUArrayBool(bool[])";
+            pub const PARAM_NAMES: &'static [&'static str] = &["param0"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["bool[]"];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 1usize {
-                    return false;
+                    return false;
+                }
+                if log.data.len() < 64usize {
+                    return false;
+                }
+                return log.topics.get(0).expect("bounds already checked").as_ref()
+                    == Self::TOPIC_ID;
+            }
+            pub fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
+                Ok(Self {
+                    param0: {
+                        let (len, elements) = decoder.array(0usize, 32usize)?;
+                        (0..len)
+                            .map(|i| -> Result<_, substreams_ethereum::DecodeError> {
+                                elements.bool(i * 32usize)
+                            })
+                            .collect::<
+                                Result<Vec<_>, substreams_ethereum::DecodeError>,
+                            >()?
+                    },
+                })
+            }
+            /// Decodes only the `param0` parameter of `log`, without decoding the whole event.
+            pub fn decode_param0(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<bool>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
                 }
-                if log.data.len() < 64usize {
-                    return false;
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
                 }
-                return log.topics.get(0).expect("bounds already checked").as_ref()
-                    == Self::TOPIC_ID;
-            }
-            pub fn decode(
-                log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bool))],
-                        log.data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
-                values.reverse();
-                Ok(Self {
-                    param0: values
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_array()
-                        .expect(INTERNAL_ERR)
-                        .into_iter()
-                        .map(|inner| inner.into_bool().expect(INTERNAL_ERR))
-                        .collect(),
+                Ok({
+                    let (len, elements) = substreams_ethereum::abi::Decoder::new(
+                            &log.data,
+                        )
+                        .array(0usize, 32usize)?;
+                    (0..len)
+                        .map(|i| -> Result<_, substreams_ethereum::DecodeError> {
+                            elements.bool(i * 32usize)
+                        })
+                        .collect::<Result<Vec<_>, substreams_ethereum::DecodeError>>()?
                 })
             }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![Self::TOPIC_ID.to_vec(),],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        32usize,
+                        |encoder| {
+                            encoder
+                                .array(
+                                    self.param0.len(),
+                                    32usize,
+                                    |elements| {
+                                        for inner in self.param0.iter() {
+                                            elements.bool(*inner);
+                                        }
+                                    },
+                                );
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventUArrayBool {
             const NAME: &'static str = "EventUArrayBool";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventUArrayBool] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventUArrayBoolRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventUArrayBoolRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventUArrayBool::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn param0(&self) -> Result<Vec<bool>, substreams_ethereum::DecodeError> {
+                Ok({
+                    let (len, elements) = substreams_ethereum::abi::Decoder::new(
+                            &self.log.data,
+                        )
+                        .array(0usize, 32usize)?;
+                    (0..len)
+                        .map(|i| -> Result<_, substreams_ethereum::DecodeError> {
+                            elements.bool(i * 32usize)
+                        })
+                        .collect::<Result<Vec<_>, substreams_ethereum::DecodeError>>()?
+                })
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventUArrayBool, substreams_ethereum::DecodeError> {
+                Ok(EventUArrayBool {
+                    param0: self.param0()?,
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventUBytes8UBytes16UBytes24UBytes32 {
@@ -2387,7 +4174,7 @@
             pub param3: [u8; 32usize],
         }
         impl EventUBytes8UBytes16UBytes24UBytes32 {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 117u8,
                 163u8,
                 183u8,
@@ -2421,6 +4208,22 @@
                 176u8,
                 196u8,
             ];
+            pub const SIGNATURE: &'static str = "EventUBytes8UBytes16UBytes24UBytes32(bytes8,bytes16,bytes24,bytes32)";
+            pub const PARAM_NAMES: &'static [&'static str] = &[
+                "param0",
+                "param1",
+                "param2",
+                "param3",
+            ];
+            pub const PARAM_TYPES: &'static [&'static str] = &[
+                "bytes8",
+                "bytes16",
+                "bytes24",
+                "bytes32",
+            ];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 1usize {
                     return false;
@@ -2433,79 +4236,228 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
-                let mut values = ethabi::decode(
-                        &[
-                            ethabi::ParamType::FixedBytes(8usize),
-                            ethabi::ParamType::FixedBytes(16usize),
-                            ethabi::ParamType::FixedBytes(24usize),
-                            ethabi::ParamType::FixedBytes(32usize),
-                        ],
-                        log.data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
-                values.reverse();
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
                 Ok(Self {
-                    param0: {
-                        let mut result = [0u8; 8];
-                        let v = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_fixed_bytes()
-                            .expect(INTERNAL_ERR);
-                        result.copy_from_slice(&v);
-                        result
-                    },
-                    param1: {
-                        let mut result = [0u8; 16];
-                        let v = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_fixed_bytes()
-                            .expect(INTERNAL_ERR);
-                        result.copy_from_slice(&v);
-                        result
-                    },
-                    param2: {
-                        let mut result = [0u8; 24];
-                        let v = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_fixed_bytes()
-                            .expect(INTERNAL_ERR);
-                        result.copy_from_slice(&v);
-                        result
-                    },
-                    param3: {
-                        let mut result = [0u8; 32];
-                        let v = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_fixed_bytes()
-                            .expect(INTERNAL_ERR);
-                        result.copy_from_slice(&v);
-                        result
-                    },
+                    param0: decoder.fixed_bytes::<8usize>(0usize)?,
+                    param1: decoder.fixed_bytes::<16usize>(32usize)?,
+                    param2: decoder.fixed_bytes::<24usize>(64usize)?,
+                    param3: decoder.fixed_bytes::<32usize>(96usize)?,
                 })
             }
+            /// Decodes only the `param0` parameter of `log`, without decoding the whole event.
+            pub fn decode_param0(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<[u8; 8usize], substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data)
+                    .fixed_bytes::<8usize>(0usize)
+            }
+            /// Decodes only the `param1` parameter of `log`, without decoding the whole event.
+            pub fn decode_param1(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<[u8; 16usize], substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data)
+                    .fixed_bytes::<16usize>(32usize)
+            }
+            /// Decodes only the `param2` parameter of `log`, without decoding the whole event.
+            pub fn decode_param2(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<[u8; 24usize], substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data)
+                    .fixed_bytes::<24usize>(64usize)
+            }
+            /// Decodes only the `param3` parameter of `log`, without decoding the whole event.
+            pub fn decode_param3(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<[u8; 32usize], substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                substreams_ethereum::abi::Decoder::new(&log.data)
+                    .fixed_bytes::<32usize>(96usize)
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![Self::TOPIC_ID.to_vec(),],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        128usize,
+                        |encoder| {
+                            encoder.fixed_bytes(self.param0.as_slice());
+                            encoder.fixed_bytes(self.param1.as_slice());
+                            encoder.fixed_bytes(self.param2.as_slice());
+                            encoder.fixed_bytes(self.param3.as_slice());
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventUBytes8UBytes16UBytes24UBytes32 {
             const NAME: &'static str = "EventUBytes8UBytes16UBytes24UBytes32";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventUBytes8UBytes16UBytes24UBytes32] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventUBytes8UBytes16UBytes24UBytes32Ref<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventUBytes8UBytes16UBytes24UBytes32Ref<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventUBytes8UBytes16UBytes24UBytes32::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn param0(
+                &self,
+            ) -> Result<[u8; 8usize], substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data)
+                    .fixed_bytes::<8usize>(0usize)
+            }
+            pub fn param1(
+                &self,
+            ) -> Result<[u8; 16usize], substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data)
+                    .fixed_bytes::<16usize>(32usize)
+            }
+            pub fn param2(
+                &self,
+            ) -> Result<[u8; 24usize], substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data)
+                    .fixed_bytes::<24usize>(64usize)
+            }
+            pub fn param3(
+                &self,
+            ) -> Result<[u8; 32usize], substreams_ethereum::DecodeError> {
+                substreams_ethereum::abi::Decoder::new(&self.log.data)
+                    .fixed_bytes::<32usize>(96usize)
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<
+                EventUBytes8UBytes16UBytes24UBytes32,
+                substreams_ethereum::DecodeError,
+            > {
+                Ok(EventUBytes8UBytes16UBytes24UBytes32 {
+                    param0: self.param0()?,
+                    param1: self.param1()?,
+                    param2: self.param2()?,
+                    param3: self.param3()?,
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventUFixedArrayString {
             pub param0: [String; 2usize],
         }
         impl EventUFixedArrayString {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 47u8,
                 102u8,
                 209u8,
@@ -2539,6 +4491,12 @@
                 19u8,
                 124u8,
             ];
+            pub const SIGNATURE: &'static str = "EventUFixedArrayString(string[2])";
+            pub const PARAM_NAMES: &'static [&'static str] = &["param0"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["string[2]"];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 1usize {
                     return false;
@@ -2551,52 +4509,146 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
-                let mut values = ethabi::decode(
-                        &[
-                            ethabi::ParamType::FixedArray(
-                                Box::new(ethabi::ParamType::String),
-                                2usize,
-                            ),
-                        ],
-                        log.data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
-                values.reverse();
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
                 Ok(Self {
                     param0: {
-                        let mut iter = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_fixed_array()
-                            .expect(INTERNAL_ERR)
-                            .into_iter()
-                            .map(|inner| inner.into_string().expect(INTERNAL_ERR));
-                        [
-                            iter.next().expect(INTERNAL_ERR),
-                            iter.next().expect(INTERNAL_ERR),
-                        ]
+                        let elements = decoder.tail(0usize)?;
+                        [elements.string(0usize)?, elements.string(32usize)?]
                     },
                 })
             }
+            /// Decodes only the `param0` parameter of `log`, without decoding the whole event.
+            pub fn decode_param0(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<[String; 2usize], substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                Ok({
+                    let elements = substreams_ethereum::abi::Decoder::new(&log.data)
+                        .tail(0usize)?;
+                    [elements.string(0usize)?, elements.string(32usize)?]
+                })
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![Self::TOPIC_ID.to_vec(),],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        32usize,
+                        |encoder| {
+                            encoder
+                                .tuple(
+                                    64usize,
+                                    |elements| {
+                                        for inner in self.param0.iter() {
+                                            elements.string(inner.as_str());
+                                        }
+                                    },
+                                );
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventUFixedArrayString {
             const NAME: &'static str = "EventUFixedArrayString";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventUFixedArrayString] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventUFixedArrayStringRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventUFixedArrayStringRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventUFixedArrayString::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn param0(
+                &self,
+            ) -> Result<[String; 2usize], substreams_ethereum::DecodeError> {
+                Ok({
+                    let elements = substreams_ethereum::abi::Decoder::new(&self.log.data)
+                        .tail(0usize)?;
+                    [elements.string(0usize)?, elements.string(32usize)?]
+                })
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventUFixedArrayString, substreams_ethereum::DecodeError> {
+                Ok(EventUFixedArrayString {
+                    param0: self.param0()?,
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventUFixedArraySubDynamic {
             pub param0: [Vec<u8>; 2usize],
         }
         impl EventUFixedArraySubDynamic {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 214u8,
                 61u8,
                 69u8,
@@ -2630,6 +4682,12 @@
                 140u8,
                 216u8,
             ];
+            pub const SIGNATURE: &'static str = "EventUFixedArraySubDynamic(bytes[2])";
+            pub const PARAM_NAMES: &'static [&'static str] = &["param0"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["bytes[2]"];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 1usize {
                     return false;
@@ -2642,52 +4700,149 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
-                let mut values = ethabi::decode(
-                        &[
-                            ethabi::ParamType::FixedArray(
-                                Box::new(ethabi::ParamType::Bytes),
-                                2usize,
-                            ),
-                        ],
-                        log.data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
-                values.reverse();
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
                 Ok(Self {
                     param0: {
-                        let mut iter = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_fixed_array()
-                            .expect(INTERNAL_ERR)
-                            .into_iter()
-                            .map(|inner| inner.into_bytes().expect(INTERNAL_ERR));
+                        let elements = decoder.tail(0usize)?;
                         [
-                            iter.next().expect(INTERNAL_ERR),
-                            iter.next().expect(INTERNAL_ERR),
+                            elements.bytes(0usize)?.to_vec(),
+                            elements.bytes(32usize)?.to_vec(),
                         ]
                     },
                 })
             }
+            /// Decodes only the `param0` parameter of `log`, without decoding the whole event.
+            pub fn decode_param0(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<[Vec<u8>; 2usize], substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                Ok({
+                    let elements = substreams_ethereum::abi::Decoder::new(&log.data)
+                        .tail(0usize)?;
+                    [elements.bytes(0usize)?.to_vec(), elements.bytes(32usize)?.to_vec()]
+                })
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![Self::TOPIC_ID.to_vec(),],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        32usize,
+                        |encoder| {
+                            encoder
+                                .tuple(
+                                    64usize,
+                                    |elements| {
+                                        for inner in self.param0.iter() {
+                                            elements.bytes(inner.as_slice());
+                                        }
+                                    },
+                                );
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventUFixedArraySubDynamic {
             const NAME: &'static str = "EventUFixedArraySubDynamic";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventUFixedArraySubDynamic] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventUFixedArraySubDynamicRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventUFixedArraySubDynamicRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventUFixedArraySubDynamic::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn param0(
+                &self,
+            ) -> Result<[Vec<u8>; 2usize], substreams_ethereum::DecodeError> {
+                Ok({
+                    let elements = substreams_ethereum::abi::Decoder::new(&self.log.data)
+                        .tail(0usize)?;
+                    [elements.bytes(0usize)?.to_vec(), elements.bytes(32usize)?.to_vec()]
+                })
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventUFixedArraySubDynamic, substreams_ethereum::DecodeError> {
+                Ok(EventUFixedArraySubDynamic {
+                    param0: self.param0()?,
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventUFixedArraySubFixed {
             pub param0: [Vec<u8>; 2usize],
         }
         impl EventUFixedArraySubFixed {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 22u8,
                 94u8,
                 52u8,
@@ -2721,6 +4876,12 @@
                 171u8,
                 178u8,
             ];
+            pub const SIGNATURE: &'static str = "EventUFixedArraySubFixed(address[2])";
+            pub const PARAM_NAMES: &'static [&'static str] = &["param0"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["address[2]"];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 1usize {
                     return false;
@@ -2733,58 +4894,146 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
-                let mut values = ethabi::decode(
-                        &[
-                            ethabi::ParamType::FixedArray(
-                                Box::new(ethabi::ParamType::Address),
-                                2usize,
-                            ),
-                        ],
-                        log.data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
-                values.reverse();
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
                 Ok(Self {
-                    param0: {
-                        let mut iter = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_fixed_array()
-                            .expect(INTERNAL_ERR)
-                            .into_iter()
-                            .map(|inner| {
-                                inner
-                                    .into_address()
-                                    .expect(INTERNAL_ERR)
-                                    .as_bytes()
-                                    .to_vec()
-                            });
-                        [
-                            iter.next().expect(INTERNAL_ERR),
-                            iter.next().expect(INTERNAL_ERR),
-                        ]
-                    },
+                    param0: [
+                        decoder.address(0usize)?.to_vec(),
+                        decoder.address(32usize)?.to_vec(),
+                    ],
                 })
             }
+            /// Decodes only the `param0` parameter of `log`, without decoding the whole event.
+            pub fn decode_param0(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<[Vec<u8>; 2usize], substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                Ok([
+                    substreams_ethereum::abi::Decoder::new(&log.data)
+                        .address(0usize)?
+                        .to_vec(),
+                    substreams_ethereum::abi::Decoder::new(&log.data)
+                        .address(32usize)?
+                        .to_vec(),
+                ])
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![Self::TOPIC_ID.to_vec(),],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        64usize,
+                        |encoder| {
+                            for inner in self.param0.iter() {
+                                encoder.address(inner.as_slice());
+                            }
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventUFixedArraySubFixed {
             const NAME: &'static str = "EventUFixedArraySubFixed";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventUFixedArraySubFixed] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventUFixedArraySubFixedRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventUFixedArraySubFixedRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventUFixedArraySubFixed::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn param0(
+                &self,
+            ) -> Result<[Vec<u8>; 2usize], substreams_ethereum::DecodeError> {
+                Ok([
+                    substreams_ethereum::abi::Decoder::new(&self.log.data)
+                        .address(0usize)?
+                        .to_vec(),
+                    substreams_ethereum::abi::Decoder::new(&self.log.data)
+                        .address(32usize)?
+                        .to_vec(),
+                ])
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventUFixedArraySubFixed, substreams_ethereum::DecodeError> {
+                Ok(EventUFixedArraySubFixed {
+                    param0: self.param0()?,
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventUTupleAddress {
             pub param0: (Vec<u8>,),
         }
         impl EventUTupleAddress {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 173u8,
                 178u8,
                 91u8,
@@ -2818,6 +5067,12 @@
                 135u8,
                 211u8,
             ];
+            pub const SIGNATURE: &'static str = "EventUTupleAddress((address))";
+            pub const PARAM_NAMES: &'static [&'static str] = &["param0"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["(address)"];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 1usize {
                     return false;
@@ -2830,49 +5085,135 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::Tuple(vec![ethabi::ParamType::Address])],
-                        log.data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
-                values.reverse();
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
                 Ok(Self {
-                    param0: {
-                        let tuple_elements = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_tuple()
-                            .expect(INTERNAL_ERR);
-                        (
-                            tuple_elements[0usize]
-                                .clone()
-                                .into_address()
-                                .expect(INTERNAL_ERR)
-                                .as_bytes()
-                                .to_vec(),
-                        )
-                    },
+                    param0: (decoder.address(0usize)?.to_vec(),),
                 })
             }
+            /// Decodes only the `param0` parameter of `log`, without decoding the whole event.
+            pub fn decode_param0(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<(Vec<u8>,), substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                Ok((
+                    substreams_ethereum::abi::Decoder::new(&log.data)
+                        .address(0usize)?
+                        .to_vec(),
+                ))
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![Self::TOPIC_ID.to_vec(),],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        32usize,
+                        |encoder| {
+                            encoder.address(self.param0.0.as_slice());
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventUTupleAddress {
             const NAME: &'static str = "EventUTupleAddress";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventUTupleAddress] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventUTupleAddressRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventUTupleAddressRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventUTupleAddress::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn param0(
+                &self,
+            ) -> Result<(Vec<u8>,), substreams_ethereum::DecodeError> {
+                Ok((
+                    substreams_ethereum::abi::Decoder::new(&self.log.data)
+                        .address(0usize)?
+                        .to_vec(),
+                ))
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventUTupleAddress, substreams_ethereum::DecodeError> {
+                Ok(EventUTupleAddress {
+                    param0: self.param0()?,
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventUTupleBool {
             pub param0: (bool,),
         }
         impl EventUTupleBool {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 228u8,
                 110u8,
                 6u8,
@@ -2906,6 +5247,12 @@
                 255u8,
                 35u8,
             ];
+            pub const SIGNATURE: &'static str = "EventUTupleBool((bool))";
+            pub const PARAM_NAMES: &'static [&'static str] = &["param0"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["(bool)"];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 1usize {
                     return false;
@@ -2918,47 +5265,127 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
-                let mut values = ethabi::decode(
-                        &[ethabi::ParamType::Tuple(vec![ethabi::ParamType::Bool])],
-                        log.data.as_ref(),
-                    )
-                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
-                values.reverse();
-                Ok(Self {
-                    param0: {
-                        let tuple_elements = values
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_tuple()
-                            .expect(INTERNAL_ERR);
-                        (
-                            tuple_elements[0usize]
-                                .clone()
-                                .into_bool()
-                                .expect(INTERNAL_ERR),
-                        )
-                    },
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
+                Ok(Self {
+                    param0: (decoder.bool(0usize)?,),
                 })
             }
+            /// Decodes only the `param0` parameter of `log`, without decoding the whole event.
+            pub fn decode_param0(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<(bool,), substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                Ok((substreams_ethereum::abi::Decoder::new(&log.data).bool(0usize)?,))
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![Self::TOPIC_ID.to_vec(),],
+                    data: substreams_ethereum::abi::Encoder::encode(
+                        32usize,
+                        |encoder| {
+                            encoder.bool(self.param0.0);
+                        },
+                    ),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventUTupleBool {
             const NAME: &'static str = "EventUTupleBool";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventUTupleBool] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventUTupleBoolRef<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventUTupleBoolRef<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 1usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 1usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventUTupleBool::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn param0(&self) -> Result<(bool,), substreams_ethereum::DecodeError> {
+                Ok((
+                    substreams_ethereum::abi::Decoder::new(&self.log.data).bool(0usize)?,
+                ))
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventUTupleBool, substreams_ethereum::DecodeError> {
+                Ok(EventUTupleBool {
+                    param0: self.param0()?,
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventWithOverloads1 {
             pub first: Vec<u8>,
         }
         impl EventWithOverloads1 {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 160u8,
                 232u8,
                 134u8,
@@ -2992,6 +5419,12 @@
                 28u8,
                 21u8,
             ];
+            pub const SIGNATURE: &'static str = "EventWithOverloads(address)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["first"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["address"];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 2usize {
                     return false;
@@ -3004,44 +5437,143 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
                 Ok(Self {
-                    first: ethabi::decode(
-                            &[ethabi::ParamType::Address],
-                            log.topics[1usize].as_ref(),
-                        )
-                        .map_err(|e| {
-                            format!(
-                                "unable to decode param 'first' from topic of type 'address': {:?}",
-                                e
-                            )
-                        })?
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_address()
-                        .expect(INTERNAL_ERR)
-                        .as_bytes()
-                        .to_vec(),
+                    first: {
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "first",
+                            "address",
+                            1usize,
+                        );
+                        topic.address(0usize)?.to_vec()
+                    },
                 })
             }
+            /// Decodes only the `first` parameter of `log`, without decoding the whole event.
+            pub fn decode_first(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[1usize],
+                    "first",
+                    "address",
+                    1usize,
+                );
+                Ok(topic.address(0usize)?.to_vec())
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        Self::TOPIC_ID.to_vec(),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.address(self.first.as_slice()); })
+                    ],
+                    data: Vec::<u8>::new(),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventWithOverloads1 {
             const NAME: &'static str = "EventWithOverloads1";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventWithOverloads1] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventWithOverloads1Ref<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventWithOverloads1Ref<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventWithOverloads1::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn first(&self) -> Result<&'a [u8], substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[1usize],
+                    "first",
+                    "address",
+                    1usize,
+                );
+                topic.word(0usize).map(|word| &word[12..])
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventWithOverloads1, substreams_ethereum::DecodeError> {
+                Ok(EventWithOverloads1 {
+                    first: self.first()?.to_vec(),
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventWithOverloads2 {
-            pub second: String,
+            pub second: substreams_ethereum::scalar::TopicHash,
         }
         impl EventWithOverloads2 {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 145u8,
                 118u8,
                 46u8,
@@ -3075,6 +5607,12 @@
                 35u8,
                 150u8,
             ];
+            pub const SIGNATURE: &'static str = "EventWithOverloads(string)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["second"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["string"];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 2usize {
                     return false;
@@ -3087,42 +5625,151 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
                 Ok(Self {
-                    second: ethabi::decode(
-                            &[ethabi::ParamType::String],
-                            log.topics[1usize].as_ref(),
-                        )
-                        .map_err(|e| {
-                            format!(
-                                "unable to decode param 'second' from topic of type 'string': {:?}",
-                                e
-                            )
-                        })?
-                        .pop()
-                        .expect(INTERNAL_ERR)
-                        .into_string()
-                        .expect(INTERNAL_ERR),
+                    second: <substreams_ethereum::scalar::TopicHash as std::convert::TryFrom<
+                        &[u8],
+                    >>::try_from(log.topics[1usize].as_ref())
+                        .map_err(|e| substreams_ethereum::DecodeError::topic(
+                            "second",
+                            "string",
+                            1usize,
+                            e,
+                        ))?,
                 })
             }
+            /// Decodes only the `second` parameter of `log`, without decoding the whole event.
+            pub fn decode_second(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<
+                substreams_ethereum::scalar::TopicHash,
+                substreams_ethereum::DecodeError,
+            > {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                <substreams_ethereum::scalar::TopicHash as std::convert::TryFrom<
+                    &[u8],
+                >>::try_from(log.topics[1usize].as_ref())
+                    .map_err(|e| substreams_ethereum::DecodeError::topic(
+                        "second",
+                        "string",
+                        1usize,
+                        e,
+                    ))
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![Self::TOPIC_ID.to_vec(), self.second.to_vec()],
+                    data: Vec::<u8>::new(),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventWithOverloads2 {
             const NAME: &'static str = "EventWithOverloads2";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventWithOverloads2] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventWithOverloads2Ref<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventWithOverloads2Ref<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventWithOverloads2::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn second(
+                &self,
+            ) -> Result<
+                substreams_ethereum::scalar::TopicHash,
+                substreams_ethereum::DecodeError,
+            > {
+                <substreams_ethereum::scalar::TopicHash as std::convert::TryFrom<
+                    &[u8],
+                >>::try_from(self.log.topics[1usize].as_ref())
+                    .map_err(|e| substreams_ethereum::DecodeError::topic(
+                        "second",
+                        "string",
+                        1usize,
+                        e,
+                    ))
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventWithOverloads2, substreams_ethereum::DecodeError> {
+                Ok(EventWithOverloads2 {
+                    second: self.second()?,
+                })
+            }
         }
         #[derive(Debug, Clone, PartialEq)]
         pub struct EventWithOverloads3 {
             pub third: substreams::scalar::BigInt,
         }
         impl EventWithOverloads3 {
-            const TOPIC_ID: [u8; 32] = [
+            pub const TOPIC_ID: [u8; 32] = [
                 2u8,
                 227u8,
                 188u8,
@@ -3156,6 +5803,12 @@
                 73u8,
                 118u8,
             ];
+            pub const SIGNATURE: &'static str = "EventWithOverloads(uint256)";
+            pub const PARAM_NAMES: &'static [&'static str] = &["third"];
+            pub const PARAM_TYPES: &'static [&'static str] = &["uint256"];
+            pub fn is_anonymous() -> bool {
+                false
+            }
             pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 if log.topics.len() != 2usize {
                     return false;
@@ -3168,39 +5821,550 @@
             }
             pub fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
                 Ok(Self {
                     third: {
-                        let mut v = [0 as u8; 32];
-                        ethabi::decode(
-                                &[ethabi::ParamType::Uint(256usize)],
-                                log.topics[1usize].as_ref(),
-                            )
-                            .map_err(|e| {
-                                format!(
-                                    "unable to decode param 'third' from topic of type 'uint256': {:?}",
-                                    e
-                                )
-                            })?
-                            .pop()
-                            .expect(INTERNAL_ERR)
-                            .into_uint()
-                            .expect(INTERNAL_ERR)
-                            .to_big_endian(v.as_mut_slice());
-                        substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "third",
+                            "uint256",
+                            1usize,
+                        );
+                        topic.big_uint(0usize)?
                     },
                 })
             }
+            /// Decodes only the `third` parameter of `log`, without decoding the whole event.
+            pub fn decode_third(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(
+                        substreams_ethereum::DecodeError::topic(
+                            Self::SIGNATURE,
+                            "bytes32",
+                            0,
+                            "not the topic id of the event",
+                        ),
+                    );
+                }
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &log.topics[1usize],
+                    "third",
+                    "uint256",
+                    1usize,
+                );
+                topic.big_uint(0usize)
+            }
+            pub fn encode_log(
+                &self,
+                address: Vec<u8>,
+            ) -> substreams_ethereum::pb::eth::v2::Log {
+                substreams_ethereum::pb::eth::v2::Log {
+                    address,
+                    topics: vec![
+                        Self::TOPIC_ID.to_vec(),
+                        substreams_ethereum::abi::Encoder::encode(32usize, | encoder | {
+                        encoder.word(substreams_ethereum::abi::encode_big_uint(& self
+                        .third)); })
+                    ],
+                    data: Vec::<u8>::new(),
+                    ..Default::default()
+                }
+            }
         }
         impl substreams_ethereum::Event for EventWithOverloads3 {
             const NAME: &'static str = "EventWithOverloads3";
+            const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+            const SIGNATURE: &'static str = Self::SIGNATURE;
+            const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+            const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+            fn is_anonymous() -> bool {
+                Self::is_anonymous()
+            }
             fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                 Self::match_log(log)
             }
             fn decode(
                 log: &substreams_ethereum::pb::eth::v2::Log,
-            ) -> Result<Self, String> {
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
                 Self::decode(log)
             }
+            fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                self.encode_log(address)
+            }
+        }
+        /// Borrowed view of a [EventWithOverloads3] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EventWithOverloads3Ref<'a> {
+            log: &'a substreams_ethereum::pb::eth::v2::Log,
+        }
+        impl<'a> EventWithOverloads3Ref<'a> {
+            /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+            pub fn decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                if log.topics.len() != 2usize {
+                    return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                        expected: 2usize,
+                        actual: log.topics.len(),
+                    });
+                }
+                Ok(Self { log })
+            }
+            pub fn match_and_decode(
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            ) -> Option<Self> {
+                if EventWithOverloads3::match_log(log) {
+                    return Self::decode(log).ok();
+                }
+                None
+            }
+            pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                self.log
+            }
+            pub fn third(
+                &self,
+            ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                let topic = substreams_ethereum::abi::Decoder::topic(
+                    &self.log.topics[1usize],
+                    "third",
+                    "uint256",
+                    1usize,
+                );
+                topic.big_uint(0usize)
+            }
+            /// Decodes all the fields into the owned event.
+            pub fn to_owned(
+                self,
+            ) -> Result<EventWithOverloads3, substreams_ethereum::DecodeError> {
+                Ok(EventWithOverloads3 {
+                    third: self.third()?,
+                })
+            }
+        }
+        /// Any of the contract's events, see [Events::match_and_decode].
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Events {
+            EventAddressIdxString(EventAddressIdxString),
+            EventAddressIdxStringUint256IdxBytes(EventAddressIdxStringUint256IdxBytes),
+            EventAddressIdxUint256Uint256AddressIdx(
+                EventAddressIdxUint256Uint256AddressIdx,
+            ),
+            EventBytes20UintAddressIdx(EventBytes20UintAddressIdx),
+            EventBytes32UintAddressIdx(EventBytes32UintAddressIdx),
+            EventInt256(EventInt256),
+            EventInt256Idx(EventInt256Idx),
+            EventOrderFulfilled(EventOrderFulfilled),
+            EventUArrayBool(EventUArrayBool),
+            EventUBytes8UBytes16UBytes24UBytes32(EventUBytes8UBytes16UBytes24UBytes32),
+            EventUFixedArrayString(EventUFixedArrayString),
+            EventUFixedArraySubDynamic(EventUFixedArraySubDynamic),
+            EventUFixedArraySubFixed(EventUFixedArraySubFixed),
+            EventUTupleAddress(EventUTupleAddress),
+            EventUTupleBool(EventUTupleBool),
+            EventWithOverloads1(EventWithOverloads1),
+            EventWithOverloads2(EventWithOverloads2),
+            EventWithOverloads3(EventWithOverloads3),
+        }
+        impl Events {
+            /// Decodes `log` into the event whose signature is the first topic of the log.
+            pub fn match_and_decode(
+                log: impl AsRef<substreams_ethereum::pb::eth::v2::Log>,
+            ) -> Option<Self> {
+                use substreams_ethereum::Event;
+                let log = log.as_ref();
+                let topic = <[u8; 32] as std::convert::TryFrom<
+                    &[u8],
+                >>::try_from(log.topics.get(0)?.as_ref())
+                    .ok()?;
+                match topic {
+                    EventAddressIdxString::TOPIC_ID => {
+                        EventAddressIdxString::match_and_decode(log)
+                            .map(Events::EventAddressIdxString)
+                    }
+                    EventAddressIdxStringUint256IdxBytes::TOPIC_ID => {
+                        EventAddressIdxStringUint256IdxBytes::match_and_decode(log)
+                            .map(Events::EventAddressIdxStringUint256IdxBytes)
+                    }
+                    EventAddressIdxUint256Uint256AddressIdx::TOPIC_ID => {
+                        EventAddressIdxUint256Uint256AddressIdx::match_and_decode(log)
+                            .map(Events::EventAddressIdxUint256Uint256AddressIdx)
+                    }
+                    EventBytes20UintAddressIdx::TOPIC_ID => {
+                        EventBytes20UintAddressIdx::match_and_decode(log)
+                            .map(Events::EventBytes20UintAddressIdx)
+                    }
+                    EventBytes32UintAddressIdx::TOPIC_ID => {
+                        EventBytes32UintAddressIdx::match_and_decode(log)
+                            .map(Events::EventBytes32UintAddressIdx)
+                    }
+                    EventInt256::TOPIC_ID => {
+                        EventInt256::match_and_decode(log).map(Events::EventInt256)
+                    }
+                    EventInt256Idx::TOPIC_ID => {
+                        EventInt256Idx::match_and_decode(log).map(Events::EventInt256Idx)
+                    }
+                    EventOrderFulfilled::TOPIC_ID => {
+                        EventOrderFulfilled::match_and_decode(log)
+                            .map(Events::EventOrderFulfilled)
+                    }
+                    EventUArrayBool::TOPIC_ID => {
+                        EventUArrayBool::match_and_decode(log)
+                            .map(Events::EventUArrayBool)
+                    }
+                    EventUBytes8UBytes16UBytes24UBytes32::TOPIC_ID => {
+                        EventUBytes8UBytes16UBytes24UBytes32::match_and_decode(log)
+                            .map(Events::EventUBytes8UBytes16UBytes24UBytes32)
+                    }
+                    EventUFixedArrayString::TOPIC_ID => {
+                        EventUFixedArrayString::match_and_decode(log)
+                            .map(Events::EventUFixedArrayString)
+                    }
+                    EventUFixedArraySubDynamic::TOPIC_ID => {
+                        EventUFixedArraySubDynamic::match_and_decode(log)
+                            .map(Events::EventUFixedArraySubDynamic)
+                    }
+                    EventUFixedArraySubFixed::TOPIC_ID => {
+                        EventUFixedArraySubFixed::match_and_decode(log)
+                            .map(Events::EventUFixedArraySubFixed)
+                    }
+                    EventUTupleAddress::TOPIC_ID => {
+                        EventUTupleAddress::match_and_decode(log)
+                            .map(Events::EventUTupleAddress)
+                    }
+                    EventUTupleBool::TOPIC_ID => {
+                        EventUTupleBool::match_and_decode(log)
+                            .map(Events::EventUTupleBool)
+                    }
+                    EventWithOverloads1::TOPIC_ID => {
+                        EventWithOverloads1::match_and_decode(log)
+                            .map(Events::EventWithOverloads1)
+                    }
+                    EventWithOverloads2::TOPIC_ID => {
+                        EventWithOverloads2::match_and_decode(log)
+                            .map(Events::EventWithOverloads2)
+                    }
+                    EventWithOverloads3::TOPIC_ID => {
+                        EventWithOverloads3::match_and_decode(log)
+                            .map(Events::EventWithOverloads3)
+                    }
+                    _ => None,
+                }
+            }
+            pub fn name(&self) -> &'static str {
+                match self {
+                    Events::EventAddressIdxString(_) => "EventAddressIdxString",
+                    Events::EventAddressIdxStringUint256IdxBytes(_) => {
+                        "EventAddressIdxStringUint256IdxBytes"
+                    }
+                    Events::EventAddressIdxUint256Uint256AddressIdx(_) => {
+                        "EventAddressIdxUint256Uint256AddressIdx"
+                    }
+                    Events::EventBytes20UintAddressIdx(_) => "EventBytes20UintAddressIdx",
+                    Events::EventBytes32UintAddressIdx(_) => "EventBytes32UintAddressIdx",
+                    Events::EventInt256(_) => "EventInt256",
+                    Events::EventInt256Idx(_) => "EventInt256Idx",
+                    Events::EventOrderFulfilled(_) => "EventOrderFulfilled",
+                    Events::EventUArrayBool(_) => "EventUArrayBool",
+                    Events::EventUBytes8UBytes16UBytes24UBytes32(_) => {
+                        "EventUBytes8UBytes16UBytes24UBytes32"
+                    }
+                    Events::EventUFixedArrayString(_) => "EventUFixedArrayString",
+                    Events::EventUFixedArraySubDynamic(_) => "EventUFixedArraySubDynamic",
+                    Events::EventUFixedArraySubFixed(_) => "EventUFixedArraySubFixed",
+                    Events::EventUTupleAddress(_) => "EventUTupleAddress",
+                    Events::EventUTupleBool(_) => "EventUTupleBool",
+                    Events::EventWithOverloads1(_) => "EventWithOverloads1",
+                    Events::EventWithOverloads2(_) => "EventWithOverloads2",
+                    Events::EventWithOverloads3(_) => "EventWithOverloads3",
+                }
+            }
+        }
+        impl substreams_ethereum::ContractEvents for Events {
+            fn match_and_decode(
+                log: impl AsRef<substreams_ethereum::pb::eth::v2::Log>,
+            ) -> Option<Self> {
+                Self::match_and_decode(log)
+            }
+            fn name(&self) -> &'static str {
+                self.name()
+            }
+        }
+    }
+    /// Contract's custom errors.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod errors {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct InsufficientBalance {
+            pub available: substreams::scalar::BigInt,
+            pub required: substreams::scalar::BigInt,
+        }
+        impl InsufficientBalance {
+            pub const SELECTOR: [u8; 4] = [207u8, 71u8, 145u8, 129u8];
+            pub fn match_revert(data: &[u8]) -> bool {
+                match data.get(0..4) {
+                    Some(selector) => Self::SELECTOR == selector,
+                    None => false,
+                }
+            }
+            pub fn decode(
+                data: &[u8],
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let maybe_data = data.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: data.len(),
+                    });
+                }
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(
+                    maybe_data.unwrap(),
+                    4,
+                );
+                Ok(Self {
+                    available: decoder.big_uint(0usize)?,
+                    required: decoder.big_uint(32usize)?,
+                })
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = substreams_ethereum::abi::Encoder::encode(
+                    64usize,
+                    |encoder| {
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_uint(&self.available),
+                            );
+                        encoder
+                            .word(
+                                substreams_ethereum::abi::encode_big_uint(&self.required),
+                            );
+                    },
+                );
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::SELECTOR);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                call.status_reverted && Self::match_revert(call.return_data.as_ref())
+            }
+            pub fn decode_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call.return_data.as_ref())
+            }
+        }
+        impl substreams_ethereum::Error for InsufficientBalance {
+            const NAME: &'static str = "InsufficientBalance";
+            fn match_revert(data: &[u8]) -> bool {
+                Self::match_revert(data)
+            }
+            fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(data)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Unauthorized {}
+        impl Unauthorized {
+            pub const SELECTOR: [u8; 4] = [130u8, 180u8, 41u8, 0u8];
+            pub fn match_revert(data: &[u8]) -> bool {
+                match data.get(0..4) {
+                    Some(selector) => Self::SELECTOR == selector,
+                    None => false,
+                }
+            }
+            pub fn decode(
+                data: &[u8],
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Ok(Self {})
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                let data = Vec::<u8>::new();
+                let mut encoded = Vec::with_capacity(4 + data.len());
+                encoded.extend(Self::SELECTOR);
+                encoded.extend(data);
+                encoded
+            }
+            pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                call.status_reverted && Self::match_revert(call.return_data.as_ref())
+            }
+            pub fn decode_call(
+                call: &substreams_ethereum::pb::eth::v2::Call,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(call.return_data.as_ref())
+            }
+        }
+        impl substreams_ethereum::Error for Unauthorized {
+            const NAME: &'static str = "Unauthorized";
+            fn match_revert(data: &[u8]) -> bool {
+                Self::match_revert(data)
+            }
+            fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode(data)
+            }
+            fn encode(&self) -> Vec<u8> {
+                self.encode()
+            }
+        }
+    }
+    /// Contract's enums.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod enums {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[repr(u8)]
+        pub enum ItemType {
+            Native = 0u8,
+            Erc20 = 1u8,
+            Erc721 = 2u8,
+            Erc1155 = 3u8,
+            Erc721WithCriteria = 4u8,
+            Erc1155WithCriteria = 5u8,
+        }
+        impl std::convert::TryFrom<u8> for ItemType {
+            type Error = substreams_ethereum::DecodeError;
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                match value {
+                    0u8 => Ok(Self::Native),
+                    1u8 => Ok(Self::Erc20),
+                    2u8 => Ok(Self::Erc721),
+                    3u8 => Ok(Self::Erc1155),
+                    4u8 => Ok(Self::Erc721WithCriteria),
+                    5u8 => Ok(Self::Erc1155WithCriteria),
+                    _ => {
+                        Err(substreams_ethereum::DecodeError::UnknownEnumVariant {
+                            name: "ItemType".to_string(),
+                            value,
+                        })
+                    }
+                }
+            }
+        }
+        impl From<ItemType> for u8 {
+            fn from(value: ItemType) -> u8 {
+                value as u8
+            }
+        }
+    }
+    /// Contract's structs.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod structs {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Order {
+            pub offerer: Vec<u8>,
+            pub offer: Vec<super::structs::SpentItem>,
+        }
+        impl Order {
+            pub fn decode(
+                data: &[u8],
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode_tuple(substreams_ethereum::abi::Decoder::new(data), 0)
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                substreams_ethereum::abi::Encoder::encode(
+                    32usize,
+                    |encoder| self.encode_tuple(encoder),
+                )
+            }
+            /// Decodes the struct whose head is at `at` of `decoder`.
+            pub fn decode_tuple(
+                decoder: substreams_ethereum::abi::Decoder,
+                at: usize,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                let decoder = decoder.tail(at)?;
+                Ok(Self {
+                    offerer: decoder.address(0usize)?.to_vec(),
+                    offer: {
+                        let (len, elements) = decoder.array(32usize, 64usize)?;
+                        (0..len)
+                            .map(|i| -> Result<_, substreams_ethereum::DecodeError> {
+                                super::structs::SpentItem::decode_tuple(
+                                    elements,
+                                    i * 64usize,
+                                )
+                            })
+                            .collect::<
+                                Result<Vec<_>, substreams_ethereum::DecodeError>,
+                            >()?
+                    },
+                })
+            }
+            /// Writes the struct with `encoder`.
+            pub fn encode_tuple(&self, encoder: &mut substreams_ethereum::abi::Encoder) {
+                encoder
+                    .tuple(
+                        64usize,
+                        |encoder| {
+                            encoder.address(self.offerer.as_slice());
+                            encoder
+                                .array(
+                                    self.offer.len(),
+                                    64usize,
+                                    |elements| {
+                                        for inner in self.offer.iter() {
+                                            inner.encode_tuple(elements);
+                                        }
+                                    },
+                                );
+                        },
+                    );
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct SpentItem {
+            pub item_type: super::enums::ItemType,
+            pub amount: substreams::scalar::BigInt,
+        }
+        impl SpentItem {
+            pub fn decode(
+                data: &[u8],
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Self::decode_tuple(substreams_ethereum::abi::Decoder::new(data), 0)
+            }
+            pub fn encode(&self) -> Vec<u8> {
+                substreams_ethereum::abi::Encoder::encode(
+                    64usize,
+                    |encoder| self.encode_tuple(encoder),
+                )
+            }
+            /// Decodes the struct whose head is at `at` of `decoder`.
+            pub fn decode_tuple(
+                decoder: substreams_ethereum::abi::Decoder,
+                at: usize,
+            ) -> Result<Self, substreams_ethereum::DecodeError> {
+                Ok(Self {
+                    item_type: decoder
+                        .uint::<u8>(at, 8usize)
+                        .and_then(
+                            <super::enums::ItemType as std::convert::TryFrom<
+                                u8,
+                            >>::try_from,
+                        )?,
+                    amount: decoder.big_uint(at + 32usize)?,
+                })
+            }
+            /// Writes the struct with `encoder`.
+            pub fn encode_tuple(&self, encoder: &mut substreams_ethereum::abi::Encoder) {
+                encoder
+                    .word(
+                        substreams_ethereum::abi::encode_uint(u8::from(self.item_type)),
+                    );
+                encoder.word(substreams_ethereum::abi::encode_big_uint(&self.amount));
+            }
         }
     }
\ No newline at end of file
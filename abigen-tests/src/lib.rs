@@ -8,7 +8,7 @@ mod tests {
     use pretty_assertions::assert_eq;
     use substreams::scalar::BigInt;
     use substreams::{hex, Hex};
-    use substreams_ethereum::pb;
+    use substreams_ethereum::{pb, DecodeError, NULL_ADDRESS};
 
     #[test]
     fn it_decode_event_tuple() {
@@ -258,6 +258,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_decode_event_indexed_string() {
+        use substreams_ethereum::abi::keccak256;
+        use substreams_ethereum::scalar::TopicHash;
+        use tests::events::EventWithOverloads2 as Event;
+
+        // The topic of an indexed `string` is the hash of the value, not the value
+        let log = pb::eth::v2::Log {
+            address: hex!("0000000000000000000000000000000000000000").to_vec(),
+            topics: vec![Event::TOPIC_ID.to_vec(), keccak256("second string").to_vec()],
+            ..Default::default()
+        };
+
+        assert_eq!(Event::match_log(&log), true);
+
+        let event = Event::decode(&log).unwrap();
+        assert_eq!(event.second, TopicHash(keccak256("second string")));
+        assert!(event.second.matches("second string"));
+        assert!(!event.second.matches("first string"));
+        assert_eq!(event.encode_log(log.address.clone()), log);
+    }
+
     #[test]
     fn it_decode_event_bytes_32_uint_address_idx() {
         use num_traits::Num;
@@ -594,12 +616,9 @@ mod tests {
 
     #[test]
     fn it_encode_fun_input_int8() {
-        use substreams::scalar::BigInt;
         use tests::functions::FunInt8 as Function;
 
-        let fun = Function {
-            param0: BigInt::from(num_bigint::ToBigInt::to_bigint(&-127).unwrap()),
-        };
+        let fun = Function { param0: -127 };
 
         assert_eq!(
             fun.encode(),
@@ -608,14 +627,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_decode_fun_input_int8_out_of_range() {
+        use tests::functions::FunInt8 as Function;
+
+        let call = pb::eth::v2::Call {
+            input: hex!("3036e6870000000000000000000000000000000000000000000000000000000000000180").to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Function::decode(&call),
+            Err(DecodeError::IntegerOutOfRange {
+                kind: "int8".to_string()
+            })
+        );
+    }
+
     #[test]
     fn it_encode_fun_input_int32() {
-        use substreams::scalar::BigInt;
         use tests::functions::FunInt32 as Function;
 
-        let fun = Function {
-            param0: BigInt::from(num_bigint::ToBigInt::to_bigint(&-898877731).unwrap()),
-        };
+        let fun = Function { param0: -898877731 };
 
         assert_eq!(
             fun.encode(),
@@ -646,11 +679,9 @@ mod tests {
         use tests::functions::FunInt8Int32Int64Int256 as Function;
 
         let fun = Function {
-            param0: BigInt::from(num_bigint::ToBigInt::to_bigint(&-127).unwrap()),
-            param1: BigInt::from(num_bigint::ToBigInt::to_bigint(&-898877731).unwrap()),
-            param2: BigInt::from(
-                num_bigint::ToBigInt::to_bigint(&(-9809887317731 as i64)).unwrap(),
-            ),
+            param0: -127,
+            param1: -898877731,
+            param2: -9809887317731,
             param3: BigInt::from(
                 num_bigint::ToBigInt::to_bigint(&(-223372036854775808 as i64)).unwrap(),
             ),
@@ -679,11 +710,9 @@ mod tests {
         assert_eq!(
             fun,
             Ok(Function {
-                param0: BigInt::from(num_bigint::ToBigInt::to_bigint(&-127).unwrap(),),
-                param1: BigInt::from(num_bigint::ToBigInt::to_bigint(&-898877731).unwrap(),),
-                param2: BigInt::from(
-                    num_bigint::ToBigInt::to_bigint(&(-9809887317731 as i64)).unwrap(),
-                ),
+                param0: -127,
+                param1: -898877731,
+                param2: -9809887317731,
                 param3: BigInt::from(
                     num_bigint::ToBigInt::to_bigint(&(-223372036854775808 as i64)).unwrap(),
                 ),
@@ -803,4 +832,830 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn it_decode_error_insufficient_balance() {
+        use tests::errors::InsufficientBalance as Error;
+
+        let call = pb::eth::v2::Call {
+            status_failed: true,
+            status_reverted: true,
+            return_data: hex!("cf479181000000000000000000000000000000000000000000000000000000000000000a0000000000000000000000000000000000000000000000000000000000000014").to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(Error::match_call(&call), true);
+
+        let error = Error::decode_call(&call);
+        assert_eq!(
+            error,
+            Ok(Error {
+                available: BigInt::from(10u64),
+                required: BigInt::from(20u64),
+            }),
+        );
+    }
+
+    #[test]
+    fn it_encode_error_insufficient_balance() {
+        use tests::errors::InsufficientBalance as Error;
+
+        let error = Error {
+            available: BigInt::from(10u64),
+            required: BigInt::from(20u64),
+        };
+
+        assert_eq!(error.encode(), hex!("cf479181000000000000000000000000000000000000000000000000000000000000000a0000000000000000000000000000000000000000000000000000000000000014").to_vec());
+    }
+
+    #[test]
+    fn it_match_and_decode_error_only_on_reverted_call() {
+        use substreams_ethereum::Error as _;
+        use tests::errors::{InsufficientBalance, Unauthorized};
+
+        let mut call = pb::eth::v2::Call {
+            return_data: hex!("82b42900").to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(Unauthorized::match_and_decode(&call), None);
+
+        call.status_failed = true;
+        call.status_reverted = true;
+
+        assert_eq!(Unauthorized::match_and_decode(&call), Some(Unauthorized {}));
+        assert_eq!(InsufficientBalance::match_and_decode(&call), None);
+    }
+
+    #[test]
+    fn it_decode_event_struct_array() {
+        use tests::enums::ItemType;
+        use tests::events::EventOrderFulfilled as Event;
+        use tests::structs::SpentItem;
+
+        let log = pb::eth::v2::Log {
+            address: hex!("0000000000000000000000000000000000000000").to_vec(),
+            topics: vec![
+                hex!("ba8e54bb4055b931e94206777b798577606997867a580800e925dd2db6c01b75").to_vec(),
+            ],
+            data: hex!("11111111111111111111111111111111111111111111111111111111111111110000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000064000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000c8").to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(Event::match_log(&log), true);
+
+        let event = Event::decode(&log);
+        assert_eq!(
+            event,
+            Ok(Event {
+                order_hash: hex!(
+                    "1111111111111111111111111111111111111111111111111111111111111111"
+                ),
+                offer: vec![
+                    SpentItem {
+                        item_type: ItemType::Erc20,
+                        amount: BigInt::from(100u64),
+                    },
+                    SpentItem {
+                        item_type: ItemType::Erc721,
+                        amount: BigInt::from(200u64),
+                    },
+                ],
+            }),
+        );
+    }
+
+    #[test]
+    fn it_decode_event_unknown_enum_variant() {
+        use tests::events::EventOrderFulfilled as Event;
+
+        let log = pb::eth::v2::Log {
+            topics: vec![
+                hex!("ba8e54bb4055b931e94206777b798577606997867a580800e925dd2db6c01b75").to_vec(),
+            ],
+            data: hex!("11111111111111111111111111111111111111111111111111111111111111110000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000090000000000000000000000000000000000000000000000000000000000000064000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000c8").to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Event::decode(&log),
+            Err(DecodeError::UnknownEnumVariant {
+                name: "ItemType".to_string(),
+                value: 9,
+            })
+        );
+    }
+
+    #[test]
+    fn it_encode_decode_fun_nested_struct() {
+        use tests::enums::ItemType;
+        use tests::functions::FunStructOrder as Function;
+        use tests::structs::{Order, SpentItem};
+
+        let fun = Function {
+            order: Order {
+                offerer: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+                offer: vec![SpentItem {
+                    item_type: ItemType::Erc20,
+                    amount: BigInt::from(100u64),
+                }],
+            },
+        };
+
+        let input = hex!("baf78c190000000000000000000000000000000000000000000000000000000000000020000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec0000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000064").to_vec();
+        assert_eq!(fun.encode(), input);
+
+        let call = pb::eth::v2::Call {
+            input,
+            return_data: hex!("000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000c8").to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(Function::match_call(&call), true);
+        assert_eq!(Function::decode(&call), Ok(fun));
+        assert_eq!(
+            Function::output_call(&call),
+            Ok(SpentItem {
+                item_type: ItemType::Erc721,
+                amount: BigInt::from(200u64),
+            })
+        );
+    }
+
+    #[test]
+    fn it_decode_constructor_from_creation_input() {
+        use tests::functions::Constructor;
+
+        let code = hex!("6080604052348015600f57600080fd5b50").to_vec();
+        let constructor = Constructor {
+            owner: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+            supply: BigInt::from(1000u64),
+        };
+
+        let input = hex!("6080604052348015600f57600080fd5b50000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec00000000000000000000000000000000000000000000000000000000000003e8").to_vec();
+        assert_eq!(constructor.encode(code.clone()), input);
+
+        let mut call = pb::eth::v2::Call {
+            call_type: pb::eth::v2::CallType::Create as i32,
+            input,
+            ..Default::default()
+        };
+
+        assert_eq!(Constructor::match_call(&call), true);
+        assert_eq!(
+            Constructor::decode_from_creation_input(&call, code.len()),
+            Ok(constructor)
+        );
+
+        // Offsets of errors are relative to the whole creation input
+        call.input.truncate(code.len() + 32);
+        assert!(matches!(
+            Constructor::decode_from_creation_input(&call, code.len()),
+            Err(DecodeError::InvalidData { offset, .. }) if offset == code.len() + 32
+        ));
+        assert!(matches!(
+            Constructor::decode(&call.input[code.len()..]),
+            Err(DecodeError::InvalidData { offset: 32, .. })
+        ));
+        assert_eq!(
+            Constructor::decode_from_creation_input(&call, call.input.len() + 1),
+            Err(DecodeError::DataTooShort {
+                expected: call.input.len() + 1,
+                actual: call.input.len()
+            })
+        );
+
+        call.call_type = pb::eth::v2::CallType::Call as i32;
+        assert_eq!(Constructor::match_call(&call), false);
+        assert_eq!(
+            Constructor::decode_from_creation_input(&call, code.len()),
+            Err(DecodeError::NotContractCreation)
+        );
+    }
+
+    #[test]
+    fn it_decode_event_anonymous() {
+        use substreams_ethereum::Event as _;
+        use tests::events::EventAnonymous as Event;
+
+        let log = pb::eth::v2::Log {
+            address: hex!("0000000000000000000000000000000000000000").to_vec(),
+            topics: vec![
+                hex!("000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+            ],
+            data: hex!("00000000000000000000000000000000000000000000000000000000000003e8").to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(<Event as substreams_ethereum::Event>::is_anonymous(), true);
+        assert_eq!(Event::match_log(&log), true);
+        assert_eq!(
+            Event::match_and_decode(&log),
+            Some(Event {
+                owner: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+                amount: BigInt::from(1000u64),
+            }),
+        );
+
+        let mut with_signature = log.clone();
+        with_signature.topics.insert(
+            0,
+            hex!("ba8e54bb4055b931e94206777b798577606997867a580800e925dd2db6c01b75").to_vec(),
+        );
+        assert_eq!(Event::match_log(&with_signature), false);
+    }
+
+    #[test]
+    fn it_match_and_decode_contract_events() {
+        use tests::events::{EventAddressIdxString, EventAnonymous, Events};
+
+        let contract = hex!("0000000000000000000000000000000000000001");
+        let string_log = pb::eth::v2::Log{
+            address: contract.to_vec(),
+            topics: vec![
+                hex!("3cdb310171efa4c08617535044016fb81ec0a9db468c06b008d2f446ee9346a8").to_vec(),
+                hex!("000000000000000000000000ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+            ],
+            data: hex!("0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000d7365636f6e6420737472696e6700000000000000000000000000000000000000").to_vec(),
+            ..Default::default()
+        };
+        let anonymous_log = pb::eth::v2::Log {
+            address: contract.to_vec(),
+            topics: vec![
+                hex!("000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+            ],
+            data: hex!("00000000000000000000000000000000000000000000000000000000000003e8").to_vec(),
+            ..Default::default()
+        };
+
+        let event = EventAddressIdxString {
+            first: hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+            second: "second string".to_string(),
+        };
+
+        assert_eq!(
+            Events::match_and_decode(&string_log),
+            Some(Events::EventAddressIdxString(event.clone()))
+        );
+        assert_eq!(Events::match_and_decode(&anonymous_log), None);
+        assert_eq!(EventAnonymous::match_log(&anonymous_log), true);
+
+        let block = pb::eth::v2::Block {
+            transaction_traces: vec![pb::eth::v2::TransactionTrace {
+                status: 1,
+                receipt: Some(pb::eth::v2::TransactionReceipt {
+                    logs: vec![anonymous_log, string_log],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let events: Vec<_> = block
+            .contract_events::<Events>(&[&contract])
+            .map(|(event, _)| event)
+            .collect();
+        assert_eq!(events, vec![Events::EventAddressIdxString(event)]);
+        assert_eq!(events[0].name(), "EventAddressIdxString");
+        assert_eq!(block.contract_events::<Events>(&[&NULL_ADDRESS]).count(), 0);
+    }
+
+    #[test]
+    fn it_match_and_decode_contract_functions() {
+        use tests::enums::ItemType;
+        use tests::functions::{FunStructOrder, Functions};
+        use tests::structs::{Order, SpentItem};
+
+        let call = pb::eth::v2::Call {
+            input: hex!("baf78c190000000000000000000000000000000000000000000000000000000000000020000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec0000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000064").to_vec(),
+            ..Default::default()
+        };
+
+        let function = Functions::match_and_decode(&call);
+        assert_eq!(
+            function,
+            Some(Functions::FunStructOrder(FunStructOrder {
+                order: Order {
+                    offerer: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+                    offer: vec![SpentItem {
+                        item_type: ItemType::Erc20,
+                        amount: BigInt::from(100u64),
+                    }],
+                },
+            }))
+        );
+        assert_eq!(function.unwrap().name(), "funStructOrder");
+
+        let unknown = pb::eth::v2::Call {
+            input: hex!("deadbeef").to_vec(),
+            ..Default::default()
+        };
+        assert_eq!(Functions::match_and_decode(&unknown), None);
+
+        let short = pb::eth::v2::Call {
+            input: hex!("baf78c").to_vec(),
+            ..Default::default()
+        };
+        assert_eq!(Functions::match_and_decode(&short), None);
+    }
+
+    #[test]
+    fn it_generate_contract_handle() {
+        use tests::structs::{Order, SpentItem};
+
+        let handle = tests::Tests {
+            address: hex!("0000000000000000000000000000000000000001").to_vec(),
+        };
+
+        assert_eq!(tests::Tests::NAME, "tests");
+        assert_eq!(handle.address, hex!("0000000000000000000000000000000000000001"));
+
+        // Calls go through RPC which is only available in `wasm32`, checking the signature is enough
+        let _: fn(&tests::Tests, Order) -> Option<SpentItem> = tests::Tests::fun_struct_order;
+    }
+
+    #[test]
+    fn it_encode_positive_int() {
+        use substreams_ethereum::Event as _;
+        use tests::events::EventInt256Idx as Event;
+
+        // Positive values are padded with zeros, only negative ones are sign-extended
+        for (value, topic) in [
+            (9809887317731i64, hex!("000000000000000000000000000000000000000000000000000008ec0ad94ee3")),
+            (128i64, hex!("0000000000000000000000000000000000000000000000000000000000000080")),
+            (-128i64, hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff80")),
+        ] {
+            let event = Event {
+                param0: BigInt::from(value),
+            };
+            let log = event.encode(hex!("0000000000000000000000000000000000000000").to_vec());
+            assert_eq!(log.topics[1], topic.to_vec());
+            assert_eq!(Event::decode(&log), Ok(event));
+        }
+    }
+
+    #[test]
+    fn it_encode_event_log_roundtrip() {
+        use substreams_ethereum::Event as _;
+        use tests::events::{EventAddressIdxString, EventAnonymous, EventInt256Idx};
+
+        let address = hex!("0000000000000000000000000000000000000000").to_vec();
+
+        // ethc tools encode --abi ./abigen-tests/abi/tests.json event 'EventAddressIdxString' "0xab07a50AD459B41Fe065f7BBAb866D5390e9f705"  "second string"
+        let event = EventAddressIdxString {
+            first: hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+            second: "second string".to_string(),
+        };
+        let log = event.encode_log(address.clone());
+        assert_eq!(
+            log,
+            pb::eth::v2::Log{
+                address: address.clone(),
+                topics: vec![
+                    hex!("3cdb310171efa4c08617535044016fb81ec0a9db468c06b008d2f446ee9346a8").to_vec(),
+                    hex!("000000000000000000000000ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+                ],
+                data: hex!("0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000d7365636f6e6420737472696e6700000000000000000000000000000000000000").to_vec(),
+                ..Default::default()
+            }
+        );
+        assert_eq!(EventAddressIdxString::match_and_decode(&log), Some(event));
+
+        let event = EventInt256Idx {
+            param0: BigInt::from(-9809887317731i64),
+        };
+        assert_eq!(
+            EventInt256Idx::decode(&event.encode(address.clone())),
+            Ok(event)
+        );
+
+        let event = EventAnonymous {
+            owner: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+            amount: BigInt::from(1000u64),
+        };
+        let log = event.encode(address);
+        assert_eq!(
+            log.topics,
+            vec![hex!("000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec").to_vec()]
+        );
+        assert_eq!(EventAnonymous::match_and_decode(&log), Some(event));
+    }
+
+    #[test]
+    fn it_decode_errors() {
+        use tests::events::EventAddressIdxString as Event;
+        use tests::functions::FunString as Function;
+
+        let mut log = pb::eth::v2::Log {
+            address: hex!("0000000000000000000000000000000000000000").to_vec(),
+            topics: vec![
+                hex!("3cdb310171efa4c08617535044016fb81ec0a9db468c06b008d2f446ee9346a8").to_vec(),
+            ],
+            data: hex!("0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000d7365636f6e6420737472696e6700000000000000000000000000000000000000").to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Event::decode(&log),
+            Err(DecodeError::InvalidTopicCount {
+                expected: 2,
+                actual: 1
+            })
+        );
+
+        log.topics.push(hex!("ab07a50a").to_vec());
+        assert!(matches!(
+            Event::decode(&log),
+            Err(DecodeError::InvalidTopic { name, kind, index: 1, .. }) if name == "first" && kind == "address"
+        ));
+
+        log.topics[1] =
+            hex!("000000000000000000000000ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec();
+        log.data.truncate(64);
+        assert!(matches!(
+            Event::decode(&log),
+            Err(DecodeError::InvalidData { offset: 64, .. })
+        ));
+
+        let call = pb::eth::v2::Call {
+            input: hex!("b0d9").to_vec(),
+            ..Default::default()
+        };
+        assert_eq!(
+            Function::decode(&call),
+            Err(DecodeError::DataTooShort {
+                expected: 4,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn it_decode_borrowed_events() {
+        use tests::events::{
+            EventAddressIdxStringUint256IdxBytes as Event,
+            EventAddressIdxStringUint256IdxBytesRef as EventRef,
+        };
+
+        let mut log = pb::eth::v2::Log{
+            address: hex!("0000000000000000000000000000000000000000").to_vec(),
+            topics: vec![
+                hex!("13c827c8aff69c8c51a406825a22313c37b01da4b8e8cc1ab95ff9e5abd433a9").to_vec(),
+                hex!("000000000000000000000000ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000001000000000").to_vec(),
+            ],
+            data: hex!("00000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000d7365636f6e6420737472696e67000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004abdeff9000000000000000000000000000000000000000000000000000000000").to_vec(),
+            ..Default::default()
+        };
+
+        let event = EventRef::match_and_decode(&log).unwrap();
+        assert_eq!(
+            event.first(),
+            Ok(&hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705")[..])
+        );
+        assert_eq!(event.second().unwrap(), "second string");
+        assert_eq!(event.third(), Ok(BigInt::from(0x1000000000u64)));
+        assert_eq!(event.fourth(), Ok(&hex!("abdeff90")[..]));
+
+        // Values are borrowed from the log
+        assert!(matches!(event.second(), Ok(std::borrow::Cow::Borrowed(_))));
+        assert_eq!(event.fourth().unwrap().as_ptr(), log.data[160..].as_ptr());
+
+        assert_eq!(event.to_owned(), Event::decode(&log));
+
+        // Fields are decoded on access only
+        log.data.truncate(128);
+        let event = EventRef::decode(&log).unwrap();
+        assert_eq!(event.second().unwrap(), "second string");
+        assert!(matches!(
+            event.fourth(),
+            Err(DecodeError::InvalidData { offset: 128, .. })
+        ));
+        assert!(event.to_owned().is_err());
+
+        log.topics.pop();
+        assert_eq!(
+            EventRef::decode(&log).unwrap_err(),
+            DecodeError::InvalidTopicCount {
+                expected: 3,
+                actual: 2
+            }
+        );
+        assert!(EventRef::match_and_decode(&log).is_none());
+    }
+
+    #[test]
+    fn it_decode_event_params() {
+        use crate::abi::erc721::events::{Approval, Transfer};
+        use substreams_ethereum::scalar::{Address, TopicHash};
+        use substreams_ethereum::{abi::keccak256, Event as _};
+        use tests::events::{EventAddressIdxStringUint256IdxBytes as Event, EventWithOverloads2};
+
+        let transfer = Transfer {
+            from: Address(hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705")),
+            to: Address(hex!("fffdb7377345371817f2b4dd490319755f5899ec")),
+            token_id: BigInt::from(1000u64),
+        };
+        let log = transfer.encode(NULL_ADDRESS.to_vec());
+        assert_eq!(Transfer::decode_to(&log), Ok(transfer.to));
+        assert_eq!(Transfer::decode_token_id(&log), Ok(transfer.token_id));
+
+        // Logs of other events with as many topics are rejected
+        let approval = Approval {
+            owner: transfer.from,
+            approved: transfer.to,
+            token_id: BigInt::from(1000u64),
+        };
+        assert!(matches!(
+            Transfer::decode_to(&approval.encode(NULL_ADDRESS.to_vec())),
+            Err(DecodeError::InvalidTopic { index: 0, .. })
+        ));
+
+        // The heads of data parameters follow the ones of the parameters before them
+        let mut log = pb::eth::v2::Log{
+            address: hex!("0000000000000000000000000000000000000000").to_vec(),
+            topics: vec![
+                hex!("13c827c8aff69c8c51a406825a22313c37b01da4b8e8cc1ab95ff9e5abd433a9").to_vec(),
+                hex!("000000000000000000000000ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000001000000000").to_vec(),
+            ],
+            data: hex!("00000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000d7365636f6e6420737472696e67000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004abdeff9000000000000000000000000000000000000000000000000000000000").to_vec(),
+            ..Default::default()
+        };
+        assert_eq!(
+            Event::decode_first(&log),
+            Ok(hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec())
+        );
+        assert_eq!(Event::decode_fourth(&log), Ok(hex!("abdeff90").to_vec()));
+
+        // Only the decoded parameter is checked
+        log.data.truncate(128);
+        assert_eq!(Event::decode_second(&log), Ok("second string".to_string()));
+        assert!(Event::decode_fourth(&log).is_err());
+
+        log.topics.pop();
+        assert_eq!(
+            Event::decode_second(&log),
+            Err(DecodeError::InvalidTopicCount {
+                expected: 3,
+                actual: 2
+            })
+        );
+
+        let log = pb::eth::v2::Log {
+            topics: vec![
+                EventWithOverloads2::TOPIC_ID.to_vec(),
+                keccak256("second string").to_vec(),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            EventWithOverloads2::decode_second(&log),
+            Ok(TopicHash(keccak256("second string")))
+        );
+    }
+
+    #[test]
+    fn it_generate_typed_scalars() {
+        use crate::abi::erc721;
+        use substreams_ethereum::{scalar::Address, Event as _};
+
+        let contract = Address(hex!("bc4ca0eda7647a8ab7c2061c2e118a18a936f13d"));
+        let event = erc721::events::Transfer {
+            from: Address::from_str("0xab07a50AD459B41Fe065f7BBAb866D5390e9f705").unwrap(),
+            to: Address(hex!("fffdb7377345371817f2b4dd490319755f5899ec")),
+            token_id: BigInt::from(1000u64),
+        };
+
+        let log = event.encode(contract.to_vec());
+        assert_eq!(
+            log.topics[1],
+            hex!("000000000000000000000000ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec()
+        );
+        assert!(log.address.as_slice() == contract);
+        assert_eq!(erc721::events::Transfer::match_and_decode(&log), Some(event));
+
+        let handle = erc721::Erc721 { address: contract };
+        assert_eq!(handle.address, contract);
+
+        // Calls go through RPC which is only available in `wasm32`, checking the signature is enough
+        let _: fn(&erc721::Erc721, BigInt) -> Option<Address> = erc721::Erc721::owner_of;
+    }
+
+    substreams_ethereum::use_contract!(erc20, [
+        "event Transfer(address indexed from, address indexed to, uint256 value)",
+        "function balanceOf(address owner) view returns (uint256)",
+        "function fill((address maker, uint amount)[] orders)",
+    ]);
+
+    #[test]
+    fn it_generate_from_signatures() {
+        use substreams_ethereum::Event as _;
+
+        let event = erc20::events::Transfer {
+            from: hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+            to: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+            value: BigInt::from(1000u64),
+        };
+
+        let log = event.encode(NULL_ADDRESS.to_vec());
+        assert_eq!(
+            log.topics[0],
+            hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").to_vec()
+        );
+        assert_eq!(erc20::events::Transfer::match_and_decode(&log), Some(event));
+
+        let call = erc20::functions::BalanceOf {
+            owner: hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+        };
+        assert_eq!(call.encode()[..4], hex!("70a08231"));
+
+        let fill = erc20::functions::Fill {
+            orders: vec![(
+                hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+                BigInt::from(1u64),
+            )],
+        };
+        assert_eq!(
+            erc20::functions::Fill::decode(&pb::eth::v2::Call {
+                input: fill.encode(),
+                ..Default::default()
+            }),
+            Ok(fill)
+        );
+    }
+
+    #[test]
+    fn it_generate_from_foundry_artifact() {
+        use crate::abi::ownable;
+
+        let owner = hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec();
+        let constructor = ownable::functions::Constructor {
+            initial_owner: owner.clone(),
+        };
+
+        assert_eq!(
+            ownable::functions::Constructor::BYTECODE,
+            hex!("6080604052348015600f57600080fd5b50")
+        );
+
+        let input = constructor.encode_with_bytecode();
+        assert_eq!(
+            input,
+            constructor.encode(ownable::functions::Constructor::BYTECODE.to_vec())
+        );
+
+        let call = pb::eth::v2::Call {
+            call_type: pb::eth::v2::CallType::Create as i32,
+            input,
+            ..Default::default()
+        };
+        assert_eq!(
+            ownable::functions::Constructor::decode_from_creation_input(
+                &call,
+                ownable::functions::Constructor::BYTECODE.len()
+            ),
+            Ok(constructor)
+        );
+    }
+
+    #[test]
+    fn it_generate_signature_constants() {
+        use substreams_ethereum::abi::keccak256;
+        use substreams_ethereum::{Event, Function};
+
+        type Signature = (&'static str, Vec<&'static str>, Vec<&'static str>);
+
+        fn event_signature<E: Event>() -> Signature {
+            assert_eq!(keccak256(E::SIGNATURE), E::TOPIC_ID);
+            (E::SIGNATURE, E::PARAM_NAMES.to_vec(), E::PARAM_TYPES.to_vec())
+        }
+
+        fn function_signature<F: Function>() -> Signature {
+            assert_eq!(keccak256(F::SIGNATURE)[..4], F::METHOD_ID);
+            (F::SIGNATURE, F::PARAM_NAMES.to_vec(), F::PARAM_TYPES.to_vec())
+        }
+
+        assert_eq!(
+            event_signature::<tests::events::EventOrderFulfilled>(),
+            (
+                "EventOrderFulfilled(bytes32,(uint8,uint256)[])",
+                vec!["orderHash", "offer"],
+                vec!["bytes32", "(uint8,uint256)[]"]
+            )
+        );
+        assert_eq!(
+            function_signature::<tests::functions::FunStructOrder>(),
+            (
+                "funStructOrder((address,(uint8,uint256)[]))",
+                vec!["order"],
+                vec!["(address,(uint8,uint256)[])"]
+            )
+        );
+        assert_eq!(
+            tests::events::EventAnonymous::SIGNATURE,
+            "EventAnonymous(address,uint256)"
+        );
+    }
+
+    #[test]
+    fn it_generate_signature_overload_names() {
+        use crate::abi::erc721::functions;
+
+        assert_eq!(
+            functions::SafeTransferFromAddressAddressUint256::SIGNATURE,
+            "safeTransferFrom(address,address,uint256)"
+        );
+        assert_eq!(
+            functions::SafeTransferFromAddressAddressUint256::METHOD_ID,
+            hex!("42842e0e")
+        );
+        assert_eq!(
+            functions::SafeTransferFromWithData::SIGNATURE,
+            "safeTransferFrom(address,address,uint256,bytes)"
+        );
+        assert_eq!(
+            functions::SafeTransferFromWithData::METHOD_ID,
+            hex!("b88d4fde")
+        );
+    }
+
+    #[test]
+    fn it_generate_set_with_shared_events() {
+        use crate::abi::set::{erc20, shared, weth};
+        use substreams_ethereum::Event as _;
+
+        // Both contracts use the single `Transfer` type of the shared module
+        let event: weth::events::Transfer = erc20::events::Transfer {
+            from: hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+            to: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+            value: BigInt::from(1000u64),
+        };
+
+        let log = event.encode(NULL_ADDRESS.to_vec());
+        assert_eq!(
+            shared::events::Transfer::match_and_decode(&log),
+            Some(event.clone())
+        );
+        assert_eq!(
+            erc20::events::Events::match_and_decode(&log),
+            Some(erc20::events::Events::Transfer(event.clone()))
+        );
+        assert_eq!(
+            weth::events::Events::match_and_decode(&log),
+            Some(weth::events::Events::Transfer(event.clone()))
+        );
+
+        // Along with its borrowed view
+        let view: weth::events::TransferRef = erc20::events::TransferRef::decode(&log).unwrap();
+        assert_eq!(view.to_owned(), Ok(event));
+
+        // Events of a single contract are generated in its module
+        let deposit = weth::events::Deposit {
+            dst: hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+            wad: BigInt::from(1u64),
+        };
+        assert_eq!(
+            weth::events::Events::match_and_decode(deposit.encode(NULL_ADDRESS.to_vec())),
+            Some(weth::events::Events::Deposit(deposit))
+        );
+    }
+
+    #[test]
+    fn it_generate_serde_and_prost_messages() {
+        use crate::abi::erc721;
+        use prost::Message;
+        use substreams_ethereum::scalar::Address;
+
+        let event = erc721::events::Transfer {
+            from: Address(hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705")),
+            to: Address(hex!("fffdb7377345371817f2b4dd490319755f5899ec")),
+            token_id: BigInt::from_str("1000000000000000000000").unwrap(),
+        };
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            json,
+            r#"{"from":"0xab07a50ad459b41fe065f7bbab866d5390e9f705","to":"0xfffdb7377345371817f2b4dd490319755f5899ec","token_id":"1000000000000000000000"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<erc721::events::Transfer>(&json).unwrap(),
+            event
+        );
+
+        let message = erc721::events::pb::Transfer::from(event.clone());
+        assert_eq!(
+            message,
+            erc721::events::pb::Transfer {
+                from: event.from.to_vec(),
+                to: event.to.to_vec(),
+                token_id: "1000000000000000000000".to_string(),
+            }
+        );
+        assert_eq!(
+            erc721::events::pb::Transfer::decode(message.encode_to_vec().as_slice()).unwrap(),
+            message
+        );
+    }
 }
@@ -0,0 +1,149 @@
+use anyhow::{format_err, Context};
+use serde_json::Value;
+
+/// The JSON ABI of a contract along with its creation bytecode, extracted from the file given
+/// to [crate::build::Abigen] or `use_contract!`.
+///
+/// Supported formats are the bare JSON ABI array, the Hardhat, Foundry and Truffle artifacts
+/// holding the ABI under `abi` and the Etherscan `getabi` response holding the ABI as a JSON
+/// string under `result`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Artifact {
+    /// The bare JSON ABI array.
+    pub(crate) abi: Vec<u8>,
+    /// The creation bytecode, `None` when the format does not record it, when it is empty like
+    /// for interfaces or when it is not linked yet.
+    pub(crate) bytecode: Option<Vec<u8>>,
+}
+
+impl Artifact {
+    pub(crate) fn parse(bytes: &[u8]) -> Result<Artifact, anyhow::Error> {
+        let value: Value = serde_json::from_slice(bytes).context("parsing ABI JSON")?;
+
+        match value {
+            Value::Array(_) => Ok(Artifact {
+                abi: bytes.to_vec(),
+                bytecode: None,
+            }),
+            Value::Object(ref object) if object.contains_key("abi") => {
+                let abi = &object["abi"];
+                if !abi.is_array() {
+                    return Err(format_err!("artifact field 'abi' is not a JSON ABI array"));
+                }
+
+                // Hardhat and Truffle record the bytecode as a string, Foundry as an object
+                let bytecode = match &object.get("bytecode") {
+                    Some(Value::String(code)) => decode_bytecode(code),
+                    Some(Value::Object(code)) => {
+                        code.get("object").and_then(Value::as_str).and_then(decode_bytecode)
+                    }
+                    _ => None,
+                };
+
+                Ok(Artifact {
+                    abi: serde_json::to_vec(abi)?,
+                    bytecode,
+                })
+            }
+            Value::Object(ref object) if object.contains_key("result") => {
+                let result = object["result"].as_str().unwrap_or_default();
+                if object.get("status").and_then(Value::as_str) == Some("0") {
+                    return Err(format_err!("Etherscan response is an error: {}", result));
+                }
+
+                let abi: Value = serde_json::from_str(result)
+                    .context("parsing the JSON ABI of Etherscan response field 'result'")?;
+                if !abi.is_array() {
+                    return Err(format_err!(
+                        "Etherscan response field 'result' is not a JSON ABI array"
+                    ));
+                }
+
+                Ok(Artifact {
+                    abi: result.as_bytes().to_vec(),
+                    bytecode: None,
+                })
+            }
+            _ => Err(format_err!(
+                "unknown ABI format, expected a JSON ABI array, a Hardhat, Foundry or Truffle artifact or an Etherscan response"
+            )),
+        }
+    }
+}
+
+/// Decodes the `0x` prefixed hex `code`, `None` when it is empty or holds the placeholders of
+/// libraries that are not linked.
+fn decode_bytecode(code: &str) -> Option<Vec<u8>> {
+    let code = code.strip_prefix("0x").unwrap_or(code);
+    if code.is_empty() {
+        return None;
+    }
+
+    hex::decode(code).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::Artifact;
+
+    const ABI: &str = r#"[{"type":"event","name":"Paused","inputs":[],"anonymous":false}]"#;
+
+    #[test]
+    fn test_parse_artifacts() {
+        let abi = |artifact: &Artifact| serde_json::from_slice::<Value>(&artifact.abi).unwrap();
+        let expected: Value = serde_json::from_str(ABI).unwrap();
+
+        let bare = Artifact::parse(ABI.as_bytes()).unwrap();
+        assert_eq!(bare.abi, ABI.as_bytes());
+        assert_eq!(bare.bytecode, None);
+
+        let hardhat = format!(
+            r#"{{"contractName":"Pausable","abi":{},"bytecode":"0x6080","deployedBytecode":"0x"}}"#,
+            ABI
+        );
+        let artifact = Artifact::parse(hardhat.as_bytes()).unwrap();
+        assert_eq!(abi(&artifact), expected);
+        assert_eq!(artifact.bytecode, Some(vec![0x60, 0x80]));
+
+        let foundry = format!(
+            r#"{{"abi":{},"bytecode":{{"object":"0x6080","linkReferences":{{}}}}}}"#,
+            ABI
+        );
+        let artifact = Artifact::parse(foundry.as_bytes()).unwrap();
+        assert_eq!(abi(&artifact), expected);
+        assert_eq!(artifact.bytecode, Some(vec![0x60, 0x80]));
+
+        let interface = format!(r#"{{"abi":{},"bytecode":"0x"}}"#, ABI);
+        assert_eq!(
+            Artifact::parse(interface.as_bytes()).unwrap().bytecode,
+            None
+        );
+
+        let unlinked = format!(r#"{{"abi":{},"bytecode":"0x73__$a1b2$__"}}"#, ABI);
+        assert_eq!(Artifact::parse(unlinked.as_bytes()).unwrap().bytecode, None);
+
+        let etherscan = format!(
+            r#"{{"status":"1","message":"OK","result":{}}}"#,
+            serde_json::to_string(ABI).unwrap()
+        );
+        let artifact = Artifact::parse(etherscan.as_bytes()).unwrap();
+        assert_eq!(abi(&artifact), expected);
+        assert_eq!(artifact.bytecode, None);
+    }
+
+    #[test]
+    fn test_parse_invalid_artifacts() {
+        let etherscan = r#"{"status":"0","message":"NOTOK","result":"Invalid API Key"}"#;
+        assert_eq!(
+            Artifact::parse(etherscan.as_bytes())
+                .unwrap_err()
+                .to_string(),
+            "Etherscan response is an error: Invalid API Key"
+        );
+
+        assert!(Artifact::parse(br#"{"abi":{}}"#).is_err());
+        assert!(Artifact::parse(br#"{"bytecode":"0x"}"#).is_err());
+    }
+}
@@ -1,86 +1,334 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 use std::str;
 
-use crate::{generate_abi_code, generate_abi_code_from_bytes, normalize_path};
-use anyhow::Context;
+use crate::{
+    contract::{generate_shared_module, Contract, ContractOptions, SharedItem},
+    load_contract,
+    meta::TypeOptions,
+    normalize_path,
+    signature::parse_signatures,
+};
+use anyhow::{format_err, Context};
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
 use quote::quote;
 
 #[derive(Debug, Clone)]
 pub struct Abigen<'a> {
+    /// The name of the contract, used to generate the contract handle type.
+    contract_name: String,
+
     /// The path where to find the source of the ABI JSON for the contract whose bindings
     /// are being generated.
     abi_path: PathBuf,
 
     /// The bytes of the ABI for the contract whose bindings are being generated.
-    bytes: Option<&'a [u8]>,
+    bytes: Option<Cow<'a, [u8]>>,
+
+    /// Options changing the Rust types used in the generated bindings.
+    options: TypeOptions,
+
+    /// The variants of the Solidity enums, keyed by enum name.
+    enums: BTreeMap<String, Vec<String>>,
+
+    /// Options selecting the generated items and how they are exposed.
+    contract_options: ContractOptions,
 }
 
 impl<'a> Abigen<'a> {
     /// Creates a new builder for the given contract name and where the ABI JSON file can be found
     /// at `path`, which is relative to the your crate's root directory (where `Cargo.toml` file is located).
-    pub fn new<S: AsRef<str>>(_contract_name: S, path: S) -> Result<Self, anyhow::Error> {
+    ///
+    /// The file is either the bare JSON ABI, a Hardhat, Foundry or Truffle artifact holding it
+    /// under `abi` or an Etherscan `getabi` response holding it under `result`. The creation
+    /// bytecode of artifacts is generated as `Constructor::BYTECODE`, a constructor without
+    /// arguments is generated for it if the ABI has none.
+    pub fn new<S: AsRef<str>>(contract_name: S, path: S) -> Result<Self, anyhow::Error> {
         let path = normalize_path(path.as_ref()).context("normalize path")?;
 
         Ok(Self {
+            contract_name: contract_name.as_ref().to_string(),
             abi_path: path,
             bytes: None,
+            options: TypeOptions::default(),
+            enums: BTreeMap::new(),
+            contract_options: ContractOptions::default(),
         })
     }
 
     /// Creates a new builder for the given contract name and where the ABI bytes can be found
-    /// at 'abi_bytes'.
+    /// at 'abi_bytes', in any of the formats accepted by [Abigen::new].
     pub fn from_bytes<S: AsRef<str>>(
-        _contract_name: S,
+        contract_name: S,
         abi_bytes: &'a [u8],
     ) -> Result<Self, anyhow::Error> {
         Ok(Self {
+            contract_name: contract_name.as_ref().to_string(),
+            abi_path: "".parse()?,
+            bytes: Some(Cow::Borrowed(abi_bytes)),
+            options: TypeOptions::default(),
+            enums: BTreeMap::new(),
+            contract_options: ContractOptions::default(),
+        })
+    }
+
+    /// Creates a new builder for the given contract name whose ABI is described by the
+    /// human-readable Solidity `signatures`, like
+    /// `event Transfer(address indexed from, address indexed to, uint256 value)` or
+    /// `function balanceOf(address) view returns (uint256)`. Tuples are written `tuple(...)`
+    /// or `(...)`, they are generated as tuples as their struct name is unknown.
+    pub fn from_signatures<S: AsRef<str>, T: AsRef<str>>(
+        contract_name: S,
+        signatures: &[T],
+    ) -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            contract_name: contract_name.as_ref().to_string(),
             abi_path: "".parse()?,
-            bytes: Some(abi_bytes),
+            bytes: Some(Cow::Owned(parse_signatures(signatures)?)),
+            options: TypeOptions::default(),
+            enums: BTreeMap::new(),
+            contract_options: ContractOptions::default(),
         })
     }
 
+    /// Generates `substreams_ethereum::scalar::Address` for `address` parameters and
+    /// `substreams_ethereum::scalar::H256` for `bytes32` ones instead of `Vec<u8>` and
+    /// `[u8; 32]`. The `address` of the contract handle becomes an `Address` too.
+    pub fn typed_scalars(mut self, enabled: bool) -> Self {
+        self.options.typed_scalars = enabled;
+        self
+    }
+
+    /// Generates native Rust integers (`u8` to `u128`, `i8` to `i128`) for `uint` and `int`
+    /// parameters of up to 128 bits, the smallest one holding the declared width is used and
+    /// decoding fails on values out of that width. Enabled by default, when disabled every
+    /// integer is a `substreams::scalar::BigInt`.
+    pub fn native_ints(mut self, enabled: bool) -> Self {
+        self.options.native_ints = enabled;
+        self
+    }
+
+    /// Declares the `variants` of the Solidity enum `name`, in declaration order, the JSON ABI
+    /// only records enums as `uint8` with their name in `internalType`. A Rust enum is then
+    /// generated for it, decoding fails on values that are not a variant. Enums without declared
+    /// variants are generated as an alias of `u8`. Generation fails with more than 256 variants.
+    pub fn enum_variants(mut self, name: &str, variants: &[&str]) -> Self {
+        self.enums.insert(
+            name.to_string(),
+            variants.iter().map(|variant| variant.to_string()).collect(),
+        );
+        self
+    }
+
+    /// Names the types generated for overloaded functions, events and errors after their
+    /// parameter types, e.g. `SafeTransferFromAddressAddressUint256Bytes`, instead of their
+    /// position in the ABI, e.g. `SafeTransferFrom2`. Positional names change when an overload
+    /// is added to the ABI while signature names are stable.
+    pub fn signature_overload_names(mut self, enabled: bool) -> Self {
+        self.contract_options.names.signature_overloads = enabled;
+        self
+    }
+
+    /// Names `name` the type generated for the function, event or error whose canonical
+    /// signature is `signature`, e.g. `safeTransferFrom(address,address,uint256)`, whether it
+    /// is overloaded or not. The canonical signature is the `SIGNATURE` constant of the
+    /// generated type. Generation fails if two types end up with the same name.
+    pub fn alias<S: AsRef<str>, N: AsRef<str>>(mut self, signature: S, name: N) -> Self {
+        self.contract_options.names.aliases.insert(
+            signature.as_ref().replace(' ', ""),
+            name.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Generates only the events of the contract, along with the structs and enums they use.
+    /// The constructor, functions, custom errors and contract handle are skipped.
+    pub fn events_only(mut self) -> Self {
+        self.contract_options.events_only = true;
+        self
+    }
+
+    /// Generates only the events named `names`, all overloads of an event are kept. Can be
+    /// called multiple times to extend the list.
+    pub fn include_events<I: IntoIterator<Item = S>, S: AsRef<str>>(mut self, names: I) -> Self {
+        self.contract_options.events.include(names);
+        self
+    }
+
+    /// Skips the events named `names`, takes precedence over [Abigen::include_events].
+    pub fn exclude_events<I: IntoIterator<Item = S>, S: AsRef<str>>(mut self, names: I) -> Self {
+        self.contract_options.events.exclude(names);
+        self
+    }
+
+    /// Generates only the functions named `names`, all overloads of a function are kept. Can be
+    /// called multiple times to extend the list.
+    pub fn include_functions<I: IntoIterator<Item = S>, S: AsRef<str>>(mut self, names: I) -> Self {
+        self.contract_options.functions.include(names);
+        self
+    }
+
+    /// Skips the functions named `names`, takes precedence over [Abigen::include_functions].
+    pub fn exclude_functions<I: IntoIterator<Item = S>, S: AsRef<str>>(mut self, names: I) -> Self {
+        self.contract_options.functions.exclude(names);
+        self
+    }
+
+    /// Skips the code performing RPC calls: the `call` method of functions, their
+    /// `RPCDecodable` implementation and the contract handle.
+    pub fn skip_rpc_helpers(mut self) -> Self {
+        self.contract_options.rpc_helpers = false;
+        self
+    }
+
+    /// Generates a borrowed view of each event next to it, e.g. `TransferRef<'a>`, holding the
+    /// `Log` and decoding each field on access with a method, e.g. `transfer.to()`. Untyped
+    /// addresses and `bytes` are `&'a [u8]` and strings `Cow<'a, str>` borrowing the log, so
+    /// filtering the logs of a block on a field does not allocate. `to_owned()` decodes the
+    /// whole event. The accessor of a parameter named like a method of the view (`decode`,
+    /// `match_and_decode`, `log` or `to_owned`) is suffixed with `_`, e.g. `log_()`.
+    pub fn borrowed_events(mut self, enabled: bool) -> Self {
+        self.contract_options.borrowed_events = enabled;
+        self
+    }
+
+    /// Sets the visibility of the generated modules (`functions`, `events`, ...), `pub` by
+    /// default, e.g. `pub(crate)` to keep the bindings private to the crate. Generation fails
+    /// if `visibility` is not a valid Rust visibility.
+    pub fn module_visibility<S: AsRef<str>>(mut self, visibility: S) -> Self {
+        self.contract_options.module_visibility = visibility.as_ref().to_string();
+        self
+    }
+
+    /// Derives `serde::Serialize` and `serde::Deserialize` for the generated structs and enums.
+    /// Bytes are serialized as `0x` prefixed hex strings and big integers as decimal strings
+    /// through `substreams_ethereum::abi::serde`, which requires the `serde` feature of
+    /// `substreams-ethereum`.
+    pub fn serde(mut self, enabled: bool) -> Self {
+        self.options.serde = enabled;
+        self
+    }
+
+    /// Derives the traits at paths `names`, e.g. `Eq` or `serde::Serialize`, for the generated
+    /// structs, on top of `Debug`, `Clone` and `PartialEq`. Generation fails if a name is not a
+    /// valid Rust path.
+    pub fn add_derives<I: IntoIterator<Item = S>, S: AsRef<str>>(mut self, names: I) -> Self {
+        self.contract_options
+            .derives
+            .extend(names.into_iter().map(|name| name.as_ref().to_string()));
+        self
+    }
+
+    /// Adds the outer `attributes`, e.g. `#[serde(rename_all = "camelCase")]`, to the generated
+    /// structs. Generation fails if an attribute is not a valid Rust outer attribute.
+    pub fn add_attributes<I: IntoIterator<Item = S>, S: AsRef<str>>(
+        mut self,
+        attributes: I,
+    ) -> Self {
+        self.contract_options.attributes.extend(
+            attributes
+                .into_iter()
+                .map(|attribute| attribute.as_ref().to_string()),
+        );
+        self
+    }
+
+    /// Generates a `prost::Message` version of each event, function and struct in a `pb`
+    /// module next to it, e.g. `events::pb::Transfer`, along with a `From` conversion from the
+    /// generated type. Bytes are `bytes`, integers wider than 64 bits are decimal `string` and
+    /// enums are `uint32`. Generation fails on types without protobuf equivalent, like nested
+    /// arrays, exclude the items using them to skip them.
+    pub fn prost(mut self, enabled: bool) -> Self {
+        self.contract_options.prost = enabled;
+        self
+    }
+
+    /// Generates the `.proto` schema of the messages of [Abigen::prost], which it enables, in
+    /// the protobuf `package`, e.g. `erc721.v1`. Events, functions and structs are nested in
+    /// the `Events`, `Functions` and `Structs` messages, write the schema with
+    /// [GeneratedBindings::write_proto_to_file]. The Rust messages are wire compatible with
+    /// the schema so they can be the output of a module declared with it.
+    pub fn proto<S: AsRef<str>>(mut self, package: S) -> Self {
+        self.contract_options.proto_package = Some(package.as_ref().to_string());
+        self
+    }
+
     pub fn generate(&self) -> Result<GeneratedBindings, anyhow::Error> {
-        let item;
+        let c = self.load()?;
+
+        Ok(GeneratedBindings {
+            code: unparse(c.generate())?,
+            proto: c.generate_proto(),
+        })
+    }
+
+    /// Loads the generator of the contract.
+    fn load(&self) -> Result<Contract, anyhow::Error> {
+        let c;
         match &self.bytes {
             None => {
-                item = generate_abi_code(self.abi_path.to_string_lossy())
-                    .context("generating abi code")?;
+                let source = std::fs::read(&self.abi_path).with_context(|| {
+                    format!("reading abi file {}", self.abi_path.to_string_lossy())
+                })?;
+
+                c = load_contract(
+                    &source,
+                    Some(self.contract_name.clone()),
+                    self.options,
+                    self.enums.clone(),
+                    &self.contract_options,
+                )
+                .context("generating abi code")?;
             }
             Some(bytes) => {
-                item = generate_abi_code_from_bytes(bytes).context("generating abi code")?;
+                c = load_contract(
+                    bytes,
+                    Some(self.contract_name.clone()),
+                    self.options,
+                    self.enums.clone(),
+                    &self.contract_options,
+                )
+                .context("generating abi code")?;
             }
         }
 
-        // FIXME: We wrap into a fake module because `syn::parse2(file)` doesn't like it when there is
-        // no wrapping statement. Below that we remove the first and last line of the generated code
-        // which fixes the problem.
-        //
-        // There is probably a way to avoid that somehow?
-        let file = quote! {
-            mod __remove__ {
-                #item
-            }
-        };
+        Ok(c)
+    }
+}
 
-        let file = syn::File {
-            attrs: vec![],
-            items: vec![syn::parse2(file).context("parsing generated code")?],
-            shebang: None,
-        };
+/// Formats the generated `item` as the content of a file.
+fn unparse(item: TokenStream) -> Result<String, anyhow::Error> {
+    // FIXME: We wrap into a fake module because `syn::parse2(file)` doesn't like it when there is
+    // no wrapping statement. Below that we remove the first and last line of the generated code
+    // which fixes the problem.
+    //
+    // There is probably a way to avoid that somehow?
+    let file = quote! {
+        mod __remove__ {
+            #item
+        }
+    };
 
-        let code = prettyplease::unparse(&file);
-        let mut lines = code.lines();
-        lines.next();
-        lines.next_back();
+    let file = syn::File {
+        attrs: vec![],
+        items: vec![syn::parse2(file).context("parsing generated code")?],
+        shebang: None,
+    };
 
-        Ok(GeneratedBindings {
-            code: lines.collect::<Vec<_>>().join("\n"),
-        })
-    }
+    let code = prettyplease::unparse(&file);
+    let mut lines = code.lines();
+    lines.next();
+    lines.next_back();
+
+    Ok(lines.collect::<Vec<_>>().join("\n"))
 }
 
 pub struct GeneratedBindings {
     code: String,
+    /// The `.proto` schema, see [Abigen::proto].
+    proto: Option<String>,
 }
 
 impl GeneratedBindings {
@@ -95,4 +343,316 @@ impl GeneratedBindings {
         std::fs::write(path, &self.code)
             .with_context(|| format!("writing file {}", p.as_ref().to_string_lossy()))
     }
+
+    /// Writes the `.proto` schema generated when [Abigen::proto] is set, fails otherwise.
+    pub fn write_proto_to_file<P: AsRef<Path>>(&self, p: P) -> Result<(), anyhow::Error> {
+        let proto = self
+            .proto
+            .as_ref()
+            .ok_or_else(|| format_err!("no proto generated, set a package with Abigen::proto"))?;
+        let path = normalize_path(p.as_ref()).context("normalize path")?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("creating directories for {}", parent.to_string_lossy()))?
+        }
+
+        std::fs::write(path, proto)
+            .with_context(|| format!("writing file {}", p.as_ref().to_string_lossy()))
+    }
+}
+
+/// Builder generating the bindings of many contracts at once, one module per contract along
+/// with a `mod.rs` declaring them. Events, structs and enums generated identically for many
+/// contracts, like the ERC20 `Transfer` event, are generated once in a `shared` module the
+/// contract modules import them from.
+#[derive(Debug, Clone)]
+pub struct AbigenSet<'a> {
+    /// The builder of each contract, keyed by module name.
+    abigens: Vec<(String, Abigen<'a>)>,
+}
+
+impl<'a> AbigenSet<'a> {
+    /// Creates a new builder for the ABI files matching the glob `pattern`, e.g.
+    /// `abi/erc*.json`, or found in the directory `pattern` if it is one. `pattern` is relative
+    /// to your crate's root directory (where `Cargo.toml` file is located).
+    ///
+    /// Each file is loaded like [Abigen::new] does, the contract name is the file name without
+    /// extension and the module name its snake case version.
+    pub fn new<S: AsRef<str>>(pattern: S) -> Result<Self, anyhow::Error> {
+        let mut path = normalize_path(pattern.as_ref()).context("normalize path")?;
+        if path.is_dir() {
+            path.push("*.json");
+        }
+
+        let paths = glob::glob(&path.to_string_lossy())
+            .with_context(|| format!("invalid pattern {}", pattern.as_ref()))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut abigens: Vec<(String, Abigen)> = Vec::with_capacity(paths.len());
+        for path in paths {
+            let contract_name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let module = contract_name.to_snake_case();
+
+            if module == "mod" || module == SHARED_MODULE {
+                return Err(format_err!(
+                    "contract {} conflicts with the generated module {}",
+                    contract_name,
+                    module
+                ));
+            }
+            if abigens.iter().any(|(existing, _)| existing == &module) {
+                return Err(format_err!(
+                    "contracts generated in the same module {}",
+                    module
+                ));
+            }
+
+            let abigen = Abigen::new(contract_name, path.to_string_lossy().to_string())?;
+            abigens.push((module, abigen));
+        }
+
+        if abigens.is_empty() {
+            return Err(format_err!("no ABI file matches {}", pattern.as_ref()));
+        }
+
+        Ok(AbigenSet { abigens })
+    }
+
+    /// Applies `configure` to the builder of each contract, called with the contract name.
+    pub fn configure<F: FnMut(&str, Abigen<'a>) -> Abigen<'a>>(mut self, mut configure: F) -> Self {
+        self.abigens = self
+            .abigens
+            .into_iter()
+            .map(|(module, abigen)| {
+                let contract_name = abigen.contract_name.clone();
+                (module, configure(&contract_name, abigen))
+            })
+            .collect();
+        self
+    }
+
+    pub fn generate(&self) -> Result<GeneratedSet, anyhow::Error> {
+        let mut contracts = self
+            .abigens
+            .iter()
+            .map(|(module, abigen)| {
+                abigen
+                    .load()
+                    .with_context(|| format!("generating contract {}", abigen.contract_name))
+                    .map(|c| (module.clone(), c))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let items: Vec<_> = contracts.iter().map(|(_, c)| c.shareable_items()).collect();
+        let shared = shared_items(&items);
+
+        let mut events = Vec::new();
+        let mut structs = Vec::new();
+        let mut enums = Vec::new();
+        let owners: BTreeMap<_, _> = shared
+            .iter()
+            .enumerate()
+            .flat_map(|(i, keys)| keys.iter().map(move |key| (key.clone(), i)))
+            .rev()
+            .collect();
+
+        let mut modules = Vec::with_capacity(contracts.len());
+        for (i, (module, c)) in contracts.drain(..).enumerate() {
+            let c = c.with_shared(shared[i].clone());
+
+            for ((item_module, name), _) in owners.iter().filter(|(_, owner)| **owner == i) {
+                let code = c.generate_shared_item(item_module, name);
+                match *item_module {
+                    "events" => events.extend(code),
+                    "structs" => structs.extend(code),
+                    _ => enums.extend(code),
+                }
+            }
+
+            modules.push((module, unparse(c.generate())?));
+        }
+
+        let shared = match owners.is_empty() {
+            true => None,
+            false => Some(unparse(generate_shared_module(events, structs, enums))?),
+        };
+
+        Ok(GeneratedSet { modules, shared })
+    }
+}
+
+/// The module holding the items shared by the contracts of an [AbigenSet].
+const SHARED_MODULE: &str = "shared";
+
+/// Selects the items of each contract, among their shareable `items`, generated in the shared
+/// module. An item is shared when identical in at least two contracts, along with the structs
+/// and enums it uses, the version used by most contracts is shared when they differ.
+fn shared_items(items: &[Vec<SharedItem>]) -> Vec<BTreeSet<(&'static str, String)>> {
+    let mut versions: BTreeMap<(&'static str, &str), BTreeMap<&str, usize>> = BTreeMap::new();
+    for item in items.iter().flatten() {
+        *versions
+            .entry((item.module, &item.name))
+            .or_default()
+            .entry(&item.code)
+            .or_default() += 1;
+    }
+
+    // The first version used by most contracts is selected, `max_by_key` returns the last one
+    let selected: BTreeMap<_, _> = versions
+        .into_iter()
+        .filter_map(|(key, versions)| {
+            let (code, count) = versions.into_iter().rev().max_by_key(|(_, count)| *count)?;
+            (count > 1).then(|| (key, code))
+        })
+        .collect();
+
+    let mut shared: Vec<BTreeSet<(&'static str, String)>> = items
+        .iter()
+        .map(|items| {
+            items
+                .iter()
+                .filter(|item| selected.get(&(item.module, &item.name)) == Some(&&item.code[..]))
+                .map(|item| (item.module, item.name.clone()))
+                .collect()
+        })
+        .collect();
+
+    // Items whose dependencies are not shared by the contract, or that end up used by a single
+    // contract, are generated by the contracts, until no more item changes
+    loop {
+        let mut changed = false;
+
+        for (i, items) in items.iter().enumerate() {
+            for item in items {
+                let key = (item.module, item.name.clone());
+                if shared[i].contains(&key)
+                    && item.dependencies.iter().any(|d| !shared[i].contains(d))
+                {
+                    shared[i].remove(&key);
+                    changed = true;
+                }
+            }
+        }
+
+        let mut users: BTreeMap<(&'static str, String), usize> = BTreeMap::new();
+        for key in shared.iter().flatten() {
+            *users.entry(key.clone()).or_default() += 1;
+        }
+        for keys in shared.iter_mut() {
+            let count = keys.len();
+            keys.retain(|key| users[key] > 1);
+            changed |= keys.len() != count;
+        }
+
+        if !changed {
+            return shared;
+        }
+    }
+}
+
+/// The bindings generated by an [AbigenSet].
+pub struct GeneratedSet {
+    /// The code of each contract, keyed by module name.
+    modules: Vec<(String, String)>,
+    /// The code of the shared module, if any item is shared.
+    shared: Option<String>,
+}
+
+impl GeneratedSet {
+    /// Writes the module of each contract as `<module>.rs`, the shared module as `shared.rs`
+    /// and the `mod.rs` declaring them in the directory `dir`, relative to your crate's root
+    /// directory. Include them in your crate with a `mod` declaration of the directory.
+    pub fn write_to_dir<P: AsRef<Path>>(&self, dir: P) -> Result<(), anyhow::Error> {
+        let dir = normalize_path(dir.as_ref()).context("normalize path")?;
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("creating directory {}", dir.to_string_lossy()))?;
+
+        let shared = self.shared.iter().map(|code| (SHARED_MODULE, code));
+        let modules = self
+            .modules
+            .iter()
+            .map(|(module, code)| (module.as_str(), code))
+            .chain(shared);
+
+        let mut declarations = String::new();
+        for (module, code) in modules {
+            let path = dir.join(format!("{}.rs", module));
+            std::fs::write(&path, code)
+                .with_context(|| format!("writing file {}", path.to_string_lossy()))?;
+
+            declarations.push_str(&format!(
+                "#[rustfmt::skip]\n#[allow(dead_code)]\n#[allow(unused_variables)]\npub mod {};\n",
+                module
+            ));
+        }
+
+        let path = dir.join("mod.rs");
+        std::fs::write(&path, declarations)
+            .with_context(|| format!("writing file {}", path.to_string_lossy()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::shared_items;
+    use crate::contract::SharedItem;
+
+    fn item(module: &'static str, name: &str, code: &str, dependencies: &[&str]) -> SharedItem {
+        SharedItem {
+            module,
+            name: name.to_string(),
+            code: code.to_string(),
+            dependencies: dependencies
+                .iter()
+                .map(|d| ("structs", d.to_string()))
+                .collect(),
+        }
+    }
+
+    fn keys(keys: &[(&'static str, &str)]) -> BTreeSet<(&'static str, String)> {
+        keys.iter()
+            .map(|(module, name)| (*module, name.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_shared_items() {
+        let shared = shared_items(&[
+            vec![
+                item("events", "Transfer", "a", &[]),
+                item("events", "Fill", "a", &["Order"]),
+                item("structs", "Order", "a", &[]),
+            ],
+            vec![
+                item("events", "Transfer", "a", &[]),
+                item("events", "Fill", "a", &["Order"]),
+                item("structs", "Order", "b", &[]),
+            ],
+            vec![
+                item("events", "Transfer", "b", &[]),
+                item("events", "Fill", "a", &["Order"]),
+                item("structs", "Order", "b", &[]),
+            ],
+        ]);
+
+        // `Order` differs in the first contract, its `Fill` event cannot use the shared one
+        assert_eq!(
+            shared,
+            vec![
+                keys(&[("events", "Transfer")]),
+                keys(&[
+                    ("events", "Transfer"),
+                    ("events", "Fill"),
+                    ("structs", "Order")
+                ]),
+                keys(&[("events", "Fill"), ("structs", "Order")]),
+            ]
+        );
+    }
 }
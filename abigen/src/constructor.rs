@@ -6,129 +6,307 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
 
-use super::{
-	from_template_param, get_template_names, input_names, rust_type, template_param_type, to_ethabi_param_vec, to_token,
+use crate::{
+    decode_tuple, encode_tuple,
+    meta::{EntryMeta, EMPTY_ENTRY},
+    Position,
 };
 
+use super::{param_names, struct_field};
+
 /// Structure used to generate contract's constructor interface.
 pub struct Constructor {
-	inputs_declarations: Vec<TokenStream>,
-	inputs_definitions: Vec<TokenStream>,
-	tokenize: Vec<TokenStream>,
-	recreate_inputs: TokenStream,
+    /// Expression of the ABI encoding of the arguments.
+    encode: TokenStream,
+    decoded_values: TokenStream,
+    decoded_fields: Vec<TokenStream>,
+    fields: Vec<TokenStream>,
+    /// Attributes added to the generated type, like derives.
+    pub(crate) attributes: TokenStream,
+    /// Creation bytecode of the contract, known when loaded from an artifact.
+    pub(crate) bytecode: Option<Vec<u8>>,
 }
 
 impl<'a> From<&'a ethabi::Constructor> for Constructor {
-	fn from(c: &'a ethabi::Constructor) -> Self {
-		// [param0, hello_world, param2]
-		let input_names = input_names(&c.inputs);
-
-		// [T0: Into<Uint>, T1: Into<Bytes>, T2: IntoIterator<Item = U2>, U2 = Into<Uint>]
-		let inputs_declarations =
-			c.inputs.iter().enumerate().map(|(index, param)| template_param_type(&param.kind, index)).collect();
-
-		// [Uint, Bytes, Vec<Uint>]
-		let kinds: Vec<_> = c.inputs.iter().map(|param| rust_type(&param.kind)).collect();
-
-		// [T0, T1, T2]
-		let template_names: Vec<_> = get_template_names(&kinds);
-
-		// [param0: T0, hello_world: T1, param2: T2]
-		let inputs_definitions = input_names
-			.iter()
-			.zip(template_names.iter())
-			.map(|(param_name, template_name)| quote! { #param_name: #template_name });
-
-		let inputs_definitions = Some(quote! { code: ethabi::Bytes }).into_iter().chain(inputs_definitions).collect();
-
-		// [Token::Uint(param0.into()), Token::Bytes(hello_world.into()), Token::Array(param2.into_iter().map(Into::into).collect())]
-		let tokenize: Vec<_> = input_names
-			.iter()
-			.zip(c.inputs.iter())
-			.map(|(param_name, param)| to_token(&from_template_param(&param.kind, param_name), &param.kind))
-			.collect();
-
-		Constructor {
-			inputs_declarations,
-			inputs_definitions,
-			tokenize,
-			recreate_inputs: to_ethabi_param_vec(&c.inputs),
-		}
-	}
+    fn from(c: &'a ethabi::Constructor) -> Self {
+        (c, &EMPTY_ENTRY).into()
+    }
+}
+
+impl<'a> From<(&'a ethabi::Constructor, &'a EntryMeta)> for Constructor {
+    fn from((c, meta): (&'a ethabi::Constructor, &'a EntryMeta)) -> Self {
+        // [param0, hello_world, param2]
+        let input_names = param_names(&c.inputs);
+
+        let fields = input_names
+            .iter()
+            .zip(c.inputs.iter())
+            .enumerate()
+            .map(|(i, (param_name, param))| struct_field(param_name, &param.kind, meta.input(i)))
+            .collect();
+
+        let decoded_values = if !c.inputs.is_empty() {
+            quote! {
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(data, offset);
+            }
+        } else {
+            quote! {}
+        };
+
+        let decoded_fields = decode_tuple(
+            c.inputs
+                .iter()
+                .enumerate()
+                .map(|(i, param)| (&param.kind, meta.input(i))),
+            &quote! { decoder },
+            &Position::at(0),
+        )
+        .into_iter()
+        .zip(input_names.iter())
+        .map(|(decode_input, name)| {
+            quote! {
+               #name: #decode_input
+            }
+        })
+        .collect();
+
+        let encode = encode_tuple(
+            &input_names
+                .iter()
+                .zip(c.inputs.iter())
+                .enumerate()
+                .map(|(i, (param_name, param))| {
+                    (quote! { self.#param_name }, &param.kind, meta.input(i))
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        Constructor {
+            encode,
+            decoded_values,
+            decoded_fields,
+            fields,
+            attributes: TokenStream::new(),
+            bytecode: None,
+        }
+    }
 }
 
 impl Constructor {
-	/// Generates contract constructor interface.
-	pub fn generate(&self) -> TokenStream {
-		let declarations = &self.inputs_declarations;
-		let definitions = &self.inputs_definitions;
-		let tokenize = &self.tokenize;
-		let recreate_inputs = &self.recreate_inputs;
-
-		quote! {
-			/// Encodes a call to contract's constructor.
-			pub fn constructor<#(#declarations),*>(#(#definitions),*) -> ethabi::Bytes {
-				let c = ethabi::Constructor {
-					inputs: #recreate_inputs,
-				};
-				let tokens = vec![#(#tokenize),*];
-				c.encode_input(code, &tokens).expect(INTERNAL_ERR)
-			}
-		}
-	}
+    /// Generates contract constructor interface.
+    pub fn generate(&self) -> TokenStream {
+        let fields = &self.fields;
+        let encode = &self.encode;
+        let decoded_values = &self.decoded_values;
+        let decoded_fields = &self.decoded_fields;
+        let attributes = &self.attributes;
+        let bytecode = self.bytecode.as_ref().map(|bytecode| {
+            let bytecode = syn::LitByteStr::new(bytecode, Span::call_site());
+
+            quote! {
+                /// Creation bytecode of the contract, taken from its artifact.
+                pub const BYTECODE: &'static [u8] = #bytecode;
+
+                /// Encodes the creation input deploying [Self::BYTECODE] with these constructor
+                /// arguments.
+                pub fn encode_with_bytecode(&self) -> Vec<u8> {
+                    self.encode(Self::BYTECODE.to_vec())
+                }
+            }
+        });
+
+        quote! {
+            #[derive(Debug, Clone, PartialEq)]
+            #attributes
+            pub struct Constructor {
+                #(#fields),*
+            }
+
+            impl Constructor {
+                #bytecode
+
+                /// Decodes the constructor arguments of a contract creation call, they are
+                /// appended to the init code whose length is `code_len`.
+                pub fn decode_from_creation_input(
+                    call: &substreams_ethereum::pb::eth::v2::Call,
+                    code_len: usize,
+                ) -> Result<Self, substreams_ethereum::DecodeError> {
+                    if !Self::match_call(call) {
+                        return Err(substreams_ethereum::DecodeError::NotContractCreation);
+                    }
+
+                    let data = call.input.get(code_len..).ok_or(
+                        substreams_ethereum::DecodeError::DataTooShort {
+                            expected: code_len,
+                            actual: call.input.len(),
+                        },
+                    )?;
+
+                    Self::decode_at(data, code_len)
+                }
+
+                /// Decodes the ABI encoded constructor arguments, without the init code.
+                pub fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+                    Self::decode_at(data, 0)
+                }
+
+                /// Decodes the constructor arguments found at byte `offset` of the input, the
+                /// offset reported in errors.
+                fn decode_at(data: &[u8], offset: usize) -> Result<Self, substreams_ethereum::DecodeError> {
+                    #decoded_values
+
+                    Ok(Self {
+                        #(#decoded_fields),*
+                    })
+                }
+
+                /// Encodes the creation input deploying `code` with these constructor arguments.
+                pub fn encode(&self, code: Vec<u8>) -> Vec<u8> {
+                    let data = #encode;
+
+                    let mut encoded = Vec::with_capacity(code.len() + data.len());
+                    encoded.extend(code);
+                    encoded.extend(data);
+
+                    encoded
+                }
+
+                pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                    call.call_type == substreams_ethereum::pb::eth::v2::CallType::Create as i32
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-	use super::Constructor;
-	use quote::quote;
-
-	#[test]
-	fn test_no_params() {
-		let ethabi_constructor = ethabi::Constructor { inputs: vec![] };
-
-		let c = Constructor::from(&ethabi_constructor);
-
-		let expected = quote! {
-			/// Encodes a call to contract's constructor.
-			pub fn constructor<>(code: ethabi::Bytes) -> ethabi::Bytes {
-				let c = ethabi::Constructor {
-					inputs: vec![],
-				};
-				let tokens = vec![];
-				c.encode_input(code, &tokens).expect(INTERNAL_ERR)
-			}
-		};
-
-		assert_eq!(expected.to_string(), c.generate().to_string());
-	}
-
-	#[test]
-	fn test_one_param() {
-		let ethabi_constructor = ethabi::Constructor {
-			inputs: vec![ethabi::Param { name: "foo".into(), kind: ethabi::ParamType::Uint(256), internal_type: None }],
-		};
-
-		let c = Constructor::from(&ethabi_constructor);
-
-		let expected = quote! {
-			/// Encodes a call to contract's constructor.
-			pub fn constructor<T0: Into<ethabi::Uint> >(code: ethabi::Bytes, foo: T0) -> ethabi::Bytes {
-				let c = ethabi::Constructor {
-					inputs: vec![ethabi::Param {
-						name: "foo".to_owned(),
-						kind: ethabi::ParamType::Uint(256usize),
-						internal_type: None
-					}],
-				};
-				let tokens = vec![ethabi::Token::Uint(foo.into())];
-				c.encode_input(code, &tokens).expect(INTERNAL_ERR)
-			}
-		};
-
-		assert_eq!(expected.to_string(), c.generate().to_string());
-	}
+    use crate::assertions::assert_ast_eq;
+
+    use super::Constructor;
+    use quote::quote;
+
+    #[test]
+    fn test_no_params() {
+        let ethabi_constructor = ethabi::Constructor { inputs: vec![] };
+
+        let c = Constructor::from(&ethabi_constructor);
+
+        assert_ast_eq(
+            c.generate(),
+            quote! {
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct Constructor {}
+                impl Constructor {
+                    /// Decodes the constructor arguments of a contract creation call, they are
+                    /// appended to the init code whose length is `code_len`.
+                    pub fn decode_from_creation_input(
+                        call: &substreams_ethereum::pb::eth::v2::Call,
+                        code_len: usize,
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        if !Self::match_call(call) {
+                            return Err(substreams_ethereum::DecodeError::NotContractCreation);
+                        }
+                        let data = call.input.get(code_len..).ok_or(
+                            substreams_ethereum::DecodeError::DataTooShort {
+                                expected: code_len,
+                                actual: call.input.len(),
+                            },
+                        )?;
+                        Self::decode_at(data, code_len)
+                    }
+                    /// Decodes the ABI encoded constructor arguments, without the init code.
+                    pub fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+                        Self::decode_at(data, 0)
+                    }
+                    /// Decodes the constructor arguments found at byte `offset` of the input, the
+                    /// offset reported in errors.
+                    fn decode_at(data: &[u8], offset: usize) -> Result<Self, substreams_ethereum::DecodeError> {
+                        Ok(Self {})
+                    }
+                    /// Encodes the creation input deploying `code` with these constructor arguments.
+                    pub fn encode(&self, code: Vec<u8>) -> Vec<u8> {
+                        let data = Vec::<u8>::new();
+                        let mut encoded = Vec::with_capacity(code.len() + data.len());
+                        encoded.extend(code);
+                        encoded.extend(data);
+                        encoded
+                    }
+                    pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                        call.call_type == substreams_ethereum::pb::eth::v2::CallType::Create as i32
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_one_param() {
+        let ethabi_constructor = ethabi::Constructor {
+            inputs: vec![ethabi::Param {
+                name: "foo".into(),
+                kind: ethabi::ParamType::Address,
+                internal_type: None,
+            }],
+        };
+
+        let c = Constructor::from(&ethabi_constructor);
+
+        assert_ast_eq(
+            c.generate(),
+            quote! {
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct Constructor {
+                    pub foo: Vec<u8>
+                }
+                impl Constructor {
+                    /// Decodes the constructor arguments of a contract creation call, they are
+                    /// appended to the init code whose length is `code_len`.
+                    pub fn decode_from_creation_input(
+                        call: &substreams_ethereum::pb::eth::v2::Call,
+                        code_len: usize,
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        if !Self::match_call(call) {
+                            return Err(substreams_ethereum::DecodeError::NotContractCreation);
+                        }
+                        let data = call.input.get(code_len..).ok_or(
+                            substreams_ethereum::DecodeError::DataTooShort {
+                                expected: code_len,
+                                actual: call.input.len(),
+                            },
+                        )?;
+                        Self::decode_at(data, code_len)
+                    }
+                    /// Decodes the ABI encoded constructor arguments, without the init code.
+                    pub fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+                        Self::decode_at(data, 0)
+                    }
+                    /// Decodes the constructor arguments found at byte `offset` of the input, the
+                    /// offset reported in errors.
+                    fn decode_at(data: &[u8], offset: usize) -> Result<Self, substreams_ethereum::DecodeError> {
+                        let decoder = substreams_ethereum::abi::Decoder::with_offset(data, offset);
+                        Ok(Self {
+                            foo: decoder.address(0usize)?.to_vec()
+                        })
+                    }
+                    /// Encodes the creation input deploying `code` with these constructor arguments.
+                    pub fn encode(&self, code: Vec<u8>) -> Vec<u8> {
+                        let data = substreams_ethereum::abi::Encoder::encode(32usize, |encoder| {
+                            encoder.address(self.foo.as_slice());
+                        });
+                        let mut encoded = Vec::with_capacity(code.len() + data.len());
+                        encoded.extend(code);
+                        encoded.extend(data);
+                        encoded
+                    }
+                    pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                        call.call_type == substreams_ethereum::pb::eth::v2::CallType::Create as i32
+                    }
+                }
+            },
+        );
+    }
 }
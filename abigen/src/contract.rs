@@ -6,107 +6,815 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use proc_macro2::TokenStream;
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{format_err, Context};
+use ethabi::ParamType;
+use heck::ToUpperCamelCase;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
+use syn::parse::Parser;
+
+use crate::{
+    canonical_signature,
+    constructor::Constructor,
+    enums::Enum,
+    error::Error,
+    event::{generate_events_enum, Event},
+    function::{generate_functions_enum, Function},
+    message::{Message, Param},
+    meta::{AbiMeta, TypeOptions},
+    structs::Struct,
+};
+
+/// Options of [crate::build::Abigen] selecting the items generated for a contract and how
+/// they are exposed.
+#[derive(Debug, Clone)]
+pub(crate) struct ContractOptions {
+    pub(crate) events: ItemSelection,
+    pub(crate) functions: ItemSelection,
+    /// Generates only the events, the constructor, functions and custom errors are skipped.
+    pub(crate) events_only: bool,
+    /// Generates the `call` methods of functions and the contract handle, which go through RPC.
+    pub(crate) rpc_helpers: bool,
+    /// Generates the borrowed view of each event, e.g. `TransferRef<'a>`.
+    pub(crate) borrowed_events: bool,
+    /// Visibility of the generated modules, e.g. `pub(crate)`.
+    pub(crate) module_visibility: String,
+    /// Paths of the extra traits derived by the generated structs, e.g. `Eq`.
+    pub(crate) derives: Vec<String>,
+    /// Extra outer attributes of the generated structs, e.g. `#[serde(rename_all = "camelCase")]`.
+    pub(crate) attributes: Vec<String>,
+    /// Generates a `prost::Message` version of the events, functions and structs in a `pb`
+    /// module of their own module.
+    pub(crate) prost: bool,
+    /// Package of the `.proto` schema of the `prost::Message` versions, the schema is only
+    /// generated when set.
+    pub(crate) proto_package: Option<String>,
+    /// Names of the generated types of overloaded and aliased entries.
+    pub(crate) names: EntryNames,
+}
+
+impl Default for ContractOptions {
+    fn default() -> Self {
+        ContractOptions {
+            events: ItemSelection::default(),
+            functions: ItemSelection::default(),
+            events_only: false,
+            rpc_helpers: true,
+            borrowed_events: false,
+            module_visibility: "pub".to_string(),
+            derives: Vec::new(),
+            attributes: Vec::new(),
+            prost: false,
+            proto_package: None,
+            names: EntryNames::default(),
+        }
+    }
+}
+
+impl ContractOptions {
+    /// Removes from `c` and `meta` the items that are not selected.
+    pub(crate) fn retain(&self, c: &mut ethabi::Contract, meta: &mut AbiMeta) {
+        if self.events_only {
+            c.constructor = None;
+            c.functions.clear();
+            c.errors.clear();
+        }
+
+        c.events.retain(|name, _| self.events.allows(name));
+        c.functions.retain(|name, _| self.functions.allows(name));
+        meta.retain(|kind, name| match kind {
+            "event" => self.events.allows(name),
+            "function" => !self.events_only && self.functions.allows(name),
+            _ => !self.events_only,
+        });
+    }
+}
+
+/// Names of the ABI items of a kind to generate, all of them by default.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ItemSelection {
+    /// Only these items are generated when set.
+    include: Option<BTreeSet<String>>,
+    exclude: BTreeSet<String>,
+}
+
+impl ItemSelection {
+    pub(crate) fn include<I: IntoIterator<Item = S>, S: AsRef<str>>(&mut self, names: I) {
+        self.include
+            .get_or_insert_with(BTreeSet::new)
+            .extend(names.into_iter().map(|name| name.as_ref().to_string()));
+    }
+
+    pub(crate) fn exclude<I: IntoIterator<Item = S>, S: AsRef<str>>(&mut self, names: I) {
+        self.exclude
+            .extend(names.into_iter().map(|name| name.as_ref().to_string()));
+    }
+
+    /// Whether the item `name`, covering all of its overloads, is generated.
+    fn allows(&self, name: &str) -> bool {
+        let included = match &self.include {
+            Some(include) => include.contains(name),
+            None => true,
+        };
+
+        included && !self.exclude.contains(name)
+    }
+}
+
+/// How the generated types of functions, events and errors are named, overloads sharing the
+/// same Solidity name need distinct Rust names.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EntryNames {
+    /// Suffixes overloads with their parameter types, e.g. `SafeTransferFromAddressAddressUint256`,
+    /// instead of their position in the ABI, e.g. `SafeTransferFrom1`, which changes when an
+    /// overload is added.
+    pub(crate) signature_overloads: bool,
+    /// Names keyed by canonical signature, e.g. `transfer(address,uint256)`, they take precedence
+    /// over the other names and apply to entries that are not overloaded too.
+    pub(crate) aliases: BTreeMap<String, String>,
+}
+
+impl EntryNames {
+    /// Names of the entries named `name`, given the types of the parameters of each overload
+    /// in ABI order.
+    fn names(&self, name: &str, overloads: &[Vec<&ParamType>]) -> Vec<String> {
+        overloads
+            .iter()
+            .enumerate()
+            .map(|(index, kinds)| {
+                let signature = canonical_signature(name, kinds.iter().copied());
+                if let Some(alias) = self.aliases.get(&signature) {
+                    return alias.clone();
+                }
+
+                if overloads.len() <= 1 {
+                    return name.to_string();
+                }
+                if !self.signature_overloads {
+                    return format!("{}{}", name, index + 1);
+                }
+
+                // Overloads differing only by their outputs have the same parameter types, they
+                // are numbered in ABI order
+                let name = kinds
+                    .iter()
+                    .fold(name.to_string(), |name, kind| name + &type_name(kind));
+                let same: Vec<_> = (0..overloads.len())
+                    .filter(|other| &overloads[*other] == kinds)
+                    .collect();
+                match same.iter().position(|other| *other == index) {
+                    Some(position) if same.len() > 1 => format!("{}{}", name, position + 1),
+                    _ => name,
+                }
+            })
+            .collect()
+    }
+}
+
+/// The Rust identifier part describing `kind`, e.g. `Uint256Array` for `uint256[]`.
+fn type_name(kind: &ParamType) -> String {
+    match kind {
+        ParamType::Address => "Address".to_string(),
+        ParamType::Bytes => "Bytes".to_string(),
+        ParamType::Int(size) => format!("Int{}", size),
+        ParamType::Uint(size) => format!("Uint{}", size),
+        ParamType::Bool => "Bool".to_string(),
+        ParamType::String => "String".to_string(),
+        ParamType::FixedBytes(size) => format!("Bytes{}", size),
+        ParamType::Array(kind) => format!("{}Array", type_name(kind)),
+        ParamType::FixedArray(kind, size) => format!("{}Array{}", type_name(kind), size),
+        ParamType::Tuple(kinds) => kinds
+            .iter()
+            .fold("Tuple".to_string(), |name, kind| name + &type_name(kind)),
+    }
+}
+
+/// Fails when one of the generated `names` is not a valid Rust identifier or two of them are
+/// the same, which happens when aliases or signature names of overloads collide.
+fn check_names<'a, I: IntoIterator<Item = &'a String>>(
+    kind: &str,
+    names: I,
+) -> Result<(), anyhow::Error> {
+    let mut seen = BTreeSet::new();
+    for name in names {
+        let ident = name.to_upper_camel_case();
+        syn::parse_str::<syn::Ident>(&ident)
+            .with_context(|| format!("invalid {} name {}", kind, name))?;
+
+        if !seen.insert(ident) {
+            return Err(format_err!(
+                "two {}s are generated as {}, rename one with Abigen::alias",
+                kind,
+                name.to_upper_camel_case()
+            ));
+        }
+    }
 
-// use crate::{constructor::Constructor,};
-use crate::{event::Event, function::Function};
+    Ok(())
+}
 
 /// Structure used to generate rust interface for solidity contract.
 pub struct Contract {
-    // constructor: Option<Constructor>,
+    /// Contract name, a handle type is generated for the contract when known.
+    name: Option<String>,
+    options: TypeOptions,
+    /// Generates the contract handle, see [ContractOptions::rpc_helpers].
+    rpc_helpers: bool,
+    visibility: syn::Visibility,
+    constructor: Option<Constructor>,
     functions: Vec<Function>,
     events: Vec<Event>,
+    errors: Vec<Error>,
+    enums: Vec<Enum>,
+    structs: Vec<Struct>,
+    /// The `prost::Message` versions of events, functions and structs, `None` unless
+    /// [ContractOptions::prost] is set.
+    messages: Option<Messages>,
+    proto_package: Option<String>,
+    /// The events, structs and enums generated in the shared module, as `(module, name)`.
+    shared: BTreeSet<(&'static str, String)>,
+}
+
+/// An event, struct or enum of a contract that can be generated once in the module shared by
+/// the contracts of a [crate::build::AbigenSet].
+pub(crate) struct SharedItem {
+    /// The module of the item, `events`, `structs` or `enums`.
+    pub(crate) module: &'static str,
+    pub(crate) name: String,
+    /// The generated code, identical items have the same code.
+    pub(crate) code: String,
+    /// The structs and enums used by the item, as `(module, name)`.
+    pub(crate) dependencies: Vec<(&'static str, String)>,
+}
+
+/// The `prost::Message` versions of the generated types, grouped by module.
+struct Messages {
+    events: Vec<Message>,
+    functions: Vec<Message>,
+    structs: Vec<Message>,
 }
 
 impl<'a> From<&'a ethabi::Contract> for Contract {
     fn from(c: &'a ethabi::Contract) -> Self {
+        Contract::new(c, AbiMeta::default(), &EntryNames::default())
+            .expect("no struct to generate without ABI details")
+    }
+}
+
+impl Contract {
+    /// Creates the generator for contract `c`, `meta` holds the details of the JSON ABI that
+    /// `ethabi` does not keep, like struct names of tuples and enum names of `uint8`.
+    pub(crate) fn new(
+        c: &ethabi::Contract,
+        mut meta: AbiMeta,
+        names: &EntryNames,
+    ) -> Result<Self, anyhow::Error> {
+        // Enums first as struct fields refer to them.
+        let enums = meta
+            .resolve_enums()
+            .iter()
+            .map(Enum::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let structs = meta
+            .resolve_structs()
+            .iter()
+            .map(Struct::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let meta = &meta;
+
         let mut events: Vec<_> = c
             .events
             .values()
             .flat_map(|events| {
-                let count = events.len();
+                let overloads: Vec<Vec<_>> = events
+                    .iter()
+                    .map(|event| event.inputs.iter().map(|param| &param.kind).collect())
+                    .collect();
+                let names = names.names(&events[0].name, &overloads);
 
-                events.iter().enumerate().map(move |(index, event)| {
-                    if count <= 1 {
-                        (&event.name, event).into()
-                    } else {
-                        (&format!("{}{}", event.name, index + 1), event).into()
-                    }
-                })
+                events
+                    .iter()
+                    .zip(names)
+                    .enumerate()
+                    .map(move |(index, (event, name))| {
+                        let entry = meta.entry("event", &event.name, index);
+                        (&name, event, entry).into()
+                    })
             })
             .collect();
 
         // Since some people will actually commit this code, we use a "stable" generation order
         events.sort_by(|left: &Event, right: &Event| left.name.cmp(&right.name));
+        check_names("event", events.iter().map(|event| &event.name))?;
 
         let mut functions: Vec<_> = c
             .functions
             .values()
             .flat_map(|functions| {
-                let count = functions.len();
+                let overloads: Vec<Vec<_>> = functions
+                    .iter()
+                    .map(|function| function.inputs.iter().map(|param| &param.kind).collect())
+                    .collect();
+                let names = names.names(&functions[0].name, &overloads);
 
-                functions.iter().enumerate().map(move |(index, function)| {
-                    if count <= 1 {
-                        (&function.name, function).into()
-                    } else {
-                        (&format!("{}{}", function.name, index + 1), function).into()
-                    }
-                })
+                functions
+                    .iter()
+                    .zip(names)
+                    .enumerate()
+                    .map(move |(index, (function, name))| {
+                        let entry = meta.entry("function", &function.name, index);
+                        (&name, function, entry).into()
+                    })
             })
             .collect();
 
         // Since some people will actually commit this code, we use a "stable" generation order
         functions.sort_by(|left: &Function, right: &Function| left.name.cmp(&right.name));
+        check_names("function", functions.iter().map(|function| &function.name))?;
+
+        let mut errors: Vec<_> = c
+            .errors
+            .values()
+            .flat_map(|errors| {
+                let overloads: Vec<Vec<_>> = errors
+                    .iter()
+                    .map(|error| error.inputs.iter().map(|param| &param.kind).collect())
+                    .collect();
+                let names = names.names(&errors[0].name, &overloads);
+
+                errors
+                    .iter()
+                    .zip(names)
+                    .enumerate()
+                    .map(move |(index, (error, name))| {
+                        let entry = meta.entry("error", &error.name, index);
+                        (&name, error, entry).into()
+                    })
+            })
+            .collect();
+
+        // Since some people will actually commit this code, we use a "stable" generation order
+        errors.sort_by(|left: &Error, right: &Error| left.name.cmp(&right.name));
+        check_names("error", errors.iter().map(|error| &error.name))?;
 
-        Contract {
-            // constructor: c.constructor.as_ref().map(Into::into),
+        Ok(Contract {
+            name: None,
+            options: meta.options(),
+            rpc_helpers: true,
+            visibility: syn::parse_quote! { pub },
+            constructor: c
+                .constructor
+                .as_ref()
+                .map(|constructor| (constructor, meta.entry("constructor", "", 0)).into()),
             functions,
             events,
+            errors,
+            enums,
+            structs,
+            messages: None,
+            proto_package: None,
+            shared: BTreeSet::new(),
+        })
+    }
+
+    /// Sets the name of the contract, used to generate the contract handle.
+    pub(crate) fn with_name(mut self, name: Option<String>) -> Self {
+        self.name = name;
+        self
+    }
+
+    /// Sets the creation bytecode of the contract, used to encode its creation input. A
+    /// constructor without arguments is generated when the ABI does not declare one.
+    pub(crate) fn with_bytecode(mut self, bytecode: Option<Vec<u8>>) -> Self {
+        if bytecode.is_some() {
+            let constructor = self.constructor.get_or_insert_with(|| {
+                Constructor::from(&ethabi::Constructor { inputs: Vec::new() })
+            });
+            constructor.bytecode = bytecode;
         }
+        self
+    }
+
+    /// Applies the generation `options` to the items that are kept, see
+    /// [ContractOptions::retain] for the selection of those items.
+    pub(crate) fn with_options(mut self, options: &ContractOptions) -> Result<Self, anyhow::Error> {
+        self.visibility = syn::parse_str(&options.module_visibility).with_context(|| {
+            format!("invalid module visibility '{}'", options.module_visibility)
+        })?;
+        self.rpc_helpers = options.rpc_helpers;
+        for function in self.functions.iter_mut() {
+            function.rpc_helpers = options.rpc_helpers;
+        }
+        for event in self.events.iter_mut() {
+            event.borrowed = options.borrowed_events;
+        }
+
+        let mut derives = Vec::new();
+        if self.options.serde {
+            derives.push(quote! { serde::Serialize });
+            derives.push(quote! { serde::Deserialize });
+        }
+        let enum_attributes = match derives.is_empty() {
+            true => quote! {},
+            false => quote! { #[derive(#(#derives),*)] },
+        };
+
+        for derive in options.derives.iter() {
+            let path: syn::Path =
+                syn::parse_str(derive).with_context(|| format!("invalid derive '{}'", derive))?;
+            derives.push(quote! { #path });
+        }
+        let mut attributes = match derives.is_empty() {
+            true => quote! {},
+            false => quote! { #[derive(#(#derives),*)] },
+        };
+        for attribute in options.attributes.iter() {
+            let parsed = syn::Attribute::parse_outer
+                .parse_str(attribute)
+                .with_context(|| format!("invalid attribute '{}'", attribute))?;
+            attributes.extend(parsed.iter().map(|attribute| quote! { #attribute }));
+        }
+
+        if let Some(constructor) = self.constructor.as_mut() {
+            constructor.attributes = attributes.clone();
+        }
+        for function in self.functions.iter_mut() {
+            function.attributes = attributes.clone();
+        }
+        for event in self.events.iter_mut() {
+            event.attributes = attributes.clone();
+        }
+        for error in self.errors.iter_mut() {
+            error.attributes = attributes.clone();
+        }
+        for s in self.structs.iter_mut() {
+            s.attributes = attributes.clone();
+        }
+        for e in self.enums.iter_mut() {
+            e.attributes = enum_attributes.clone();
+        }
+
+        if let Some(package) = &options.proto_package {
+            let valid = package.split('.').all(|part| {
+                part.starts_with(|c: char| c.is_ascii_alphabetic())
+                    && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            });
+            if !valid {
+                return Err(format_err!("invalid proto package '{}'", package));
+            }
+            self.proto_package = Some(package.clone());
+        }
+
+        if options.prost || self.proto_package.is_some() {
+            self.messages = Some(Messages {
+                events: self
+                    .events
+                    .iter()
+                    .map(|event| Message::new(&event.name, &event.params))
+                    .collect::<Result<_, _>>()
+                    .context("cannot generate prost message, exclude the event to skip it")?,
+                functions: self
+                    .functions
+                    .iter()
+                    .map(|function| Message::new(&function.name, &function.params))
+                    .collect::<Result<_, _>>()
+                    .context("cannot generate prost message, exclude the function to skip it")?,
+                structs: self
+                    .structs
+                    .iter()
+                    .map(|s| Message::new(&s.name, &s.params))
+                    .collect::<Result<_, _>>()
+                    .context("cannot generate prost message")?,
+            });
+        }
+
+        Ok(self)
+    }
+
+    /// The events, structs and enums of the contract, candidates to the shared module.
+    pub(crate) fn shareable_items(&self) -> Vec<SharedItem> {
+        let dependencies = |params: &[Param]| {
+            let mut dependencies: Vec<_> = params.iter().flat_map(Param::dependencies).collect();
+            dependencies.sort();
+            dependencies.dedup();
+            dependencies
+        };
+
+        let events = self.events.iter().map(|event| SharedItem {
+            module: "events",
+            name: event.name.to_upper_camel_case(),
+            code: event.generate_event().to_string(),
+            dependencies: dependencies(&event.params),
+        });
+        let structs = self.structs.iter().map(|s| SharedItem {
+            module: "structs",
+            name: s.name.clone(),
+            code: s.generate().to_string(),
+            dependencies: dependencies(&s.params),
+        });
+        let enums = self.enums.iter().map(|e| SharedItem {
+            module: "enums",
+            name: e.name.clone(),
+            code: e.generate().to_string(),
+            dependencies: Vec::new(),
+        });
+
+        events.chain(structs).chain(enums).collect()
+    }
+
+    /// Uses the `shared` events, structs and enums, as `(module, name)`, from the shared
+    /// module instead of generating them.
+    pub(crate) fn with_shared(mut self, shared: BTreeSet<(&'static str, String)>) -> Self {
+        self.shared = shared;
+        self
+    }
+
+    /// Generates the code of the shared item `name` of `module` for the shared module.
+    pub(crate) fn generate_shared_item(&self, module: &str, name: &str) -> Option<TokenStream> {
+        match module {
+            "events" => self
+                .events
+                .iter()
+                .find(|event| event.name.to_upper_camel_case() == name)
+                .map(Event::generate_event),
+            "structs" => self
+                .structs
+                .iter()
+                .find(|s| s.name == name)
+                .map(Struct::generate),
+            "enums" => self
+                .enums
+                .iter()
+                .find(|e| e.name == name)
+                .map(Enum::generate),
+            _ => None,
+        }
+    }
+
+    /// Generates the item `name` of `module`, or its import from the shared module.
+    fn generate_item<F: FnOnce() -> TokenStream>(
+        &self,
+        module: &'static str,
+        name: &str,
+        generate: F,
+    ) -> TokenStream {
+        if !self.shared.contains(&(module, name.to_string())) {
+            return generate();
+        }
+
+        let module = syn::Ident::new(module, Span::call_site());
+        let name = syn::Ident::new(name, Span::call_site());
+        quote! {
+            pub use super::super::shared::#module::#name;
+        }
+    }
+
+    /// Generates the `.proto` schema of the `prost::Message` versions of the events, functions
+    /// and structs, they are nested in the `Events`, `Functions` and `Structs` messages like
+    /// the Rust ones live in the `pb` module of their module. `None` unless a proto package is
+    /// set.
+    pub fn generate_proto(&self) -> Option<String> {
+        let package = self.proto_package.as_ref()?;
+        let messages = self.messages.as_ref()?;
+
+        let mut proto = String::new();
+        match &self.name {
+            Some(name) => proto.push_str(&format!(
+                "// Generated by substreams-ethereum-abigen from the ABI of contract `{}`.\n",
+                name
+            )),
+            None => proto.push_str("// Generated by substreams-ethereum-abigen.\n"),
+        }
+        proto.push_str("syntax = \"proto3\";\n\n");
+        proto.push_str(&format!("package {};\n", package));
+
+        for (module, messages) in [
+            ("Events", &messages.events),
+            ("Functions", &messages.functions),
+            ("Structs", &messages.structs),
+        ] {
+            if messages.is_empty() {
+                continue;
+            }
+
+            proto.push_str(&format!("\nmessage {} {{\n", module));
+            for (i, message) in messages.iter().enumerate() {
+                if i > 0 {
+                    proto.push('\n');
+                }
+                proto.push_str(&message.generate_proto("  "));
+            }
+            proto.push_str("}\n");
+        }
+
+        Some(proto)
+    }
+
+    /// Generates the handle on a deployed contract along with the `use` re-exporting it at the
+    /// root, the handle lives in the `functions` module next to the functions it calls.
+    fn generate_handle(&self) -> (TokenStream, TokenStream) {
+        let name = match &self.name {
+            Some(name) if self.rpc_helpers => name,
+            _ => return (quote! {}, quote! {}),
+        };
+
+        let camel_name = syn::Ident::new(&name.to_upper_camel_case(), Span::call_site());
+        let doc = format!(
+            " Handle on a deployed `{}` contract, its view functions are called through RPC.",
+            name
+        );
+        let methods = self
+            .functions
+            .iter()
+            .filter_map(Function::generate_handle_method);
+        let visibility = &self.visibility;
+        let address_type = match self.options.typed_scalars {
+            true => quote! { substreams_ethereum::scalar::Address },
+            false => quote! { Vec<u8> },
+        };
+
+        (
+            quote! {
+                #[doc = #doc]
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct #camel_name {
+                    pub address: #address_type,
+                }
+
+                impl #camel_name {
+                    pub const NAME: &'static str = #name;
+
+                    #(#methods)*
+                }
+            },
+            quote! {
+                #[allow(unused_imports)]
+                #visibility use self::functions::#camel_name;
+            },
+        )
     }
-}
 
-impl Contract {
     /// Generates rust interface for a contract.
     pub fn generate(&self) -> TokenStream {
-        // let constructor = self.constructor.as_ref().map(Constructor::generate);
+        let constructor = self.constructor.as_ref().map(Constructor::generate);
         let functions: Vec<_> = self.functions.iter().map(Function::generate).collect();
+        let functions_enum = generate_functions_enum(&self.functions);
+        let (handle, handle_use) = self.generate_handle();
         let events: Vec<_> = self
             .events
             .iter()
-            .map(|event| event.generate_event())
+            .map(|event| {
+                let name = event.name.to_upper_camel_case();
+                let mut item = self.generate_item("events", &name, || event.generate_event());
+
+                // The borrowed view is generated along with the shared event
+                if event.borrowed && self.shared.contains(&("events", name.clone())) {
+                    let borrowed = syn::Ident::new(&format!("{}Ref", name), Span::call_site());
+                    item.extend(quote! {
+                        pub use super::super::shared::events::#borrowed;
+                    });
+                }
+
+                item
+            })
+            .collect();
+        let events_enum = generate_events_enum(&self.events);
+        let errors: Vec<_> = self.errors.iter().map(Error::generate).collect();
+        let enums: Vec<_> = self
+            .enums
+            .iter()
+            .map(|e| self.generate_item("enums", &e.name, || e.generate()))
+            .collect();
+        let structs: Vec<_> = self
+            .structs
+            .iter()
+            .map(|s| self.generate_item("structs", &s.name, || s.generate()))
             .collect();
+        let visibility = &self.visibility;
+        let (events_pb, functions_pb, structs_pb) = match &self.messages {
+            Some(messages) => (
+                generate_pb_module(&messages.events),
+                generate_pb_module(&messages.functions),
+                generate_pb_module(&messages.structs),
+            ),
+            None => (quote! {}, quote! {}, quote! {}),
+        };
         // let logs: Vec<_> = self.events.iter().map(Event::generate_log).collect();
         quote! {
-            const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
-
-            // #constructor
+            #handle_use
 
             /// Contract's functions.
             #[allow(dead_code, unused_imports, unused_variables)]
-            pub mod functions {
-                use super::INTERNAL_ERR;
+            #visibility mod functions {
+                #constructor
                 #(#functions)*
+                #functions_enum
+                #handle
+                #functions_pb
             }
 
             /// Contract's events.
             #[allow(dead_code, unused_imports, unused_variables)]
-            pub mod events {
-                use super::INTERNAL_ERR;
+            #visibility mod events {
                 #(#events)*
+                #events_enum
+                #events_pb
+            }
+
+            /// Contract's custom errors.
+            #[allow(dead_code, unused_imports, unused_variables)]
+            #visibility mod errors {
+                #(#errors)*
+            }
+
+            /// Contract's enums.
+            #[allow(dead_code, unused_imports, unused_variables)]
+            #visibility mod enums {
+                #(#enums)*
+            }
+
+            /// Contract's structs.
+            #[allow(dead_code, unused_imports, unused_variables)]
+            #visibility mod structs {
+                #(#structs)*
+                #structs_pb
             }
         }
     }
 }
 
+/// Generates the module holding the events, structs and enums shared by the contracts of a
+/// [crate::build::AbigenSet], the contracts import them from it.
+pub(crate) fn generate_shared_module(
+    events: Vec<TokenStream>,
+    structs: Vec<TokenStream>,
+    enums: Vec<TokenStream>,
+) -> TokenStream {
+    quote! {
+        /// Events shared by the contracts.
+        #[allow(dead_code, unused_imports, unused_variables)]
+        pub mod events {
+            #(#events)*
+        }
+
+        /// Enums shared by the contracts.
+        #[allow(dead_code, unused_imports, unused_variables)]
+        pub mod enums {
+            #(#enums)*
+        }
+
+        /// Structs shared by the contracts.
+        #[allow(dead_code, unused_imports, unused_variables)]
+        pub mod structs {
+            #(#structs)*
+        }
+    }
+}
+
+/// Generates the `pb` module holding the `prost::Message` versions of the types of a module.
+fn generate_pb_module(messages: &[Message]) -> TokenStream {
+    let messages = messages.iter().map(Message::generate);
+
+    quote! {
+        /// The `prost::Message` versions of the types of this module.
+        pub mod pb {
+            #(#messages)*
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use quote::quote;
 
-    use crate::assertions::assert_ast_eq;
+    use crate::{assertions::assert_ast_eq, meta::AbiMeta};
+
+    use super::{Contract, ContractOptions, EntryNames};
+
+    const ABI: &[u8] = br#"[
+        { "type": "event", "name": "Transfer", "inputs": [], "anonymous": false },
+        { "type": "event", "name": "Approval", "inputs": [], "anonymous": false },
+        { "type": "function", "name": "mint", "inputs": [], "outputs": [], "stateMutability": "nonpayable" },
+        { "type": "function", "name": "burn", "inputs": [], "outputs": [], "stateMutability": "nonpayable" },
+        { "type": "error", "name": "Unauthorized", "inputs": [] }
+    ]"#;
 
-    use super::Contract;
+    fn generator(options: &ContractOptions) -> Contract {
+        let mut c = ethabi::Contract::load(ABI).unwrap();
+        let mut meta = AbiMeta::parse(ABI).unwrap();
+        options.retain(&mut c, &mut meta);
+
+        Contract::new(&c, meta, &options.names)
+            .unwrap()
+            .with_options(options)
+            .unwrap()
+    }
+
+    fn names<'a>(names: impl Iterator<Item = &'a String>) -> Vec<&'a str> {
+        names.map(|name| name.as_str()).collect()
+    }
 
     #[test]
     fn test_no_body() {
@@ -124,20 +832,334 @@ mod test {
         assert_ast_eq(
             c.generate(),
             quote! {
-                const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+                /// Contract's functions.
+                #[allow(dead_code, unused_imports, unused_variables)]
+                pub mod functions {
+                }
+
+                /// Contract's events.
+                #[allow(dead_code, unused_imports, unused_variables)]
+                pub mod events {
+                }
+
+                /// Contract's custom errors.
+                #[allow(dead_code, unused_imports, unused_variables)]
+                pub mod errors {
+                }
+
+                /// Contract's enums.
+                #[allow(dead_code, unused_imports, unused_variables)]
+                pub mod enums {
+                }
+
+                /// Contract's structs.
+                #[allow(dead_code, unused_imports, unused_variables)]
+                pub mod structs {
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_named_contract_handle() {
+        let ethabi_contract = ethabi::Contract {
+            constructor: None,
+            functions: Default::default(),
+            events: Default::default(),
+            errors: Default::default(),
+            receive: false,
+            fallback: false,
+        };
+
+        let c = Contract::from(&ethabi_contract).with_name(Some("ERC721".to_string()));
+
+        assert_ast_eq(
+            c.generate(),
+            quote! {
+                #[allow(unused_imports)]
+                pub use self::functions::Erc721;
 
                 /// Contract's functions.
                 #[allow(dead_code, unused_imports, unused_variables)]
                 pub mod functions {
-                    use super::INTERNAL_ERR;
+
+                    #[doc = " Handle on a deployed `ERC721` contract, its view functions are called through RPC."]
+                    #[derive(Debug, Clone, PartialEq)]
+                    pub struct Erc721 {
+                        pub address: Vec<u8>,
+                    }
+
+                    impl Erc721 {
+                        pub const NAME: &'static str = "ERC721";
+                    }
                 }
 
                 /// Contract's events.
                 #[allow(dead_code, unused_imports, unused_variables)]
                 pub mod events {
-                    use super::INTERNAL_ERR;
+                }
+
+                /// Contract's custom errors.
+                #[allow(dead_code, unused_imports, unused_variables)]
+                pub mod errors {
+                }
+
+                /// Contract's enums.
+                #[allow(dead_code, unused_imports, unused_variables)]
+                pub mod enums {
+                }
+
+                /// Contract's structs.
+                #[allow(dead_code, unused_imports, unused_variables)]
+                pub mod structs {
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_item_selection() {
+        let mut options = ContractOptions::default();
+        options.events.include(["Transfer"]);
+        options.functions.exclude(["burn"]);
+
+        let c = generator(&options);
+        assert_eq!(names(c.events.iter().map(|e| &e.name)), vec!["Transfer"]);
+        assert_eq!(names(c.functions.iter().map(|f| &f.name)), vec!["mint"]);
+        assert_eq!(
+            names(c.errors.iter().map(|e| &e.name)),
+            vec!["Unauthorized"]
+        );
+
+        options.events_only = true;
+
+        let c = generator(&options);
+        assert_eq!(names(c.events.iter().map(|e| &e.name)), vec!["Transfer"]);
+        assert!(c.functions.is_empty());
+        assert!(c.errors.is_empty());
+    }
+
+    #[test]
+    fn test_overload_names() {
+        let abi = br#"[
+            { "type": "function", "name": "safeTransferFrom", "inputs": [{ "name": "from", "type": "address" }, { "name": "to", "type": "address" }, { "name": "id", "type": "uint256" }], "outputs": [], "stateMutability": "nonpayable" },
+            { "type": "function", "name": "safeTransferFrom", "inputs": [{ "name": "from", "type": "address" }, { "name": "to", "type": "address" }, { "name": "id", "type": "uint256" }, { "name": "data", "type": "bytes" }], "outputs": [], "stateMutability": "nonpayable" },
+            { "type": "function", "name": "batch", "inputs": [{ "name": "ids", "type": "uint256[]" }], "outputs": [], "stateMutability": "nonpayable" },
+            { "type": "function", "name": "batch", "inputs": [{ "name": "orders", "type": "tuple[2]", "components": [{ "name": "id", "type": "uint256" }, { "name": "flag", "type": "bool" }] }], "outputs": [], "stateMutability": "nonpayable" }
+        ]"#;
+        let generate = |names: &EntryNames| {
+            let c = ethabi::Contract::load(&abi[..]).unwrap();
+            let meta = AbiMeta::parse(abi).unwrap();
+            Contract::new(&c, meta, names).map(|c| {
+                c.functions
+                    .iter()
+                    .map(|f| f.name.clone())
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        assert_eq!(
+            generate(&EntryNames::default()).unwrap(),
+            vec!["batch1", "batch2", "safeTransferFrom1", "safeTransferFrom2"]
+        );
+
+        let mut names = EntryNames {
+            signature_overloads: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            generate(&names).unwrap(),
+            vec![
+                "batchTupleUint256BoolArray2",
+                "batchUint256Array",
+                "safeTransferFromAddressAddressUint256",
+                "safeTransferFromAddressAddressUint256Bytes"
+            ]
+        );
+
+        names.aliases.insert(
+            "safeTransferFrom(address,address,uint256,bytes)".to_string(),
+            "safeTransferFromWithData".to_string(),
+        );
+        assert_eq!(generate(&names).unwrap()[3], "safeTransferFromWithData");
+
+        names.aliases.insert(
+            "batch(uint256[])".to_string(),
+            "batchTupleUint256BoolArray2".to_string(),
+        );
+        assert_eq!(
+            generate(&names).unwrap_err().to_string(),
+            "two functions are generated as BatchTupleUint256BoolArray2, rename one with Abigen::alias"
+        );
+    }
+
+    #[test]
+    fn test_module_visibility_without_rpc_helpers() {
+        let ethabi_contract = ethabi::Contract {
+            constructor: None,
+            functions: Default::default(),
+            events: Default::default(),
+            errors: Default::default(),
+            receive: false,
+            fallback: false,
+        };
+
+        let options = ContractOptions {
+            rpc_helpers: false,
+            module_visibility: "pub(crate)".to_string(),
+            ..Default::default()
+        };
+
+        let c = Contract::from(&ethabi_contract)
+            .with_name(Some("ERC721".to_string()))
+            .with_options(&options)
+            .unwrap();
+
+        assert_ast_eq(
+            c.generate(),
+            quote! {
+                /// Contract's functions.
+                #[allow(dead_code, unused_imports, unused_variables)]
+                pub(crate) mod functions {
+                }
+
+                /// Contract's events.
+                #[allow(dead_code, unused_imports, unused_variables)]
+                pub(crate) mod events {
+                }
+
+                /// Contract's custom errors.
+                #[allow(dead_code, unused_imports, unused_variables)]
+                pub(crate) mod errors {
+                }
+
+                /// Contract's enums.
+                #[allow(dead_code, unused_imports, unused_variables)]
+                pub(crate) mod enums {
+                }
+
+                /// Contract's structs.
+                #[allow(dead_code, unused_imports, unused_variables)]
+                pub(crate) mod structs {
                 }
             },
         );
+
+        let options = ContractOptions {
+            module_visibility: "public".to_string(),
+            ..Default::default()
+        };
+        assert!(Contract::from(&ethabi_contract)
+            .with_options(&options)
+            .is_err());
+    }
+
+    #[test]
+    fn test_derives_and_attributes() {
+        let options = ContractOptions {
+            derives: vec!["Eq".to_string(), "serde::Serialize".to_string()],
+            attributes: vec!["#[serde(rename_all = \"camelCase\")]".to_string()],
+            ..Default::default()
+        };
+
+        let c = generator(&options);
+        let expected = quote! {
+            #[derive(Eq, serde::Serialize)]
+            #[serde(rename_all = "camelCase")]
+        };
+        assert!(c
+            .events
+            .iter()
+            .all(|e| e.attributes.to_string() == expected.to_string()));
+        assert!(c
+            .functions
+            .iter()
+            .all(|f| f.attributes.to_string() == expected.to_string()));
+        assert!(c
+            .errors
+            .iter()
+            .all(|e| e.attributes.to_string() == expected.to_string()));
+
+        let options = ContractOptions {
+            derives: vec!["not a path".to_string()],
+            ..Default::default()
+        };
+        let c = ethabi::Contract::load(ABI).unwrap();
+        let meta = AbiMeta::parse(ABI).unwrap();
+        assert!(Contract::new(&c, meta, &options.names)
+            .unwrap()
+            .with_options(&options)
+            .is_err());
+    }
+
+    #[test]
+    fn test_proto() {
+        let abi = br#"[
+            {
+                "type": "event",
+                "name": "Filled",
+                "inputs": [
+                    { "name": "maker", "type": "address", "indexed": true },
+                    {
+                        "name": "order",
+                        "type": "tuple",
+                        "internalType": "struct Order",
+                        "components": [
+                            { "name": "amount", "type": "uint256" },
+                            { "name": "tags", "type": "bytes32[]" }
+                        ],
+                        "indexed": false
+                    }
+                ],
+                "anonymous": false
+            },
+            { "type": "event", "name": "Paused", "inputs": [], "anonymous": false }
+        ]"#;
+        let options = ContractOptions {
+            proto_package: Some("market.v1".to_string()),
+            ..Default::default()
+        };
+        let c = ethabi::Contract::load(&abi[..]).unwrap();
+        let meta = AbiMeta::parse(abi).unwrap();
+
+        let c = Contract::new(&c, meta, &options.names)
+            .unwrap()
+            .with_name(Some("Market".to_string()))
+            .with_options(&options)
+            .unwrap();
+
+        assert_eq!(
+            c.generate_proto().unwrap(),
+            r#"// Generated by substreams-ethereum-abigen from the ABI of contract `Market`.
+syntax = "proto3";
+
+package market.v1;
+
+message Events {
+  message Filled {
+    bytes maker = 1;
+    Structs.Order order = 2;
+  }
+
+  message Paused {
+  }
+}
+
+message Structs {
+  message Order {
+    string amount = 1;
+    repeated bytes tags = 2;
+  }
+}
+"#
+        );
+
+        let options = ContractOptions {
+            proto_package: Some("market.1".to_string()),
+            ..Default::default()
+        };
+        assert!(Contract::from(&ethabi::Contract::load(&abi[..]).unwrap())
+            .with_options(&options)
+            .is_err());
     }
 }
@@ -0,0 +1,178 @@
+use anyhow::format_err;
+use heck::ToUpperCamelCase;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+use crate::meta::EnumMeta;
+
+/// Structure used to generate the Rust type of a Solidity enum, an ABI `uint8` whose
+/// `internalType` is `enum <Name>`.
+pub struct Enum {
+    /// Enum name.
+    pub(crate) name: String,
+
+    /// Variants in declaration order, empty if unknown.
+    variants: Vec<String>,
+
+    /// Attributes added to the generated enum, like derives.
+    pub(crate) attributes: TokenStream,
+}
+
+impl<'a> TryFrom<&'a EnumMeta> for Enum {
+    type Error = anyhow::Error;
+
+    fn try_from(e: &'a EnumMeta) -> Result<Self, Self::Error> {
+        // Variants are `uint8` values in the ABI.
+        if e.variants.len() > 256 {
+            return Err(format_err!(
+                "enum {} has {} variants, Solidity enums have at most 256",
+                e.name,
+                e.variants.len()
+            ));
+        }
+
+        Ok(Enum {
+            name: e.name.clone(),
+            variants: e.variants.clone(),
+            attributes: TokenStream::new(),
+        })
+    }
+}
+
+impl Enum {
+    /// Generates the Rust enum representing a Solidity enum, or an alias of `u8` recording the
+    /// enum name when its variants are unknown.
+    pub fn generate(&self) -> TokenStream {
+        let name_str = &self.name;
+        let name = syn::Ident::new(&self.name, Span::call_site());
+
+        if self.variants.is_empty() {
+            let doc = format!(
+                " Solidity enum `{}` whose variants are unknown, declare them with `Abigen::enum_variants` to generate a Rust enum.",
+                self.name
+            );
+
+            return quote! {
+                #[doc = #doc]
+                pub type #name = u8;
+            };
+        }
+
+        let variants: Vec<_> = self
+            .variants
+            .iter()
+            .map(|variant| syn::Ident::new(&variant.to_upper_camel_case(), Span::call_site()))
+            .collect();
+        let values: Vec<_> = (0..self.variants.len())
+            .map(|value| u8::try_from(value).expect("at most 256 variants"))
+            .collect();
+        let attributes = &self.attributes;
+
+        quote! {
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            #attributes
+            #[repr(u8)]
+            pub enum #name {
+                #(#variants = #values),*
+            }
+
+            impl std::convert::TryFrom<u8> for #name {
+                type Error = substreams_ethereum::DecodeError;
+
+                fn try_from(value: u8) -> Result<Self, Self::Error> {
+                    match value {
+                        #(#values => Ok(Self::#variants),)*
+                        _ => Err(substreams_ethereum::DecodeError::UnknownEnumVariant {
+                            name: #name_str.to_string(),
+                            value,
+                        }),
+                    }
+                }
+            }
+
+            impl From<#name> for u8 {
+                fn from(value: #name) -> u8 {
+                    value as u8
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assertions::assert_ast_eq, meta::EnumMeta};
+
+    use super::Enum;
+    use quote::quote;
+
+    #[test]
+    fn test_enum_with_variants() {
+        let e = Enum::try_from(&EnumMeta {
+            name: "ItemType".to_string(),
+            variants: vec!["NATIVE".to_string(), "ERC20".to_string()],
+        })
+        .unwrap();
+
+        assert_ast_eq(
+            e.generate(),
+            quote! {
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+                #[repr(u8)]
+                pub enum ItemType {
+                    Native = 0u8,
+                    Erc20 = 1u8
+                }
+                impl std::convert::TryFrom<u8> for ItemType {
+                    type Error = substreams_ethereum::DecodeError;
+                    fn try_from(value: u8) -> Result<Self, Self::Error> {
+                        match value {
+                            0u8 => Ok(Self::Native),
+                            1u8 => Ok(Self::Erc20),
+                            _ => Err(substreams_ethereum::DecodeError::UnknownEnumVariant {
+                                name: "ItemType".to_string(),
+                                value,
+                            }),
+                        }
+                    }
+                }
+                impl From<ItemType> for u8 {
+                    fn from(value: ItemType) -> u8 {
+                        value as u8
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_enum_without_variants() {
+        let e = Enum::try_from(&EnumMeta {
+            name: "ItemType".to_string(),
+            variants: vec![],
+        })
+        .unwrap();
+
+        assert_ast_eq(
+            e.generate(),
+            quote! {
+                #[doc = " Solidity enum `ItemType` whose variants are unknown, declare them with `Abigen::enum_variants` to generate a Rust enum."]
+                pub type ItemType = u8;
+            },
+        );
+    }
+
+    #[test]
+    fn test_enum_with_too_many_variants() {
+        let meta = |count| EnumMeta {
+            name: "ItemType".to_string(),
+            variants: (0..count).map(|i| format!("V{}", i)).collect(),
+        };
+
+        assert!(Enum::try_from(&meta(256)).is_ok());
+        assert_eq!(
+            Enum::try_from(&meta(257)).err().unwrap().to_string(),
+            "enum ItemType has 257 variants, Solidity enums have at most 256"
+        );
+    }
+}
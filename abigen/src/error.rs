@@ -0,0 +1,274 @@
+use heck::ToUpperCamelCase;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+use crate::{
+    decode_tuple, encode_tuple,
+    meta::{EntryMeta, EMPTY_ENTRY},
+    Position,
+};
+
+use super::{param_names, struct_field};
+
+/// Structure used to generate contract's custom error interface.
+pub struct Error {
+    /// Error name.
+    pub(crate) name: String,
+
+    selector: [u8; 4],
+    /// Expression of the ABI encoding of the parameters.
+    encode: TokenStream,
+    decoded_values: TokenStream,
+    decoded_fields: Vec<TokenStream>,
+    fields: Vec<TokenStream>,
+    /// Attributes added to the generated type, like derives.
+    pub(crate) attributes: TokenStream,
+}
+
+impl<'a> From<(&'a String, &'a ethabi::AbiError)> for Error {
+    fn from((name, e): (&'a String, &'a ethabi::AbiError)) -> Self {
+        (name, e, &EMPTY_ENTRY).into()
+    }
+}
+
+impl<'a> From<(&'a String, &'a ethabi::AbiError, &'a EntryMeta)> for Error {
+    fn from((name, e, meta): (&'a String, &'a ethabi::AbiError, &'a EntryMeta)) -> Self {
+        let input_names = param_names(&e.inputs);
+
+        let fields = input_names
+            .iter()
+            .zip(e.inputs.iter())
+            .enumerate()
+            .map(|(i, (param_name, param))| struct_field(param_name, &param.kind, meta.input(i)))
+            .collect();
+
+        let decoded_values = if !e.inputs.is_empty() {
+            quote! {
+                let maybe_data = data.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: data.len(),
+                    });
+                }
+
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(maybe_data.unwrap(), 4);
+            }
+        } else {
+            quote! {}
+        };
+
+        let decoded_fields = decode_tuple(
+            e.inputs
+                .iter()
+                .enumerate()
+                .map(|(i, param)| (&param.kind, meta.input(i))),
+            &quote! { decoder },
+            &Position::at(0),
+        )
+        .into_iter()
+        .zip(input_names.iter())
+        .map(|(decode_input, name)| {
+            quote! {
+               #name: #decode_input
+            }
+        })
+        .collect();
+
+        let encode = encode_tuple(
+            &input_names
+                .iter()
+                .zip(e.inputs.iter())
+                .enumerate()
+                .map(|(i, (param_name, param))| {
+                    (quote! { self.#param_name }, &param.kind, meta.input(i))
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        let mut selector = [0u8; 4];
+        selector.copy_from_slice(&e.signature()[0..4]);
+
+        Error {
+            name: name.clone(),
+            selector,
+            encode,
+            decoded_values,
+            decoded_fields,
+            fields,
+            attributes: TokenStream::new(),
+        }
+    }
+}
+
+impl Error {
+    /// Generates the interface for contract's custom error.
+    pub fn generate(&self) -> TokenStream {
+        let name = &self.name;
+        let camel_name = syn::Ident::new(&self.name.to_upper_camel_case(), Span::call_site());
+
+        let selector_bytes: Vec<_> = self
+            .selector
+            .iter()
+            .map(|value| quote! { #value })
+            .collect();
+
+        let fields = &self.fields;
+        let encode = &self.encode;
+        let decoded_values = &self.decoded_values;
+        let decoded_fields = &self.decoded_fields;
+        let attributes = &self.attributes;
+
+        quote! {
+            #[derive(Debug, Clone, PartialEq)]
+            #attributes
+            pub struct #camel_name {
+                #(#fields),*
+            }
+
+            impl #camel_name {
+                pub const SELECTOR: [u8; 4] = [#(#selector_bytes),*];
+
+                pub fn match_revert(data: &[u8]) -> bool {
+                    match data.get(0..4) {
+                        Some(selector) => Self::SELECTOR == selector,
+                        None => false
+                    }
+                }
+
+                pub fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+                    #decoded_values
+
+                    Ok(Self {
+                        #(#decoded_fields),*
+                    })
+                }
+
+                pub fn encode(&self) -> Vec<u8> {
+                    let data = #encode;
+
+                    let mut encoded = Vec::with_capacity(4 + data.len());
+                    encoded.extend(Self::SELECTOR);
+                    encoded.extend(data);
+
+                    encoded
+                }
+
+                pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                    call.status_reverted && Self::match_revert(call.return_data.as_ref())
+                }
+
+                pub fn decode_call(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<Self, substreams_ethereum::DecodeError> {
+                    Self::decode(call.return_data.as_ref())
+                }
+            }
+
+            impl substreams_ethereum::Error for #camel_name {
+                const NAME: &'static str = #name;
+                fn match_revert(data: &[u8]) -> bool {
+                    Self::match_revert(data)
+                }
+                fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+                    Self::decode(data)
+                }
+                fn encode(&self) -> Vec<u8> {
+                    self.encode()
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::assert_ast_eq;
+
+    use super::Error;
+    use quote::quote;
+
+    #[test]
+    fn test_error_with_inputs() {
+        let ethabi_error = ethabi::AbiError {
+            name: "InsufficientBalance".into(),
+            inputs: vec![
+                ethabi::Param {
+                    name: "available".into(),
+                    kind: ethabi::ParamType::Uint(256),
+                    internal_type: None,
+                },
+                ethabi::Param {
+                    name: "required".into(),
+                    kind: ethabi::ParamType::Uint(256),
+                    internal_type: None,
+                },
+            ],
+        };
+
+        let e = Error::from((&ethabi_error.name, &ethabi_error));
+
+        assert_ast_eq(
+            e.generate(),
+            quote! {
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct InsufficientBalance {
+                    pub available: substreams::scalar::BigInt,
+                    pub required: substreams::scalar::BigInt
+                }
+                impl InsufficientBalance {
+                    pub const SELECTOR: [u8; 4] = [207u8, 71u8, 145u8, 129u8];
+                    pub fn match_revert(data: &[u8]) -> bool {
+                        match data.get(0..4) {
+                            Some(selector) => Self::SELECTOR == selector,
+                            None => false
+                        }
+                    }
+                    pub fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+                        let maybe_data = data.get(4..);
+                        if maybe_data.is_none() {
+                            return Err(substreams_ethereum::DecodeError::DataTooShort {
+                                expected: 4,
+                                actual: data.len(),
+                            });
+                        }
+                        let decoder =
+                            substreams_ethereum::abi::Decoder::with_offset(maybe_data.unwrap(), 4);
+                        Ok(Self {
+                            available: decoder.big_uint(0usize)?,
+                            required: decoder.big_uint(32usize)?
+                        })
+                    }
+                    pub fn encode(&self) -> Vec<u8> {
+                        let data = substreams_ethereum::abi::Encoder::encode(64usize, |encoder| {
+                            encoder.word(substreams_ethereum::abi::encode_big_uint(&self.available));
+                            encoder.word(substreams_ethereum::abi::encode_big_uint(&self.required));
+                        });
+                        let mut encoded = Vec::with_capacity(4 + data.len());
+                        encoded.extend(Self::SELECTOR);
+                        encoded.extend(data);
+                        encoded
+                    }
+                    pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                        call.status_reverted && Self::match_revert(call.return_data.as_ref())
+                    }
+                    pub fn decode_call(
+                        call: &substreams_ethereum::pb::eth::v2::Call
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        Self::decode(call.return_data.as_ref())
+                    }
+                }
+                impl substreams_ethereum::Error for InsufficientBalance {
+                    const NAME: &'static str = "InsufficientBalance";
+                    fn match_revert(data: &[u8]) -> bool {
+                        Self::match_revert(data)
+                    }
+                    fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+                        Self::decode(data)
+                    }
+                    fn encode(&self) -> Vec<u8> {
+                        self.encode()
+                    }
+                }
+            },
+        );
+    }
+}
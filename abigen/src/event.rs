@@ -1,15 +1,23 @@
+use ethabi::ParamType;
 use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 
-use crate::{decode_topic, fixed_data_size, min_data_size};
-
-use super::{from_token, rust_type, to_syntax_string};
+use crate::{
+    decode_borrowed, decode_result, decode_topic, decode_tuple, dispatch_arms, encode_tuple,
+    fixed_data_size, head_size,
+    message::Param,
+    meta::{EntryMeta, EMPTY_ENTRY, EMPTY_META},
+    min_data_size, rust_type, signature_constants, struct_field, Position,
+};
 
 /// Structure used to generate contract's event interface.
 pub struct Event {
     pub(crate) name: String,
+    anonymous: bool,
     topic_hash: [u8; 32],
+    /// The `SIGNATURE`, `PARAM_NAMES` and `PARAM_TYPES` constants.
+    signature: TokenStream,
     topic_count: usize,
     min_data_size: usize,
     fixed_data_size: Option<usize>,
@@ -17,10 +25,32 @@ pub struct Event {
     decode_indexed_fields: Vec<TokenStream>,
     decode_unindexed_fields: Vec<TokenStream>,
     decode_data: TokenStream,
+    encode_topics: Vec<TokenStream>,
+    encode_data: TokenStream,
+    /// The `decode_<param>` functions decoding a single parameter of a log.
+    decode_params: Vec<TokenStream>,
+    /// The field accessors of the borrowed view of the event.
+    borrowed_accessors: Vec<TokenStream>,
+    /// The fields of the event built from the accessors of its borrowed view.
+    borrowed_to_owned: Vec<TokenStream>,
+    /// Generates the borrowed view of the event, e.g. `TransferRef<'a>`, decoding its fields
+    /// on access.
+    pub(crate) borrowed: bool,
+    /// Parameters of the event, used to generate its `prost::Message` version and to find
+    /// the structs and enums it uses.
+    pub(crate) params: Vec<Param>,
+    /// Attributes added to the generated type, like derives.
+    pub(crate) attributes: TokenStream,
 }
 
 impl<'a> From<(&'a String, &'a ethabi::Event)> for Event {
     fn from((name, e): (&'a String, &'a ethabi::Event)) -> Self {
+        (name, e, &EMPTY_ENTRY).into()
+    }
+}
+
+impl<'a> From<(&'a String, &'a ethabi::Event, &'a EntryMeta)> for Event {
+    fn from((name, e, meta): (&'a String, &'a ethabi::Event, &'a EntryMeta)) -> Self {
         let names: Vec<_> = e
             .inputs
             .iter()
@@ -38,7 +68,10 @@ impl<'a> From<(&'a String, &'a ethabi::Event)> for Event {
             })
             .collect();
 
-        let topic_count = e.inputs.iter().filter(|param| param.indexed).count() + 1;
+        // Anonymous events do not have the event signature as first topic, their indexed
+        // parameters start at topic 0.
+        let first_topic = if e.anonymous { 0 } else { 1 };
+        let topic_count = e.inputs.iter().filter(|param| param.indexed).count() + first_topic;
 
         let fixed_data_size = e.inputs.iter().filter(|param| !param.indexed).fold(
             Some(0usize),
@@ -58,28 +91,54 @@ impl<'a> From<(&'a String, &'a ethabi::Event)> for Event {
             .map(|param| min_data_size(&param.kind))
             .sum();
 
-        let kinds: Vec<_> = e
-            .inputs
+        let log_fields = names
             .iter()
-            .map(|param| rust_type(&param.kind))
+            .zip(e.inputs.iter())
+            .enumerate()
+            .map(|(i, (param_name, param))| match hashed_topic(param) {
+                true => {
+                    let serde = match meta.input(i).options.serde {
+                        true => quote! { #[serde(with = "substreams_ethereum::abi::serde")] },
+                        false => quote! {},
+                    };
+                    quote! { #serde pub #param_name: substreams_ethereum::scalar::TopicHash }
+                }
+                false => struct_field(param_name, &param.kind, meta.input(i)),
+            })
             .collect();
 
-        let log_fields = names
+        let params = names
             .iter()
-            .zip(kinds.iter())
-            .map(|(param_name, kind)| quote! { pub #param_name: #kind })
+            .zip(e.inputs.iter())
+            .enumerate()
+            .map(|(i, (name, param))| match hashed_topic(param) {
+                true => Param::new(name, &ParamType::FixedBytes(32), &EMPTY_META),
+                false => Param::new(name, &param.kind, meta.input(i)),
+            })
             .collect();
 
         let decode_indexed_fields = e
             .inputs
             .iter()
             .zip(names.iter())
-            .filter(|(param, _)| param.indexed)
             .enumerate()
-            .map(|(index, (param, name))| {
-                let topic_index = index + 1;
+            .filter(|(_, (param, _))| param.indexed)
+            .enumerate()
+            .map(|(index, (i, (param, name)))| {
+                let topic_index = index + first_topic;
                 let topic_access = quote! { log.topics[#topic_index].as_ref() };
-                let decode_topic = decode_topic(&name.to_string(), &param.kind, &topic_access);
+
+                if hashed_topic(param) {
+                    let name_str = name.to_string();
+                    let kind_name = param.kind.to_string();
+                    return quote! {
+                        #name: <substreams_ethereum::scalar::TopicHash as std::convert::TryFrom<&[u8]>>::try_from(#topic_access)
+                            .map_err(|e| substreams_ethereum::DecodeError::topic(#name_str, #kind_name, #topic_index, e))?
+                    };
+                }
+
+                let decode_topic =
+                    decode_topic(&name.to_string(), &param.kind, meta.input(i), topic_index);
 
                 quote! {
                     #name: #decode_topic
@@ -88,42 +147,171 @@ impl<'a> From<(&'a String, &'a ethabi::Event)> for Event {
             .collect();
 
         let decode_data = if e.inputs.iter().any(|input| !input.indexed) {
-            let params: Vec<_> = e
-                .inputs
-                .iter()
-                .filter(|input| !input.indexed)
-                .map(|input| to_syntax_string(&input.kind))
-                .collect();
-
             quote! {
-                let mut values = ethabi::decode(&[#(#params),*], log.data.as_ref())
-                        .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
-                values.reverse();
+                let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
             }
         } else {
             TokenStream::new()
         };
 
-        // We go reverse in the iteration because we use a series of `.pop()` to correctly
-        // extract elements.
-        let decode_unindexed_fields = e
+        // Unindexed parameters are the values of the tuple encoded in the data, their heads
+        // follow each other.
+        let unindexed: Vec<_> = e
             .inputs
             .iter()
             .zip(names.iter())
-            .filter(|(param, _)| !param.indexed)
-            .map(|(param, name)| {
-                let data_access = quote! { values.pop().expect(INTERNAL_ERR) };
-                let decode_topic = from_token(&param.kind, &data_access);
+            .enumerate()
+            .filter(|(_, (param, _))| !param.indexed)
+            .collect();
+
+        let decode_unindexed_fields = decode_tuple(
+            unindexed
+                .iter()
+                .map(|(i, (param, _))| (&param.kind, meta.input(*i))),
+            &quote! { decoder },
+            &Position::at(0),
+        )
+        .into_iter()
+        .zip(unindexed.iter())
+        .map(|(decode, (_, (_, name)))| {
+            quote! {
+               #name: #decode
+            }
+        })
+        .collect();
+
+        let encode_topics = e
+            .inputs
+            .iter()
+            .zip(names.iter())
+            .enumerate()
+            .filter(|(_, (param, _))| param.indexed)
+            .map(|(i, (param, name))| {
+                if hashed_topic(param) {
+                    return quote! { self.#name.to_vec() };
+                }
+
+                encode_tuple(&[(quote! { self.#name }, &param.kind, meta.input(i))])
+            })
+            .collect();
+
+        let encode_data = encode_tuple(
+            &unindexed
+                .iter()
+                .map(|(i, (param, name))| (quote! { self.#name }, &param.kind, meta.input(*i)))
+                .collect::<Vec<_>>(),
+        );
+
+        // Indexed parameters each have a topic while the heads of the unindexed ones follow
+        // each other in the data, at offsets known from the size of the types before them.
+        let mut topic_index = first_topic;
+        let mut data_offset = 0;
+        let slots: Vec<_> = e
+            .inputs
+            .iter()
+            .map(|param| match param.indexed {
+                true => {
+                    let slot = Slot::Topic(topic_index);
+                    topic_index += 1;
+                    slot
+                }
+                false => {
+                    let slot = Slot::Data(data_offset);
+                    data_offset += head_size(&param.kind);
+                    slot
+                }
+            })
+            .collect();
+
+        // Logs of other events can have the same amount of topics, the signature topic tells
+        // them apart unless the event is anonymous.
+        let check_topic_id = match e.anonymous {
+            true => quote! {},
+            false => quote! {
+                if log.topics[0].as_slice() != Self::TOPIC_ID {
+                    return Err(substreams_ethereum::DecodeError::topic(
+                        Self::SIGNATURE,
+                        "bytes32",
+                        0,
+                        "not the topic id of the event",
+                    ));
+                }
+            },
+        };
+        let decode_params = names
+            .iter()
+            .zip(e.inputs.iter())
+            .zip(slots.iter())
+            .enumerate()
+            .map(|(i, ((name, param), slot))| {
+                let function = syn::Ident::new(&format!("decode_{}", name), Span::call_site());
+                let kind = match hashed_topic(param) {
+                    true => quote! { substreams_ethereum::scalar::TopicHash },
+                    false => rust_type(&param.kind, meta.input(i)),
+                };
+                let decode = decode_slot(&quote! { log }, name, param, *slot, |decoder, at| {
+                    decode_result(&param.kind, meta.input(i), decoder, at)
+                });
+                let doc = format!(
+                    " Decodes only the `{}` parameter of `log`, without decoding the whole event.",
+                    name
+                );
 
                 quote! {
-                   #name: #decode_topic
+                    #[doc = #doc]
+                    pub fn #function(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<#kind, substreams_ethereum::DecodeError> {
+                        if log.topics.len() != #topic_count {
+                            return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                                expected: #topic_count,
+                                actual: log.topics.len(),
+                            });
+                        }
+
+                        #check_topic_id
+
+                        #decode
+                    }
                 }
             })
             .collect();
 
+        let (borrowed_accessors, borrowed_to_owned) = names
+            .iter()
+            .zip(e.inputs.iter())
+            .zip(slots.iter())
+            .enumerate()
+            .map(|(i, ((name, param), slot))| {
+                // Hashed topics are decoded as such, without decoder
+                let mut kind = quote! { substreams_ethereum::scalar::TopicHash };
+                let mut to_owned = TokenStream::new();
+                let decode =
+                    decode_slot(&quote! { self.log }, name, param, *slot, |decoder, at| {
+                        let value = decode_borrowed(&param.kind, meta.input(i), decoder, at);
+                        kind = value.kind;
+                        to_owned = value.to_owned;
+                        value.decode
+                    });
+
+                let accessor = borrowed_accessor(name);
+                (
+                    quote! {
+                        pub fn #accessor(&self) -> Result<#kind, substreams_ethereum::DecodeError> {
+                            #decode
+                        }
+                    },
+                    quote! { #name: self.#accessor()?#to_owned },
+                )
+            })
+            .unzip();
+
         Event {
             name: name.clone(),
+            anonymous: e.anonymous,
             topic_hash: e.signature().to_fixed_bytes(),
+            signature: signature_constants(
+                &e.name,
+                e.inputs.iter().map(|param| (&param.name, &param.kind)),
+            ),
             topic_count,
             fixed_data_size,
             min_data_size,
@@ -131,20 +319,93 @@ impl<'a> From<(&'a String, &'a ethabi::Event)> for Event {
             decode_indexed_fields,
             decode_unindexed_fields,
             decode_data,
+            encode_topics,
+            encode_data,
+            decode_params,
+            borrowed_accessors,
+            borrowed_to_owned,
+            borrowed: false,
+            params,
+            attributes: TokenStream::new(),
         }
     }
 }
 
+/// Whether `param` is an indexed parameter of type `string`, `bytes`, array or tuple, its topic
+/// holds the Keccak-256 hash of its value which cannot be decoded back.
+fn hashed_topic(param: &ethabi::EventParam) -> bool {
+    match param.kind {
+        ParamType::Bytes
+        | ParamType::String
+        | ParamType::Array(_)
+        | ParamType::FixedArray(_, _)
+        | ParamType::Tuple(_) => param.indexed,
+        _ => false,
+    }
+}
+
+/// Methods of the borrowed view of an event, see [Event::generate_borrowed].
+const BORROWED_METHODS: &[&str] = &["decode", "match_and_decode", "log", "to_owned"];
+
+/// The name of the accessor of the parameter `name` in the borrowed view of an event, suffixed
+/// with `_` when it is the name of one of the [BORROWED_METHODS].
+fn borrowed_accessor(name: &syn::Ident) -> syn::Ident {
+    match BORROWED_METHODS.contains(&name.to_string().as_str()) {
+        true => syn::Ident::new(&format!("{}_", name), Span::call_site()),
+        false => name.clone(),
+    }
+}
+
+/// Where the value of an event parameter is found in a log.
+#[derive(Clone, Copy)]
+enum Slot {
+    /// The topic at this index.
+    Topic(usize),
+    /// The data, the head of the value is at this byte offset.
+    Data(usize),
+}
+
+/// Generates the statements of a function returning the `Result` of the decoding of the
+/// parameter `name` from its `slot` in `log`. `decode` generates the expression of the value
+/// read at a position of a `substreams_ethereum::abi::Decoder`, except for hashed topics.
+fn decode_slot<F>(
+    log: &TokenStream,
+    name: &syn::Ident,
+    param: &ethabi::EventParam,
+    slot: Slot,
+    decode: F,
+) -> TokenStream
+where
+    F: FnOnce(&TokenStream, &Position) -> TokenStream,
+{
+    let name = name.to_string();
+    let kind_name = param.kind.to_string();
+
+    match slot {
+        Slot::Topic(index) if hashed_topic(param) => quote! {
+            <substreams_ethereum::scalar::TopicHash as std::convert::TryFrom<&[u8]>>::try_from(#log.topics[#index].as_ref())
+                .map_err(|e| substreams_ethereum::DecodeError::topic(#name, #kind_name, #index, e))
+        },
+        Slot::Topic(index) => {
+            let decode = decode(&quote! { topic }, &Position::at(0));
+            quote! {
+                let topic = substreams_ethereum::abi::Decoder::topic(&#log.topics[#index], #name, #kind_name, #index);
+                #decode
+            }
+        }
+        Slot::Data(offset) => decode(
+            &quote! { substreams_ethereum::abi::Decoder::new(&#log.data) },
+            &Position::at(offset),
+        ),
+    }
+}
+
 impl Event {
     /// Generates rust interface for contract's event.
     pub fn generate_event(&self) -> TokenStream {
         let name = &self.name;
+        let anonymous = self.anonymous;
         let topic_count = &self.topic_count;
-        let topic_hash_bytes: Vec<_> = self
-            .topic_hash
-            .iter()
-            .map(|value| quote! { #value })
-            .collect();
         let camel_name = syn::Ident::new(&self.name.to_upper_camel_case(), Span::call_site());
         let log_fields = &self.log_fields;
 
@@ -173,14 +434,50 @@ impl Event {
             }
         };
 
+        let topic_hash_bytes: Vec<_> = self
+            .topic_hash
+            .iter()
+            .map(|value| quote! { #value })
+            .collect();
+        let signature = &self.signature;
+
+        // Anonymous events cannot be identified by their signature, only the amount of topics
+        // and the data size are checked.
+        let (log_match_topic, encode_topic_id) = if self.anonymous {
+            (quote! { return true; }, quote! {})
+        } else {
+            (
+                quote! {
+                    return log.topics.get(0).expect("bounds already checked").as_ref()
+                        == Self::TOPIC_ID;
+                },
+                quote! { Self::TOPIC_ID.to_vec(), },
+            )
+        };
+
+        let encode_topics = &self.encode_topics;
+        let encode_data = &self.encode_data;
+        let decode_params = &self.decode_params;
+        let attributes = &self.attributes;
+        let borrowed = match self.borrowed {
+            true => self.generate_borrowed(&camel_name),
+            false => TokenStream::new(),
+        };
+
         quote! {
             #[derive(Debug, Clone, PartialEq)]
+            #attributes
             pub struct #camel_name {
                 #(#log_fields),*
             }
 
             impl #camel_name {
-                const TOPIC_ID: [u8; 32] = [#(#topic_hash_bytes),*];
+                pub const TOPIC_ID: [u8; 32] = [#(#topic_hash_bytes),*];
+                #signature
+
+                pub fn is_anonymous() -> bool {
+                    #anonymous
+                }
 
                 pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                     if log.topics.len() != #topic_count {
@@ -189,27 +486,178 @@ impl Event {
 
                     #log_match_data
 
-                    return log.topics.get(0).expect("bounds already checked").as_ref()
-                        == Self::TOPIC_ID;
+                    #log_match_topic
                 }
 
-                pub fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+                pub fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, substreams_ethereum::DecodeError> {
+                    if log.topics.len() != #topic_count {
+                        return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                            expected: #topic_count,
+                            actual: log.topics.len(),
+                        });
+                    }
+
                     #decode_data
 
                     Ok(Self {
                         #(#decode_fields),*
                     })
                 }
+
+                #(#decode_params)*
+
+                pub fn encode_log(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                    substreams_ethereum::pb::eth::v2::Log {
+                        address,
+                        topics: vec![#encode_topic_id #(#encode_topics),*],
+                        data: #encode_data,
+                        ..Default::default()
+                    }
+                }
             }
 
             impl substreams_ethereum::Event for #camel_name {
                 const NAME: &'static str = #name;
+                const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+                const SIGNATURE: &'static str = Self::SIGNATURE;
+                const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+                const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+                fn is_anonymous() -> bool {
+                    Self::is_anonymous()
+                }
                 fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                     Self::match_log(log)
                 }
-                fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+                fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, substreams_ethereum::DecodeError> {
                     Self::decode(log)
                 }
+                fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                    self.encode_log(address)
+                }
+            }
+
+            #borrowed
+        }
+    }
+
+    /// Generates the borrowed view of the event, see [Event::borrowed].
+    fn generate_borrowed(&self, camel_name: &syn::Ident) -> TokenStream {
+        let ref_name = syn::Ident::new(&format!("{}Ref", camel_name), Span::call_site());
+        let topic_count = &self.topic_count;
+        let accessors = &self.borrowed_accessors;
+        let to_owned = &self.borrowed_to_owned;
+
+        let doc = format!(
+            " Borrowed view of a [{}] log, its fields are decoded on access and `address`, `bytes` and `string` values borrow the log instead of being copied.",
+            camel_name
+        );
+
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone, Copy)]
+            pub struct #ref_name<'a> {
+                log: &'a substreams_ethereum::pb::eth::v2::Log,
+            }
+
+            impl<'a> #ref_name<'a> {
+                /// Borrows `log` after checking its amount of topics, the fields are checked when accessed.
+                pub fn decode(log: &'a substreams_ethereum::pb::eth::v2::Log) -> Result<Self, substreams_ethereum::DecodeError> {
+                    if log.topics.len() != #topic_count {
+                        return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                            expected: #topic_count,
+                            actual: log.topics.len(),
+                        });
+                    }
+
+                    Ok(Self { log })
+                }
+
+                pub fn match_and_decode(log: &'a substreams_ethereum::pb::eth::v2::Log) -> Option<Self> {
+                    if #camel_name::match_log(log) {
+                        return Self::decode(log).ok();
+                    }
+
+                    None
+                }
+
+                pub fn log(&self) -> &'a substreams_ethereum::pb::eth::v2::Log {
+                    self.log
+                }
+
+                #(#accessors)*
+
+                /// Decodes all the fields into the owned event.
+                pub fn to_owned(self) -> Result<#camel_name, substreams_ethereum::DecodeError> {
+                    Ok(#camel_name {
+                        #(#to_owned),*
+                    })
+                }
+            }
+        }
+    }
+}
+
+/// Generates the `Events` enum of the contract, decoding a log into any of its events through
+/// a match on the first topic. Anonymous events are left out as they have no signature topic.
+pub fn generate_events_enum(events: &[Event]) -> TokenStream {
+    let events: Vec<_> = events.iter().filter(|event| !event.anonymous).collect();
+    if events.is_empty() {
+        return TokenStream::new();
+    }
+
+    let names: Vec<_> = events.iter().map(|event| &event.name).collect();
+    let camel_names: Vec<_> = events
+        .iter()
+        .map(|event| syn::Ident::new(&event.name.to_upper_camel_case(), Span::call_site()))
+        .collect();
+    let arms = dispatch_arms(
+        &syn::Ident::new("Events", Span::call_site()),
+        &syn::Ident::new("TOPIC_ID", Span::call_site()),
+        &quote! { log },
+        &events
+            .iter()
+            .zip(camel_names.iter())
+            .map(|(event, camel_name)| (&event.topic_hash[..], camel_name.clone()))
+            .collect::<Vec<_>>(),
+    );
+
+    quote! {
+        /// Any of the contract's events, see [Events::match_and_decode].
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Events {
+            #(#camel_names(#camel_names)),*
+        }
+
+        impl Events {
+            /// Decodes `log` into the event whose signature is the first topic of the log.
+            pub fn match_and_decode(log: impl AsRef<substreams_ethereum::pb::eth::v2::Log>) -> Option<Self> {
+                use substreams_ethereum::Event;
+
+                let log = log.as_ref();
+                let topic = <[u8; 32] as std::convert::TryFrom<&[u8]>>::try_from(
+                    log.topics.get(0)?.as_ref(),
+                )
+                .ok()?;
+
+                match topic {
+                    #(#arms)*
+                    _ => None,
+                }
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    #(Events::#camel_names(_) => #names),*
+                }
+            }
+        }
+
+        impl substreams_ethereum::ContractEvents for Events {
+            fn match_and_decode(log: impl AsRef<substreams_ethereum::pb::eth::v2::Log>) -> Option<Self> {
+                Self::match_and_decode(log)
+            }
+            fn name(&self) -> &'static str {
+                self.name()
             }
         }
     }
@@ -219,7 +667,7 @@ impl Event {
 mod tests {
     use crate::assertions::assert_ast_eq;
 
-    use super::Event;
+    use super::{generate_events_enum, Event};
     use quote::quote;
 
     #[test]
@@ -238,7 +686,7 @@ mod tests {
                 #[derive(Debug, Clone, PartialEq)]
                 pub struct Hello {}
                 impl Hello {
-                    const TOPIC_ID: [u8; 32] = [
+                    pub const TOPIC_ID: [u8; 32] = [
                         25u8,
                         255u8,
                         29u8,
@@ -272,6 +720,12 @@ mod tests {
                         16u8,
                         101u8
                     ];
+                    pub const SIGNATURE: &'static str = "hello()";
+                    pub const PARAM_NAMES: &'static [&'static str] = &[];
+                    pub const PARAM_TYPES: &'static [&'static str] = &[];
+                    pub fn is_anonymous() -> bool {
+                        false
+                    }
                     pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         if log.topics.len() != 1usize {
                             return false;
@@ -284,18 +738,44 @@ mod tests {
                     }
                     pub fn decode(
                         log: &substreams_ethereum::pb::eth::v2::Log
-                    ) -> Result<Self, String> {
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        if log.topics.len() != 1usize {
+                            return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                                expected: 1usize,
+                                actual: log.topics.len(),
+                            });
+                        }
                         Ok(Self {})
                     }
+                    pub fn encode_log(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            address,
+                            topics: vec![
+                                Self::TOPIC_ID.to_vec(),
+                            ],
+                            data: Vec::<u8>::new(),
+                            ..Default::default()
+                        }
+                    }
                 }
                 impl substreams_ethereum::Event for Hello {
                     const NAME: &'static str = "hello";
+                    const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+                    const SIGNATURE: &'static str = Self::SIGNATURE;
+                    const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+                    const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+                    fn is_anonymous() -> bool {
+                        Self::is_anonymous()
+                    }
                     fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         Self::match_log(log)
                     }
-                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, substreams_ethereum::DecodeError> {
                         Self::decode(log)
                     }
+                    fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                        self.encode_log(address)
+                    }
                 }
             },
         );
@@ -323,7 +803,7 @@ mod tests {
                     pub foo: Vec<u8>
                 }
                 impl One {
-                    const TOPIC_ID: [u8; 32] = [
+                    pub const TOPIC_ID: [u8; 32] = [
                         242u8,
                         136u8,
                         154u8,
@@ -357,6 +837,12 @@ mod tests {
                         22u8,
                         66u8
                     ];
+                    pub const SIGNATURE: &'static str = "one(address)";
+                    pub const PARAM_NAMES: &'static [&'static str] = &["foo"];
+                    pub const PARAM_TYPES: &'static [&'static str] = &["address"];
+                    pub fn is_anonymous() -> bool {
+                        false
+                    }
                     pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         if log.topics.len() != 2usize {
                             return false;
@@ -369,33 +855,76 @@ mod tests {
                     }
                     pub fn decode(
                         log: &substreams_ethereum::pb::eth::v2::Log
-                    ) -> Result<Self, String> {
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        if log.topics.len() != 2usize {
+                            return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                                expected: 2usize,
+                                actual: log.topics.len(),
+                            });
+                        }
                         Ok(Self {
-                            foo: ethabi::decode(
-                                    &[ethabi::ParamType::Address],
-                                    log.topics[1usize].as_ref()
-                                )
-                                .map_err(|e| format!(
-                                    "unable to decode param 'foo' from topic of type 'address': {:?}",
-                                    e
-                                ))?
-                                .pop()
-                                .expect(INTERNAL_ERR)
-                                .into_address()
-                                .expect(INTERNAL_ERR)
-                                .as_bytes()
-                                .to_vec()
+                            foo: {
+                                let topic = substreams_ethereum::abi::Decoder::topic(&log.topics[1usize], "foo", "address", 1usize);
+                                topic.address(0usize)?.to_vec()
+                            }
                         })
                     }
+                    #[doc = " Decodes only the `foo` parameter of `log`, without decoding the whole event."]
+                    pub fn decode_foo(
+                        log: &substreams_ethereum::pb::eth::v2::Log
+                    ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                        if log.topics.len() != 2usize {
+                            return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                                expected: 2usize,
+                                actual: log.topics.len(),
+                            });
+                        }
+                        if log.topics[0].as_slice() != Self::TOPIC_ID {
+                            return Err(substreams_ethereum::DecodeError::topic(
+                                Self::SIGNATURE,
+                                "bytes32",
+                                0,
+                                "not the topic id of the event",
+                            ));
+                        }
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "foo",
+                            "address",
+                            1usize
+                        );
+                        Ok(topic.address(0usize)?.to_vec())
+                    }
+                    pub fn encode_log(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            address,
+                            topics: vec![
+                                Self::TOPIC_ID.to_vec(),
+                                substreams_ethereum::abi::Encoder::encode(32usize, |encoder| { encoder.address(self.foo.as_slice()); })
+                            ],
+                            data: Vec::<u8>::new(),
+                            ..Default::default()
+                        }
+                    }
                 }
                 impl substreams_ethereum::Event for One {
                     const NAME: &'static str = "one";
+                    const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+                    const SIGNATURE: &'static str = Self::SIGNATURE;
+                    const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+                    const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+                    fn is_anonymous() -> bool {
+                        Self::is_anonymous()
+                    }
                     fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         Self::match_log(log)
                     }
-                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, substreams_ethereum::DecodeError> {
                         Self::decode(log)
                     }
+                    fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                        self.encode_log(address)
+                    }
                 }
             },
         );
@@ -437,7 +966,7 @@ mod tests {
                     pub quantity: substreams::scalar::BigInt
                 }
                 impl Transfer {
-                    const TOPIC_ID: [u8; 32] = [
+                    pub const TOPIC_ID: [u8; 32] = [
                         221u8,
                         242u8,
                         82u8,
@@ -471,6 +1000,12 @@ mod tests {
                         179u8,
                         239u8
                     ];
+                    pub const SIGNATURE: &'static str = "Transfer(address,address,uint256)";
+                    pub const PARAM_NAMES: &'static [&'static str] = &["from", "to", "quantity"];
+                    pub const PARAM_TYPES: &'static [&'static str] = &["address", "address", "uint256"];
+                    pub fn is_anonymous() -> bool {
+                        false
+                    }
                     pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         if log.topics.len() != 3usize {
                             return false;
@@ -483,62 +1018,129 @@ mod tests {
                     }
                     pub fn decode(
                         log: &substreams_ethereum::pb::eth::v2::Log
-                    ) -> Result<Self, String> {
-                        let mut values = ethabi::decode(
-                                &[ethabi::ParamType::Uint(256usize)],
-                                log.data.as_ref()
-                            )
-                            .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
-                        values.reverse();
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        if log.topics.len() != 3usize {
+                            return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                                expected: 3usize,
+                                actual: log.topics.len(),
+                            });
+                        }
+                        let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
                         Ok(Self {
-                            from: ethabi::decode(
-                                    &[ethabi::ParamType::Address],
-                                    log.topics[1usize].as_ref()
-                                )
-                                .map_err(|e| format!(
-                                    "unable to decode param 'from' from topic of type 'address': {:?}",
-                                    e
-                                ))?
-                                .pop()
-                                .expect(INTERNAL_ERR)
-                                .into_address()
-                                .expect(INTERNAL_ERR)
-                                .as_bytes()
-                                .to_vec(),
-                            to: ethabi::decode(
-                                    &[ethabi::ParamType::Address],
-                                    log.topics[2usize].as_ref()
-                                )
-                                .map_err(|e| format!(
-                                    "unable to decode param 'to' from topic of type 'address': {:?}", e
-                                ))?
-                                .pop()
-                                .expect(INTERNAL_ERR)
-                                .into_address()
-                                .expect(INTERNAL_ERR)
-                                .as_bytes()
-                                .to_vec(),
-                            quantity: {
-                                let mut v = [0 as u8; 32];
-                                values
-                                    .pop()
-                                    .expect(INTERNAL_ERR)
-                                    .into_uint()
-                                    .expect(INTERNAL_ERR)
-                                    .to_big_endian(v.as_mut_slice());
-                                substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
-                            }
+                            from: {
+                                let topic = substreams_ethereum::abi::Decoder::topic(&log.topics[1usize], "from", "address", 1usize);
+                                topic.address(0usize)?.to_vec()
+                            },
+                            to: {
+                                let topic = substreams_ethereum::abi::Decoder::topic(&log.topics[2usize], "to", "address", 2usize);
+                                topic.address(0usize)?.to_vec()
+                            },
+                            quantity: decoder.big_uint(0usize)?
                         })
                     }
+                    #[doc = " Decodes only the `from` parameter of `log`, without decoding the whole event."]
+                    pub fn decode_from(
+                        log: &substreams_ethereum::pb::eth::v2::Log
+                    ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                        if log.topics.len() != 3usize {
+                            return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                                expected: 3usize,
+                                actual: log.topics.len(),
+                            });
+                        }
+                        if log.topics[0].as_slice() != Self::TOPIC_ID {
+                            return Err(substreams_ethereum::DecodeError::topic(
+                                Self::SIGNATURE,
+                                "bytes32",
+                                0,
+                                "not the topic id of the event",
+                            ));
+                        }
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "from",
+                            "address",
+                            1usize
+                        );
+                        Ok(topic.address(0usize)?.to_vec())
+                    }
+                    #[doc = " Decodes only the `to` parameter of `log`, without decoding the whole event."]
+                    pub fn decode_to(
+                        log: &substreams_ethereum::pb::eth::v2::Log
+                    ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                        if log.topics.len() != 3usize {
+                            return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                                expected: 3usize,
+                                actual: log.topics.len(),
+                            });
+                        }
+                        if log.topics[0].as_slice() != Self::TOPIC_ID {
+                            return Err(substreams_ethereum::DecodeError::topic(
+                                Self::SIGNATURE,
+                                "bytes32",
+                                0,
+                                "not the topic id of the event",
+                            ));
+                        }
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[2usize],
+                            "to",
+                            "address",
+                            2usize
+                        );
+                        Ok(topic.address(0usize)?.to_vec())
+                    }
+                    #[doc = " Decodes only the `quantity` parameter of `log`, without decoding the whole event."]
+                    pub fn decode_quantity(
+                        log: &substreams_ethereum::pb::eth::v2::Log
+                    ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                        if log.topics.len() != 3usize {
+                            return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                                expected: 3usize,
+                                actual: log.topics.len(),
+                            });
+                        }
+                        if log.topics[0].as_slice() != Self::TOPIC_ID {
+                            return Err(substreams_ethereum::DecodeError::topic(
+                                Self::SIGNATURE,
+                                "bytes32",
+                                0,
+                                "not the topic id of the event",
+                            ));
+                        }
+                        substreams_ethereum::abi::Decoder::new(&log.data).big_uint(0usize)
+                    }
+                    pub fn encode_log(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            address,
+                            topics: vec![
+                                Self::TOPIC_ID.to_vec(),
+                                substreams_ethereum::abi::Encoder::encode(32usize, |encoder| { encoder.address(self.from.as_slice()); }),
+                                substreams_ethereum::abi::Encoder::encode(32usize, |encoder| { encoder.address(self.to.as_slice()); })
+                            ],
+                            data: substreams_ethereum::abi::Encoder::encode(32usize, |encoder| { encoder.word(substreams_ethereum::abi::encode_big_uint(&self.quantity)); }),
+                            ..Default::default()
+                        }
+                    }
                 }
                 impl substreams_ethereum::Event for Transfer {
                     const NAME: &'static str = "Transfer";
+                    const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+                    const SIGNATURE: &'static str = Self::SIGNATURE;
+                    const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+                    const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+                    fn is_anonymous() -> bool {
+                        Self::is_anonymous()
+                    }
                     fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         Self::match_log(log)
                     }
-                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, substreams_ethereum::DecodeError> {
                         Self::decode(log)
                     }
+                    fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                        self.encode_log(address)
+                    }
                 }
             },
         );
@@ -580,7 +1182,7 @@ mod tests {
                     pub token_id: substreams::scalar::BigInt
                 }
                 impl Transfer {
-                    const TOPIC_ID: [u8; 32] = [
+                    pub const TOPIC_ID: [u8; 32] = [
                         221u8,
                         242u8,
                         82u8,
@@ -614,6 +1216,12 @@ mod tests {
                         179u8,
                         239u8
                     ];
+                    pub const SIGNATURE: &'static str = "Transfer(address,address,uint256)";
+                    pub const PARAM_NAMES: &'static [&'static str] = &["from", "to", "token_id"];
+                    pub const PARAM_TYPES: &'static [&'static str] = &["address", "address", "uint256"];
+                    pub fn is_anonymous() -> bool {
+                        false
+                    }
                     pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         if log.topics.len() != 4usize {
                             return false;
@@ -626,65 +1234,402 @@ mod tests {
                     }
                     pub fn decode(
                         log: &substreams_ethereum::pb::eth::v2::Log
-                    ) -> Result<Self, String> {
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        if log.topics.len() != 4usize {
+                            return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                                expected: 4usize,
+                                actual: log.topics.len(),
+                            });
+                        }
                         Ok(Self {
-                            from: ethabi::decode(
-                                    &[ethabi::ParamType::Address],
-                                    log.topics[1usize].as_ref()
-                                )
-                                .map_err(|e| format!(
-                                    "unable to decode param 'from' from topic of type 'address': {:?}",
-                                    e
-                                ))?
-                                .pop()
-                                .expect(INTERNAL_ERR)
-                                .into_address()
-                                .expect(INTERNAL_ERR)
-                                .as_bytes()
-                                .to_vec(),
-                            to: ethabi::decode(
-                                    &[ethabi::ParamType::Address],
-                                    log.topics[2usize].as_ref()
-                                )
-                                .map_err(|e| format!(
-                                    "unable to decode param 'to' from topic of type 'address': {:?}", e
-                                ))?
-                                .pop()
-                                .expect(INTERNAL_ERR)
-                                .into_address()
-                                .expect(INTERNAL_ERR)
-                                .as_bytes()
-                                .to_vec(),
+                            from: {
+                                let topic = substreams_ethereum::abi::Decoder::topic(&log.topics[1usize], "from", "address", 1usize);
+                                topic.address(0usize)?.to_vec()
+                            },
+                            to: {
+                                let topic = substreams_ethereum::abi::Decoder::topic(&log.topics[2usize], "to", "address", 2usize);
+                                topic.address(0usize)?.to_vec()
+                            },
                             token_id: {
-                                let mut v = [0 as u8; 32];
-                                ethabi::decode(
-                                    &[ethabi::ParamType::Uint(256usize)],
-                                    log.topics[3usize].as_ref()
-                                )
-                                    .map_err(|e| format!(
-                                        "unable to decode param 'token_id' from topic of type 'uint256': {:?}",
-                                        e
-                                    ))?
-                                    .pop()
-                                    .expect(INTERNAL_ERR)
-                                    .into_uint()
-                                    .expect(INTERNAL_ERR)
-                                    .to_big_endian(v.as_mut_slice());
-                                substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                                let topic = substreams_ethereum::abi::Decoder::topic(&log.topics[3usize], "token_id", "uint256", 3usize);
+                                topic.big_uint(0usize)?
                             }
                         })
                     }
+                    #[doc = " Decodes only the `from` parameter of `log`, without decoding the whole event."]
+                    pub fn decode_from(
+                        log: &substreams_ethereum::pb::eth::v2::Log
+                    ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                        if log.topics.len() != 4usize {
+                            return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                                expected: 4usize,
+                                actual: log.topics.len(),
+                            });
+                        }
+                        if log.topics[0].as_slice() != Self::TOPIC_ID {
+                            return Err(substreams_ethereum::DecodeError::topic(
+                                Self::SIGNATURE,
+                                "bytes32",
+                                0,
+                                "not the topic id of the event",
+                            ));
+                        }
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[1usize],
+                            "from",
+                            "address",
+                            1usize
+                        );
+                        Ok(topic.address(0usize)?.to_vec())
+                    }
+                    #[doc = " Decodes only the `to` parameter of `log`, without decoding the whole event."]
+                    pub fn decode_to(
+                        log: &substreams_ethereum::pb::eth::v2::Log
+                    ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                        if log.topics.len() != 4usize {
+                            return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                                expected: 4usize,
+                                actual: log.topics.len(),
+                            });
+                        }
+                        if log.topics[0].as_slice() != Self::TOPIC_ID {
+                            return Err(substreams_ethereum::DecodeError::topic(
+                                Self::SIGNATURE,
+                                "bytes32",
+                                0,
+                                "not the topic id of the event",
+                            ));
+                        }
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[2usize],
+                            "to",
+                            "address",
+                            2usize
+                        );
+                        Ok(topic.address(0usize)?.to_vec())
+                    }
+                    #[doc = " Decodes only the `token_id` parameter of `log`, without decoding the whole event."]
+                    pub fn decode_token_id(
+                        log: &substreams_ethereum::pb::eth::v2::Log
+                    ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                        if log.topics.len() != 4usize {
+                            return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                                expected: 4usize,
+                                actual: log.topics.len(),
+                            });
+                        }
+                        if log.topics[0].as_slice() != Self::TOPIC_ID {
+                            return Err(substreams_ethereum::DecodeError::topic(
+                                Self::SIGNATURE,
+                                "bytes32",
+                                0,
+                                "not the topic id of the event",
+                            ));
+                        }
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[3usize],
+                            "token_id",
+                            "uint256",
+                            3usize
+                        );
+                        topic.big_uint(0usize)
+                    }
+                    pub fn encode_log(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            address,
+                            topics: vec![
+                                Self::TOPIC_ID.to_vec(),
+                                substreams_ethereum::abi::Encoder::encode(32usize, |encoder| { encoder.address(self.from.as_slice()); }),
+                                substreams_ethereum::abi::Encoder::encode(32usize, |encoder| { encoder.address(self.to.as_slice()); }),
+                                substreams_ethereum::abi::Encoder::encode(32usize, |encoder| { encoder.word(substreams_ethereum::abi::encode_big_uint(&self.token_id)); })
+                            ],
+                            data: Vec::<u8>::new(),
+                            ..Default::default()
+                        }
+                    }
                 }
                 impl substreams_ethereum::Event for Transfer {
                     const NAME: &'static str = "Transfer";
+                    const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+                    const SIGNATURE: &'static str = Self::SIGNATURE;
+                    const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+                    const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+                    fn is_anonymous() -> bool {
+                        Self::is_anonymous()
+                    }
+                    fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                        Self::match_log(log)
+                    }
+                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, substreams_ethereum::DecodeError> {
+                        Self::decode(log)
+                    }
+                    fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                        self.encode_log(address)
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_anonymous_event() {
+        let ethabi_event = ethabi::Event {
+            name: "Deposit".into(),
+            inputs: vec![
+                ethabi::EventParam {
+                    name: "owner".into(),
+                    kind: ethabi::ParamType::Address,
+                    indexed: true,
+                },
+                ethabi::EventParam {
+                    name: "amount".into(),
+                    kind: ethabi::ParamType::Uint(256),
+                    indexed: false,
+                },
+            ],
+            anonymous: true,
+        };
+
+        let e = Event::from((&ethabi_event.name, &ethabi_event));
+
+        assert_ast_eq(
+            e.generate_event(),
+            quote! {
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct Deposit {
+                    pub owner: Vec<u8>,
+                    pub amount: substreams::scalar::BigInt
+                }
+                impl Deposit {
+                    pub const TOPIC_ID: [u8; 32] = [
+                        225u8,
+                        255u8,
+                        252u8,
+                        196u8,
+                        146u8,
+                        61u8,
+                        4u8,
+                        181u8,
+                        89u8,
+                        244u8,
+                        210u8,
+                        154u8,
+                        139u8,
+                        252u8,
+                        108u8,
+                        218u8,
+                        4u8,
+                        235u8,
+                        91u8,
+                        13u8,
+                        60u8,
+                        70u8,
+                        7u8,
+                        81u8,
+                        194u8,
+                        64u8,
+                        44u8,
+                        92u8,
+                        92u8,
+                        201u8,
+                        16u8,
+                        156u8
+                    ];
+                    pub const SIGNATURE: &'static str = "Deposit(address,uint256)";
+                    pub const PARAM_NAMES: &'static [&'static str] = &["owner", "amount"];
+                    pub const PARAM_TYPES: &'static [&'static str] = &["address", "uint256"];
+                    pub fn is_anonymous() -> bool {
+                        true
+                    }
+                    pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                        if log.topics.len() != 1usize {
+                            return false;
+                        }
+                        if log.data.len() != 32usize {
+                            return false;
+                        }
+                        return true;
+                    }
+                    pub fn decode(
+                        log: &substreams_ethereum::pb::eth::v2::Log
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        if log.topics.len() != 1usize {
+                            return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                                expected: 1usize,
+                                actual: log.topics.len(),
+                            });
+                        }
+                        let decoder = substreams_ethereum::abi::Decoder::new(&log.data);
+                        Ok(Self {
+                            owner: {
+                                let topic = substreams_ethereum::abi::Decoder::topic(&log.topics[0usize], "owner", "address", 0usize);
+                                topic.address(0usize)?.to_vec()
+                            },
+                            amount: decoder.big_uint(0usize)?
+                        })
+                    }
+                    #[doc = " Decodes only the `owner` parameter of `log`, without decoding the whole event."]
+                    pub fn decode_owner(
+                        log: &substreams_ethereum::pb::eth::v2::Log
+                    ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+                        if log.topics.len() != 1usize {
+                            return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                                expected: 1usize,
+                                actual: log.topics.len(),
+                            });
+                        }
+                        let topic = substreams_ethereum::abi::Decoder::topic(
+                            &log.topics[0usize],
+                            "owner",
+                            "address",
+                            0usize
+                        );
+                        Ok(topic.address(0usize)?.to_vec())
+                    }
+                    #[doc = " Decodes only the `amount` parameter of `log`, without decoding the whole event."]
+                    pub fn decode_amount(
+                        log: &substreams_ethereum::pb::eth::v2::Log
+                    ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+                        if log.topics.len() != 1usize {
+                            return Err(substreams_ethereum::DecodeError::InvalidTopicCount {
+                                expected: 1usize,
+                                actual: log.topics.len(),
+                            });
+                        }
+                        substreams_ethereum::abi::Decoder::new(&log.data).big_uint(0usize)
+                    }
+                    pub fn encode_log(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            address,
+                            topics: vec![
+                                substreams_ethereum::abi::Encoder::encode(32usize, |encoder| { encoder.address(self.owner.as_slice()); })
+                            ],
+                            data: substreams_ethereum::abi::Encoder::encode(32usize, |encoder| { encoder.word(substreams_ethereum::abi::encode_big_uint(&self.amount)); }),
+                            ..Default::default()
+                        }
+                    }
+                }
+                impl substreams_ethereum::Event for Deposit {
+                    const NAME: &'static str = "Deposit";
+                    const TOPIC_ID: [u8; 32] = Self::TOPIC_ID;
+                    const SIGNATURE: &'static str = Self::SIGNATURE;
+                    const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+                    const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
+                    fn is_anonymous() -> bool {
+                        Self::is_anonymous()
+                    }
                     fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         Self::match_log(log)
                     }
-                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, substreams_ethereum::DecodeError> {
                         Self::decode(log)
                     }
+                    fn encode(&self, address: Vec<u8>) -> substreams_ethereum::pb::eth::v2::Log {
+                        self.encode_log(address)
+                    }
                 }
             },
         );
     }
+
+    #[test]
+    fn test_events_enum() {
+        let events: Vec<Event> = [
+            ethabi::Event {
+                name: "Approval".into(),
+                inputs: vec![],
+                anonymous: false,
+            },
+            ethabi::Event {
+                name: "Deposit".into(),
+                inputs: vec![],
+                anonymous: true,
+            },
+            ethabi::Event {
+                name: "Transfer".into(),
+                inputs: vec![],
+                anonymous: false,
+            },
+        ]
+        .iter()
+        .map(|event| (&event.name, event).into())
+        .collect();
+
+        assert_ast_eq(
+            generate_events_enum(&events),
+            quote! {
+                /// Any of the contract's events, see [Events::match_and_decode].
+                #[derive(Debug, Clone, PartialEq)]
+                pub enum Events {
+                    Approval(Approval),
+                    Transfer(Transfer)
+                }
+                impl Events {
+                    /// Decodes `log` into the event whose signature is the first topic of the log.
+                    pub fn match_and_decode(
+                        log: impl AsRef<substreams_ethereum::pb::eth::v2::Log>
+                    ) -> Option<Self> {
+                        use substreams_ethereum::Event;
+                        let log = log.as_ref();
+                        let topic = <[u8; 32] as std::convert::TryFrom<&[u8]>>::try_from(
+                            log.topics.get(0)?.as_ref(),
+                        )
+                        .ok()?;
+                        match topic {
+                            Approval::TOPIC_ID => Approval::match_and_decode(log).map(Events::Approval),
+                            Transfer::TOPIC_ID => Transfer::match_and_decode(log).map(Events::Transfer),
+                            _ => None,
+                        }
+                    }
+                    pub fn name(&self) -> &'static str {
+                        match self {
+                            Events::Approval(_) => "Approval",
+                            Events::Transfer(_) => "Transfer"
+                        }
+                    }
+                }
+                impl substreams_ethereum::ContractEvents for Events {
+                    fn match_and_decode(
+                        log: impl AsRef<substreams_ethereum::pb::eth::v2::Log>
+                    ) -> Option<Self> {
+                        Self::match_and_decode(log)
+                    }
+                    fn name(&self) -> &'static str {
+                        self.name()
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_borrowed_accessor_name_clashes() {
+        let ethabi_event = ethabi::Event {
+            name: "Hello".into(),
+            inputs: vec![
+                ethabi::EventParam {
+                    name: "log".into(),
+                    kind: ethabi::ParamType::Address,
+                    indexed: true,
+                },
+                ethabi::EventParam {
+                    name: "to_owned".into(),
+                    kind: ethabi::ParamType::Bool,
+                    indexed: false,
+                },
+            ],
+            anonymous: false,
+        };
+
+        let mut e = Event::from((&ethabi_event.name, &ethabi_event));
+        e.borrowed = true;
+        let code = e.generate_event().to_string();
+
+        // Accessors named like a method of the view are suffixed, the owned fields are not
+        assert!(code.contains("pub fn log_ (& self)"));
+        assert!(code.contains("pub fn to_owned_ (& self)"));
+        assert!(code.contains("log : self . log_ () ? . to_vec ()"));
+        assert!(code.contains("to_owned : self . to_owned_ () ?"));
+        assert!(!code.contains("pub fn log (& self) -> Result"));
+    }
 }
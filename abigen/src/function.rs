@@ -6,16 +6,25 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use ethabi::StateMutability;
 use heck::ToUpperCamelCase;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 
-use crate::to_syntax_string;
+use crate::{
+    decode_result, decode_tuple, encode_tuple,
+    message::Param,
+    meta::{EntryMeta, EMPTY_ENTRY},
+    signature_constants, struct_field, Position,
+};
 
-use super::{from_token, get_output_kinds, param_names, rust_type, to_token};
+use super::{dispatch_arms, get_output_kinds, param_names, rust_type, rust_variable};
 
 struct Inputs {
-    tokenize: Vec<TokenStream>,
+    names: Vec<syn::Ident>,
+    kinds: Vec<TokenStream>,
+    /// Expression of the ABI encoding of the inputs.
+    encode: TokenStream,
     decoded_values: TokenStream,
     decoded_fields: Vec<TokenStream>,
     fields: Vec<TokenStream>,
@@ -36,14 +45,30 @@ pub struct Function {
     pub(crate) name: String,
 
     short_signature: [u8; 4],
+    /// The `SIGNATURE`, `PARAM_NAMES` and `PARAM_TYPES` constants.
+    signature: TokenStream,
+    /// Whether the function is a `view` or `pure` function.
+    view: bool,
     /// Function input params.
     inputs: Inputs,
     /// Function output params.
     outputs: Outputs,
+    /// Whether the `call` method and the `RPCDecodable` implementation are generated.
+    pub(crate) rpc_helpers: bool,
+    /// Input parameters of the function, used to generate its `prost::Message` version.
+    pub(crate) params: Vec<Param>,
+    /// Attributes added to the generated type, like derives.
+    pub(crate) attributes: TokenStream,
 }
 
 impl<'a> From<(&'a String, &'a ethabi::Function)> for Function {
     fn from((name, f): (&'a String, &'a ethabi::Function)) -> Self {
+        (name, f, &EMPTY_ENTRY).into()
+    }
+}
+
+impl<'a> From<(&'a String, &'a ethabi::Function, &'a EntryMeta)> for Function {
+    fn from((name, f, meta): (&'a String, &'a ethabi::Function, &'a EntryMeta)) -> Self {
         // [param0, hello_world, param2]
         let input_names = param_names(&f.inputs);
 
@@ -51,121 +76,109 @@ impl<'a> From<(&'a String, &'a ethabi::Function)> for Function {
         let input_kinds: Vec<_> = f
             .inputs
             .iter()
-            .map(|param| rust_type(&param.kind))
+            .enumerate()
+            .map(|(i, param)| rust_type(&param.kind, meta.input(i)))
             .collect();
 
         let input_struct_fields = input_names
             .iter()
-            .zip(input_kinds.iter())
-            .map(|(param_name, kind)| quote! { pub #param_name: #kind })
+            .zip(f.inputs.iter())
+            .enumerate()
+            .map(|(i, (param_name, param))| struct_field(param_name, &param.kind, meta.input(i)))
             .collect();
 
-        let input_ethabi_param_types = if !f.inputs.is_empty() {
-            let params: Vec<_> = f
-                .inputs
-                .iter()
-                .map(|input| to_syntax_string(&input.kind))
-                .collect();
+        let params = input_names
+            .iter()
+            .zip(f.inputs.iter())
+            .enumerate()
+            .map(|(i, (name, param))| Param::new(name, &param.kind, meta.input(i)))
+            .collect();
 
+        let input_decoder = if !f.inputs.is_empty() {
             quote! {
                 let maybe_data = call.input.get(4..);
                 if maybe_data.is_none() {
-                    return Err("no data to decode".to_string());
+                    return Err(substreams_ethereum::DecodeError::DataTooShort {
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
                 }
 
-                let mut values = ethabi::decode(&[#(#params),*], maybe_data.unwrap())
-                        .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
-                values.reverse();
+                let decoder = substreams_ethereum::abi::Decoder::with_offset(maybe_data.unwrap(), 4);
             }
         } else {
             quote! {}
         };
 
-        // We go reverse in the iteration because we use a series of `.pop()` to correctly
-        // extract elements and put them in the good fields.
-        let input_struct_decoded_fields = f
-            .inputs
-            .iter()
-            .zip(input_names.iter())
-            .map(|(param, name)| {
-                let data_access = quote! { values.pop().expect(INTERNAL_ERR) };
-                let decode_input = from_token(&param.kind, &data_access);
-                quote! {
-                   #name: #decode_input
-                }
-            })
-            .collect();
-
-        // [Token::Uint(param0.into()), Token::Bytes(hello_world.into()), Token::Array(param2.into_iter().map(Into::into).collect())]
-        let tokenize: Vec<_> = input_names
-            .iter()
-            .zip(f.inputs.iter())
-            .map(|(param_name, param)| to_token(&quote! { self.#param_name }, &param.kind))
-            .collect();
+        let input_struct_decoded_fields = decode_tuple(
+            f.inputs
+                .iter()
+                .enumerate()
+                .map(|(i, param)| (&param.kind, meta.input(i))),
+            &quote! { decoder },
+            &Position::at(0),
+        )
+        .into_iter()
+        .zip(input_names.iter())
+        .map(|(decode_input, name)| {
+            quote! {
+               #name: #decode_input
+            }
+        })
+        .collect();
 
-        let output_result = get_output_kinds(&f.outputs);
+        let encode = encode_tuple(
+            &input_names
+                .iter()
+                .zip(f.inputs.iter())
+                .enumerate()
+                .map(|(i, (param_name, param))| {
+                    (quote! { self.#param_name }, &param.kind, meta.input(i))
+                })
+                .collect::<Vec<_>>(),
+        );
 
-        let output_param_types: Vec<_> = f
-            .outputs
-            .iter()
-            .map(|output| to_syntax_string(&output.kind))
-            .collect();
+        let output_result = get_output_kinds(&f.outputs, meta);
 
         let output_implementation = match f.outputs.len() {
             0 => quote! {},
             1 => {
-                let decode_param_type = &output_param_types[0];
-                let data_access =
-                    quote! { values.pop().expect("one output data should have existed") };
-                let decode_input = from_token(&f.outputs[0].kind, &data_access);
+                let decode_output = decode_result(
+                    &f.outputs[0].kind,
+                    meta.output(0),
+                    &quote! { decoder },
+                    &Position::at(0),
+                );
 
                 quote! {
-                    pub fn output_call(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<#output_result, String> {
+                    pub fn output_call(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<#output_result, substreams_ethereum::DecodeError> {
                         Self::output(call.return_data.as_ref())
                     }
 
-                    pub fn output(data: &[u8]) -> Result<#output_result, String> {
-                        let mut values = ethabi::decode(&[#decode_param_type], data.as_ref())
-                        .map_err(|e| format!("unable to decode output data: {:?}", e))?;
+                    pub fn output(data: &[u8]) -> Result<#output_result, substreams_ethereum::DecodeError> {
+                        let decoder = substreams_ethereum::abi::Decoder::new(data);
 
-                        Ok(#decode_input)
+                        #decode_output
                     }
                 }
             }
             _ => {
-                let output_tuple_fields: Vec<_> = f
-                    .outputs
-                    .iter()
-                    .map(|input| to_syntax_string(&input.kind))
-                    .collect();
-
-                let output_ethabi_decoded_values = quote! {
-                    let mut values = ethabi::decode(&[#(#output_tuple_fields),*], data.as_ref())
-                            .map_err(|e| format!("unable to decode output data: {:?}", e))?;
-                    values.reverse();
-                };
-
-                // We go reverse in the iteration because we use a series of `.pop()` to correctly
-                // extract elements and put them in the good fields.
-                let output_tuple_decoded_fields: Vec<TokenStream> = f
-                    .outputs
-                    .iter()
-                    .map(|param| {
-                        let data_access = quote! { values.pop().expect(INTERNAL_ERR) };
-                        let decode_input = from_token(&param.kind, &data_access);
-                        quote! {
-                           #decode_input
-                        }
-                    })
-                    .collect();
+                let output_tuple_decoded_fields = decode_tuple(
+                    f.outputs
+                        .iter()
+                        .enumerate()
+                        .map(|(i, param)| (&param.kind, meta.output(i))),
+                    &quote! { decoder },
+                    &Position::at(0),
+                );
 
                 quote! {
-                    pub fn output_call(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<#output_result, String> {
+                    pub fn output_call(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<#output_result, substreams_ethereum::DecodeError> {
                         Self::output(call.return_data.as_ref())
                     }
 
-                    pub fn output(data: &[u8]) -> Result<#output_result, String> {
-                        #output_ethabi_decoded_values
+                    pub fn output(data: &[u8]) -> Result<#output_result, substreams_ethereum::DecodeError> {
+                        let decoder = substreams_ethereum::abi::Decoder::new(data);
 
                         Ok((#(#output_tuple_decoded_fields),*))
                     }
@@ -180,9 +193,20 @@ impl<'a> From<(&'a String, &'a ethabi::Function)> for Function {
         Function {
             name: name.clone(),
             short_signature: f.short_signature(),
+            signature: signature_constants(
+                &f.name,
+                f.inputs.iter().map(|param| (&param.name, &param.kind)),
+            ),
+            view: f.constant.unwrap_or(false)
+                || matches!(
+                    f.state_mutability,
+                    StateMutability::View | StateMutability::Pure
+                ),
             inputs: Inputs {
-                tokenize,
-                decoded_values: input_ethabi_param_types,
+                names: input_names,
+                kinds: input_kinds,
+                encode,
+                decoded_values: input_decoder,
                 decoded_fields: input_struct_decoded_fields,
                 fields: input_struct_fields,
             },
@@ -191,6 +215,9 @@ impl<'a> From<(&'a String, &'a ethabi::Function)> for Function {
                 result: output_result,
                 count: f.outputs.len(),
             },
+            rpc_helpers: true,
+            params,
+            attributes: TokenStream::new(),
         }
     }
 }
@@ -207,8 +234,9 @@ impl Function {
             .map(|value| quote! { #value })
             .collect();
 
+        let signature = &self.signature;
         let function_fields = &self.inputs.fields;
-        let tokenize = &self.inputs.tokenize;
+        let encode = &self.inputs.encode;
         let decoded_input_values = &self.inputs.decoded_values;
         let decoded_input_fields = &self.inputs.decoded_fields;
 
@@ -217,6 +245,7 @@ impl Function {
 
         let call_implementation = match self.outputs.count {
             0 => quote! {},
+            _ if !self.rpc_helpers => quote! {},
             _ => quote! {
                 pub fn call(&self, address: Vec<u8>) -> Option<#outputs_result> {
                     use substreams_ethereum::pb::eth::rpc;
@@ -254,25 +283,30 @@ impl Function {
 
         let rpc_decodable_implementation = match self.outputs.count {
             0 => quote! {},
+            _ if !self.rpc_helpers => quote! {},
             _ => quote! {
                 impl substreams_ethereum::rpc::RPCDecodable<#outputs_result> for #camel_name {
-                    fn output(data: &[u8]) -> Result<#outputs_result, String> {
+                    fn output(data: &[u8]) -> Result<#outputs_result, substreams_ethereum::DecodeError> {
                     Self::output(data)
                     }
                 }
             },
         };
 
+        let attributes = &self.attributes;
+
         quote! {
             #[derive(Debug, Clone, PartialEq)]
+            #attributes
             pub struct #camel_name {
                 #(#function_fields),*
             }
 
             impl #camel_name {
-                const METHOD_ID: [u8; 4] = [#(#signature_hash_bytes),*];
+                pub const METHOD_ID: [u8; 4] = [#(#signature_hash_bytes),*];
+                #signature
 
-                pub fn decode(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<Self, String> {
+                pub fn decode(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<Self, substreams_ethereum::DecodeError> {
                     #decoded_input_values
 
                     Ok(Self {
@@ -281,7 +315,7 @@ impl Function {
                 }
 
                 pub fn encode(&self) -> Vec<u8> {
-                    let data = ethabi::encode(&[#(#tokenize),*]);
+                    let data = #encode;
 
                     let mut encoded = Vec::with_capacity(4 + data.len());
                     encoded.extend(Self::METHOD_ID);
@@ -304,10 +338,14 @@ impl Function {
 
             impl substreams_ethereum::Function for #camel_name {
                 const NAME: &'static str = #name;
+                const METHOD_ID: [u8; 4] = Self::METHOD_ID;
+                const SIGNATURE: &'static str = Self::SIGNATURE;
+                const PARAM_NAMES: &'static [&'static str] = Self::PARAM_NAMES;
+                const PARAM_TYPES: &'static [&'static str] = Self::PARAM_TYPES;
                 fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                     Self::match_call(call)
                 }
-                fn decode(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<Self, String> {
+                fn decode(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<Self, substreams_ethereum::DecodeError> {
                     Self::decode(call)
                 }
                 fn encode(&self) -> Vec<u8> {
@@ -318,4 +356,177 @@ impl Function {
             #rpc_decodable_implementation
         }
     }
+
+    /// Generates the method of the contract handle calling the function through RPC, only view
+    /// functions returning a value have one.
+    pub fn generate_handle_method(&self) -> Option<TokenStream> {
+        if !self.view || self.outputs.count == 0 {
+            return None;
+        }
+
+        let camel_name = syn::Ident::new(&self.name.to_upper_camel_case(), Span::call_site());
+        let method_name = syn::Ident::new(&rust_variable(&self.name), Span::call_site());
+        let names = &self.inputs.names;
+        let kinds = &self.inputs.kinds;
+        let outputs_result = &self.outputs.result;
+
+        Some(quote! {
+            pub fn #method_name(&self, #(#names: #kinds),*) -> Option<#outputs_result> {
+                let responses = substreams_ethereum::rpc::RpcBatch::new()
+                    .add(#camel_name { #(#names),* }, self.address.to_vec())
+                    .execute()
+                    .ok()?
+                    .responses;
+
+                substreams_ethereum::rpc::RpcBatch::decode::<_, #camel_name>(responses.get(0)?)
+            }
+        })
+    }
+}
+
+/// Generates the `Functions` enum of the contract, decoding a call into any of its functions
+/// through a match on the method id.
+pub fn generate_functions_enum(functions: &[Function]) -> TokenStream {
+    if functions.is_empty() {
+        return TokenStream::new();
+    }
+
+    let names: Vec<_> = functions.iter().map(|function| &function.name).collect();
+    let camel_names: Vec<_> = functions
+        .iter()
+        .map(|function| syn::Ident::new(&function.name.to_upper_camel_case(), Span::call_site()))
+        .collect();
+    let arms = dispatch_arms(
+        &syn::Ident::new("Functions", Span::call_site()),
+        &syn::Ident::new("METHOD_ID", Span::call_site()),
+        &quote! { call },
+        &functions
+            .iter()
+            .zip(camel_names.iter())
+            .map(|(function, camel_name)| (&function.short_signature[..], camel_name.clone()))
+            .collect::<Vec<_>>(),
+    );
+
+    quote! {
+        /// Any of the contract's functions, see [Functions::match_and_decode].
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Functions {
+            #(#camel_names(#camel_names)),*
+        }
+
+        impl Functions {
+            /// Decodes `call` into the function whose method id is the first 4 bytes of the call input.
+            pub fn match_and_decode(call: impl AsRef<substreams_ethereum::pb::eth::v2::Call>) -> Option<Self> {
+                use substreams_ethereum::Function;
+
+                let call = call.as_ref();
+                let method_id = <[u8; 4] as std::convert::TryFrom<&[u8]>>::try_from(
+                    call.input.get(0..4)?,
+                )
+                .ok()?;
+
+                match method_id {
+                    #(#arms)*
+                    _ => None,
+                }
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    #(Functions::#camel_names(_) => #names),*
+                }
+            }
+        }
+
+        impl substreams_ethereum::ContractFunctions for Functions {
+            fn match_and_decode(call: impl AsRef<substreams_ethereum::pb::eth::v2::Call>) -> Option<Self> {
+                Self::match_and_decode(call)
+            }
+            fn name(&self) -> &'static str {
+                self.name()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::assert_ast_eq;
+
+    use super::{generate_functions_enum, Function};
+    use ethabi::StateMutability;
+    use quote::quote;
+
+    #[test]
+    #[allow(deprecated)] // `ethabi::Function::constant` has to be set
+    fn test_functions_enum() {
+        let functions: Vec<Function> = [
+            ethabi::Function {
+                name: "approve".into(),
+                inputs: vec![],
+                outputs: vec![],
+                constant: None,
+                state_mutability: StateMutability::NonPayable,
+            },
+            ethabi::Function {
+                name: "totalSupply".into(),
+                inputs: vec![],
+                outputs: vec![],
+                constant: None,
+                state_mutability: StateMutability::View,
+            },
+        ]
+        .iter()
+        .map(|function| (&function.name, function).into())
+        .collect();
+
+        assert_ast_eq(
+            generate_functions_enum(&functions),
+            quote! {
+                /// Any of the contract's functions, see [Functions::match_and_decode].
+                #[derive(Debug, Clone, PartialEq)]
+                pub enum Functions {
+                    Approve(Approve),
+                    TotalSupply(TotalSupply)
+                }
+                impl Functions {
+                    /// Decodes `call` into the function whose method id is the first 4 bytes of the call input.
+                    pub fn match_and_decode(
+                        call: impl AsRef<substreams_ethereum::pb::eth::v2::Call>
+                    ) -> Option<Self> {
+                        use substreams_ethereum::Function;
+                        let call = call.as_ref();
+                        let method_id = <[u8; 4] as std::convert::TryFrom<&[u8]>>::try_from(
+                            call.input.get(0..4)?,
+                        )
+                        .ok()?;
+                        match method_id {
+                            Approve::METHOD_ID => Approve::match_and_decode(call).map(Functions::Approve),
+                            TotalSupply::METHOD_ID => TotalSupply::match_and_decode(call).map(Functions::TotalSupply),
+                            _ => None,
+                        }
+                    }
+                    pub fn name(&self) -> &'static str {
+                        match self {
+                            Functions::Approve(_) => "approve",
+                            Functions::TotalSupply(_) => "totalSupply"
+                        }
+                    }
+                }
+                impl substreams_ethereum::ContractFunctions for Functions {
+                    fn match_and_decode(
+                        call: impl AsRef<substreams_ethereum::pb::eth::v2::Call>
+                    ) -> Option<Self> {
+                        Self::match_and_decode(call)
+                    }
+                    fn name(&self) -> &'static str {
+                        self.name()
+                    }
+                }
+            },
+        );
+
+        // No enum for contracts without functions
+        assert!(generate_functions_enum(&[]).is_empty());
+    }
 }
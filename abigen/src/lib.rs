@@ -10,22 +10,33 @@
 
 extern crate proc_macro;
 
+mod artifact;
 mod assertions;
 pub mod build;
-// mod constructor;
+mod constructor;
 mod contract;
+mod enums;
+mod error;
 mod event;
 mod function;
+mod message;
+mod meta;
+mod signature;
+mod structs;
 
 use anyhow::format_err;
+use artifact::Artifact;
+use contract::ContractOptions;
 // use ethabi::{Contract, Error, Param, ParamType, Result};
 use ethabi::{Contract, Error, Param, ParamType};
 use heck::ToSnakeCase;
+use meta::{AbiMeta, EntryMeta, TypeMeta, TypeOptions};
 use proc_macro2::Span;
 // use heck::ToSnakeCase;
 use quote::{quote, ToTokens};
 use std::{
     borrow::Cow,
+    collections::BTreeMap,
     env, fs,
     path::{Path, PathBuf},
 };
@@ -35,25 +46,65 @@ pub fn generate_abi_code<S: AsRef<str>>(
     path: S,
 ) -> Result<proc_macro2::TokenStream, anyhow::Error> {
     let normalized_path = normalize_path(path.as_ref())?;
-    let source_file = fs::File::open(&normalized_path).map_err(|_| {
+    let source = fs::read(&normalized_path).map_err(|_| {
         Error::Other(Cow::Owned(format!(
             "Cannot load contract abi from `{}`",
             normalized_path.display()
         )))
     })?;
-    let contract = Contract::load(source_file)?;
-    let c = contract::Contract::from(&contract);
-    Ok(c.generate())
+
+    generate_abi_code_from_bytes(&source)
 }
 
 pub fn generate_abi_code_from_bytes(
     bytes: &[u8],
 ) -> Result<proc_macro2::TokenStream, anyhow::Error> {
-    let contract = Contract::load(bytes)?;
-    let c = contract::Contract::from(&contract);
+    let c = load_contract(
+        bytes,
+        None,
+        TypeOptions::default(),
+        BTreeMap::new(),
+        &ContractOptions::default(),
+    )?;
     Ok(c.generate())
 }
 
+/// Generates the code of the contract whose ABI is described by the human-readable Solidity
+/// `signatures`, see [build::Abigen::from_signatures].
+pub fn generate_abi_code_from_signatures<S: AsRef<str>>(
+    signatures: &[S],
+) -> Result<proc_macro2::TokenStream, anyhow::Error> {
+    generate_abi_code_from_bytes(&signature::parse_signatures(signatures)?)
+}
+
+/// Loads the generator of the contract whose JSON ABI, or artifact holding it, is `bytes`, a
+/// handle type is generated for the contract if `contract_name` is given. `enum_variants` are
+/// the variants of the Solidity enums keyed by enum name, `contract_options` selects the
+/// generated items.
+fn load_contract(
+    bytes: &[u8],
+    contract_name: Option<String>,
+    options: TypeOptions,
+    enum_variants: BTreeMap<String, Vec<String>>,
+    contract_options: &ContractOptions,
+) -> Result<contract::Contract, anyhow::Error> {
+    let artifact = Artifact::parse(bytes)?;
+    let mut contract = Contract::load(artifact.abi.as_slice())?;
+    let mut meta = AbiMeta::parse(&artifact.abi)?;
+    meta.set_options(options);
+    meta.set_enum_variants(enum_variants);
+    contract_options.retain(&mut contract, &mut meta);
+    // The constructor, generated for the bytecode, is skipped along with functions
+    let bytecode = match contract_options.events_only {
+        true => None,
+        false => artifact.bytecode,
+    };
+    contract::Contract::new(&contract, meta, &contract_options.names)?
+        .with_name(contract_name)
+        .with_bytecode(bytecode)
+        .with_options(contract_options)
+}
+
 fn normalize_path<S: AsRef<Path>>(relative_path: S) -> Result<PathBuf, anyhow::Error> {
     // workaround for https://github.com/rust-lang/rust/issues/43860
     let cargo_toml_directory =
@@ -63,76 +114,114 @@ fn normalize_path<S: AsRef<Path>>(relative_path: S) -> Result<PathBuf, anyhow::E
     Ok(path)
 }
 
-fn to_syntax_string(param_type: &ethabi::ParamType) -> proc_macro2::TokenStream {
-    match *param_type {
-        ParamType::Address => quote! { ethabi::ParamType::Address },
-        ParamType::Bytes => quote! { ethabi::ParamType::Bytes },
-        ParamType::Int(x) => quote! { ethabi::ParamType::Int(#x) },
-        ParamType::Uint(x) => quote! { ethabi::ParamType::Uint(#x) },
-        ParamType::Bool => quote! { ethabi::ParamType::Bool },
-        ParamType::String => quote! { ethabi::ParamType::String },
-        ParamType::Array(ref param_type) => {
-            let param_type_quote = to_syntax_string(param_type);
-            quote! { ethabi::ParamType::Array(Box::new(#param_type_quote)) }
-        }
-        ParamType::FixedBytes(x) => quote! { ethabi::ParamType::FixedBytes(#x) },
-        ParamType::FixedArray(ref param_type, ref x) => {
-            let param_type_quote = to_syntax_string(param_type);
-            quote! { ethabi::ParamType::FixedArray(Box::new(#param_type_quote), #x) }
-        }
-        ParamType::Tuple(ref v) => {
-            let param_type_quotes = v.iter().map(|x| to_syntax_string(x));
-            quote! { ethabi::ParamType::Tuple(vec![#(#param_type_quotes),*]) }
-        }
-    }
-}
-
-// fn to_ethabi_param_vec<'a, P: 'a>(params: P) -> proc_macro2::TokenStream
-// where
-//     P: IntoIterator<Item = &'a Param>,
-// {
-//     let p = params
-//         .into_iter()
-//         .map(|x| {
-//             let name = &x.name;
-//             let kind = to_syntax_string(&x.kind);
-//             quote! {
-//                 ethabi::Param {
-//                     name: #name.to_owned(),
-//                     kind: #kind,
-//                     internal_type: None
-//                 }
-//             }
-//         })
-//         .collect::<Vec<_>>();
-
-//     quote! { vec![ #(#p),* ] }
-// }
-
-fn rust_type(input: &ParamType) -> proc_macro2::TokenStream {
+fn rust_type(input: &ParamType, meta: &TypeMeta) -> proc_macro2::TokenStream {
     match *input {
+        ParamType::Address if meta.options.typed_scalars => {
+            quote! { substreams_ethereum::scalar::Address }
+        }
         ParamType::Address => quote! { Vec<u8> },
         ParamType::Bytes => quote! { Vec<u8> },
+        ParamType::FixedBytes(32) if meta.options.typed_scalars => {
+            quote! { substreams_ethereum::scalar::H256 }
+        }
         ParamType::FixedBytes(size) => quote! { [u8; #size] },
-        ParamType::Int(_) => quote! { substreams::scalar::BigInt },
-        ParamType::Uint(_) => quote! { substreams::scalar::BigInt },
+        ParamType::Uint(_) if enum_ident(meta).is_some() => {
+            let name = enum_ident(meta);
+            quote! { super::enums::#name }
+        }
+        ParamType::Int(_) | ParamType::Uint(_) => match native_int(input, meta) {
+            Some(native) => quote! { #native },
+            None => quote! { substreams::scalar::BigInt },
+        },
         ParamType::Bool => quote! { bool },
         ParamType::String => quote! { String },
         ParamType::Array(ref kind) => {
-            let t = rust_type(&*kind);
+            let t = rust_type(kind, meta);
             quote! { Vec<#t> }
         }
         ParamType::FixedArray(ref kind, size) => {
-            let t = rust_type(&*kind);
+            let t = rust_type(kind, meta);
             quote! { [#t; #size] }
         }
-        ParamType::Tuple(ref types) => {
-            let tuple_elements = types.iter().map(rust_type);
-            quote! { (#(#tuple_elements,)*) }
-        }
+        ParamType::Tuple(ref types) => match struct_ident(meta) {
+            Some(name) => quote! { super::structs::#name },
+            None => {
+                let tuple_elements = types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, kind)| rust_type(kind, meta.component(i)));
+                quote! { (#(#tuple_elements,)*) }
+            }
+        },
     }
 }
 
+/// The declaration of the public field `name` of a generated struct holding a parameter of type
+/// `kind`.
+fn struct_field(name: &syn::Ident, kind: &ParamType, meta: &TypeMeta) -> proc_macro2::TokenStream {
+    let rust_kind = rust_type(kind, meta);
+    let serde = match meta.options.serde && serde_with(kind, meta) {
+        true => quote! { #[serde(with = "substreams_ethereum::abi::serde")] },
+        false => quote! {},
+    };
+
+    quote! { #serde pub #name: #rust_kind }
+}
+
+/// Whether the Rust type of `kind` holds bytes or big integers, which serde does not
+/// serialize as strings, outside of generated structs and enums that handle their own fields.
+fn serde_with(kind: &ParamType, meta: &TypeMeta) -> bool {
+    match *kind {
+        ParamType::Address | ParamType::Bytes | ParamType::FixedBytes(_) => true,
+        ParamType::Uint(_) if enum_ident(meta).is_some() => false,
+        ParamType::Int(_) | ParamType::Uint(_) => native_int(kind, meta).is_none(),
+        ParamType::Bool | ParamType::String => false,
+        ParamType::Array(ref kind) | ParamType::FixedArray(ref kind, _) => serde_with(kind, meta),
+        ParamType::Tuple(_) if struct_ident(meta).is_some() => false,
+        ParamType::Tuple(ref types) => types
+            .iter()
+            .enumerate()
+            .any(|(i, kind)| serde_with(kind, meta.component(i))),
+    }
+}
+
+/// The native Rust integer of the `int` or `uint` `kind` when native integers are enabled and
+/// the smallest one holding the value has at most 128 bits.
+fn native_int(kind: &ParamType, meta: &TypeMeta) -> Option<syn::Ident> {
+    let (prefix, size) = match *kind {
+        ParamType::Int(size) => ("i", size),
+        ParamType::Uint(size) => ("u", size),
+        _ => return None,
+    };
+
+    if !meta.options.native_ints || size > 128 {
+        return None;
+    }
+
+    let bits = [8, 16, 32, 64, 128]
+        .into_iter()
+        .find(|bits| size <= *bits)?;
+    Some(syn::Ident::new(
+        &format!("{}{}", prefix, bits),
+        Span::call_site(),
+    ))
+}
+
+/// The identifier of the generated enum (or `u8` alias) of the `uint8` described by `meta`, if
+/// any.
+fn enum_ident(meta: &TypeMeta) -> Option<syn::Ident> {
+    meta.enum_meta
+        .as_ref()
+        .map(|e| syn::Ident::new(&e.name, Span::call_site()))
+}
+
+/// The identifier of the generated struct representing the tuple described by `meta`, if any.
+fn struct_ident(meta: &TypeMeta) -> Option<syn::Ident> {
+    meta.struct_name
+        .as_ref()
+        .map(|name| syn::Ident::new(name, Span::call_site()))
+}
+
 fn fixed_data_size(input: &ParamType) -> Option<usize> {
     match input {
         ParamType::Address
@@ -182,241 +271,409 @@ fn min_data_size(input: &ParamType) -> usize {
     }
 }
 
-// fn template_param_type(input: &ParamType, index: usize) -> proc_macro2::TokenStream {
-//     let t_ident = syn::Ident::new(&format!("T{}", index), Span::call_site());
-//     let u_ident = syn::Ident::new(&format!("U{}", index), Span::call_site());
-//     match *input {
-//         ParamType::Address => quote! { #t_ident: Into<ethabi::Address> },
-//         ParamType::Bytes => quote! { #t_ident: Into<ethabi::Bytes> },
-//         ParamType::FixedBytes(32) => quote! { #t_ident: Into<ethabi::Hash> },
-//         ParamType::FixedBytes(size) => quote! { #t_ident: Into<[u8; #size]> },
-//         ParamType::Int(_) => quote! { #t_ident: Into<ethabi::Int> },
-//         ParamType::Uint(_) => quote! { #t_ident: Into<ethabi::Uint> },
-//         ParamType::Bool => quote! { #t_ident: Into<bool> },
-//         ParamType::String => quote! { #t_ident: Into<String> },
-//         ParamType::Array(ref kind) => {
-//             let t = rust_type(&*kind);
-//             quote! {
-//                 #t_ident: IntoIterator<Item = #u_ident>, #u_ident: Into<#t>
-//             }
-//         }
-//         ParamType::FixedArray(ref kind, size) => {
-//             let t = rust_type(&*kind);
-//             quote! {
-//                 #t_ident: Into<[#u_ident; #size]>, #u_ident: Into<#t>
-//             }
-//         }
-//         ParamType::Tuple(_) => {
-//             unimplemented!(
-//                 "Tuples are not supported. https://github.com/openethereum/ethabi/issues/175"
-//             )
-//         }
-//     }
-// }
-
-// fn from_template_param(input: &ParamType, name: &syn::Ident) -> proc_macro2::TokenStream {
-//     match *input {
-//         ParamType::Array(_) => {
-//             quote! { self.#name.into_iter().map(Into::into).collect::<Vec<_>>() }
-//         }
-//         ParamType::FixedArray(_, _) => {
-//             quote! { (Box::new(self.#name.into()) as Box<[_]>).into_vec().into_iter().map(Into::into).collect::<Vec<_>>() }
-//         }
-//         ParamType::Address => quote! { ethabi::Address::from_slice(self.#name.as_ref() ) },
-//         _ => firehose_into_ethabi_type(input, quote! { self.#name }),
-//     }
-// }
-
-// fn firehose_into_ethabi_type(
-//     input: &ParamType,
-//     variable: proc_macro2::TokenStream,
-// ) -> proc_macro2::TokenStream {
-//     match *input {
-//         ParamType::Address => quote! { ethabi::Address::from_slice(#variable) },
-//         ParamType::String => quote! { #variable.clone() },
-//         _ => quote! {#variable.into() },
-//     }
-// }
-
-fn to_token(name: &proc_macro2::TokenStream, kind: &ParamType) -> proc_macro2::TokenStream {
-    match *kind {
-        ParamType::Address => {
-            quote! { ethabi::Token::Address(ethabi::Address::from_slice(&#name)) }
+/// The size of the head of a value of type `kind` in the ABI encoding of a tuple, the offset
+/// of its tail for dynamic types.
+fn head_size(kind: &ParamType) -> usize {
+    fixed_data_size(kind).unwrap_or(32)
+}
+
+/// The position of the head of a value read by a `substreams_ethereum::abi::Decoder`, a byte
+/// offset added to a position only known at runtime, like the one of an array element.
+#[derive(Clone)]
+struct Position {
+    base: Option<proc_macro2::TokenStream>,
+    offset: usize,
+}
+
+impl Position {
+    fn at(offset: usize) -> Self {
+        Position { base: None, offset }
+    }
+
+    fn runtime(base: proc_macro2::TokenStream) -> Self {
+        Position {
+            base: Some(base),
+            offset: 0,
         }
-        ParamType::Bytes => quote! { ethabi::Token::Bytes(#name.clone()) },
-        ParamType::FixedBytes(_) => quote! { ethabi::Token::FixedBytes(#name.as_ref().to_vec()) },
-        ParamType::Int(_) => {
-            quote! {
-                {
-                    let non_full_signed_bytes = #name.to_signed_bytes_be();
-                    let mut full_signed_bytes = [0xff as u8; 32];
-                    non_full_signed_bytes.into_iter().rev().enumerate().for_each(|(i, byte)| full_signed_bytes[31 - i] = byte);
+    }
+
+    fn add(&self, offset: usize) -> Self {
+        Position {
+            base: self.base.clone(),
+            offset: self.offset + offset,
+        }
+    }
+}
+
+impl ToTokens for Position {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let offset = self.offset;
+        tokens.extend(match (&self.base, offset) {
+            (Some(base), 0) => quote! { #base },
+            (Some(base), _) => quote! { #base + #offset },
+            (None, _) => quote! { #offset },
+        });
+    }
+}
 
-                    ethabi::Token::Int(ethabi::Int::from_big_endian(full_signed_bytes.as_ref()))
+/// Generates the statements writing `value` of type `kind` with `encoder`, a
+/// `&mut substreams_ethereum::abi::Encoder`.
+fn encode_value(
+    value: &proc_macro2::TokenStream,
+    kind: &ParamType,
+    meta: &TypeMeta,
+    encoder: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match *kind {
+        ParamType::Address if meta.options.typed_scalars => {
+            quote! { #encoder.address(#value.as_bytes()); }
+        }
+        ParamType::Address => quote! { #encoder.address(#value.as_slice()); },
+        ParamType::Bytes => quote! { #encoder.bytes(#value.as_slice()); },
+        ParamType::FixedBytes(32) if meta.options.typed_scalars => {
+            quote! { #encoder.fixed_bytes(#value.as_bytes()); }
+        }
+        ParamType::FixedBytes(_) => quote! { #encoder.fixed_bytes(#value.as_slice()); },
+        ParamType::Uint(_) if enum_ident(meta).is_some() => {
+            match meta.enum_meta.as_ref().map(|e| e.variants.is_empty()) {
+                Some(false) => quote! {
+                    #encoder.word(substreams_ethereum::abi::encode_uint(u8::from(#value)));
+                },
+                _ => {
+                    quote! { #encoder.word(substreams_ethereum::abi::encode_uint(#value)); }
                 }
             }
         }
+        ParamType::Int(_) if native_int(kind, meta).is_some() => {
+            quote! { #encoder.word(substreams_ethereum::abi::encode_int(#value)); }
+        }
+        ParamType::Uint(_) if native_int(kind, meta).is_some() => {
+            quote! { #encoder.word(substreams_ethereum::abi::encode_uint(#value)); }
+        }
+        ParamType::Int(_) => {
+            quote! { #encoder.word(substreams_ethereum::abi::encode_big_int(&#value)); }
+        }
         ParamType::Uint(_) => {
-            quote! {
-                ethabi::Token::Uint(
-                            ethabi::Uint::from_big_endian(
-                                match #name.clone().to_bytes_be() {
-                                    (num_bigint::Sign::Plus, bytes) => bytes,
-                                    (num_bigint::Sign::NoSign, bytes) => bytes,
-                                    (num_bigint::Sign::Minus, _) => {
-                                        panic!("negative numbers are not supported")
-                                    },
-                                }.as_slice(),
-                            ),
-                        )
-            }
+            quote! { #encoder.word(substreams_ethereum::abi::encode_big_uint(&#value)); }
         }
-        ParamType::Bool => quote! { ethabi::Token::Bool(#name.clone()) },
-        ParamType::String => quote! { ethabi::Token::String(#name.clone()) },
+        ParamType::Bool => quote! { #encoder.bool(#value); },
+        ParamType::String => quote! { #encoder.string(#value.as_str()); },
         ParamType::Array(ref kind) => {
-            let inner_name = quote! { inner };
-            let inner_loop = to_token(&inner_name, kind);
+            let stride = head_size(kind);
+            let encode_element =
+                encode_value(&array_element(kind, meta), kind, meta, &quote! { elements });
             quote! {
-                // note the double {{
-                {
-                    let v = #name.iter().map(|#inner_name| #inner_loop).collect();
-                    ethabi::Token::Array(v)
-                }
+                #encoder.array(#value.len(), #stride, |elements| {
+                    for inner in #value.iter() {
+                        #encode_element
+                    }
+                });
             }
         }
-        ParamType::FixedArray(ref kind, _) => {
-            let inner_name = quote! { inner };
-            let inner_loop = to_token(&inner_name, kind);
-            quote! {
-                // note the double {{
-                {
-                    let v = #name.iter().map(|#inner_name| #inner_loop).collect();
-                    ethabi::Token::FixedArray(v)
+        ParamType::FixedArray(ref kind, size) => {
+            let encode_element =
+                |encoder| encode_value(&array_element(kind, meta), kind, meta, encoder);
+            match kind.is_dynamic() {
+                true => {
+                    let head_size = size * 32;
+                    let encode_element = encode_element(&quote! { elements });
+                    quote! {
+                        #encoder.tuple(#head_size, |elements| {
+                            for inner in #value.iter() {
+                                #encode_element
+                            }
+                        });
+                    }
+                }
+                false => {
+                    let encode_element = encode_element(encoder);
+                    quote! {
+                        for inner in #value.iter() {
+                            #encode_element
+                        }
+                    }
                 }
             }
         }
+        ParamType::Tuple(_) if struct_ident(meta).is_some() => {
+            quote! { #value.encode_tuple(#encoder); }
+        }
         ParamType::Tuple(ref types) => {
-            let inner_names = (0..types.len())
-                .map(|i| {
-                    let i = Index::from(i);
-                    quote! { #name.#i }
-                })
-                .collect::<Vec<_>>();
-
-            let inner_tokens = types
-                .iter()
-                .zip(&inner_names)
-                .map(|(kind, inner_name)| to_token(&inner_name.to_token_stream(), kind))
-                .collect::<Vec<_>>();
+            let encode_fields = |encoder| {
+                types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, kind)| {
+                        let index = Index::from(i);
+                        encode_value(&quote! { #value.#index }, kind, meta.component(i), encoder)
+                    })
+                    .collect::<Vec<_>>()
+            };
 
-            quote! {
-                ethabi::Token::Tuple(vec![
-                    #(#inner_tokens),*
-                ])
+            match kind.is_dynamic() {
+                true => {
+                    let head_size: usize = types.iter().map(head_size).sum();
+                    let encode_fields = encode_fields(&quote! { fields });
+                    quote! {
+                        #encoder.tuple(#head_size, |fields| {
+                            #(#encode_fields)*
+                        });
+                    }
+                }
+                false => {
+                    let encode_fields = encode_fields(encoder);
+                    quote! { #(#encode_fields)* }
+                }
             }
         }
     }
 }
 
-fn from_token(kind: &ParamType, token: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+/// Generates the expression of the element `inner` of an array being encoded, dereferenced when
+/// its type is `Copy` so it is passed by value like the fields of the encoded item.
+fn array_element(kind: &ParamType, meta: &TypeMeta) -> proc_macro2::TokenStream {
+    let is_copy = match *kind {
+        ParamType::Bool => true,
+        ParamType::Int(_) | ParamType::Uint(_) => {
+            enum_ident(meta).is_some() || native_int(kind, meta).is_some()
+        }
+        _ => false,
+    };
+
+    match is_copy {
+        true => quote! { *inner },
+        false => quote! { inner },
+    }
+}
+
+/// Generates the expression of the ABI encoding of the tuple of `values`, given as the
+/// expression of each value along with its type.
+fn encode_tuple(
+    values: &[(proc_macro2::TokenStream, &ParamType, &TypeMeta)],
+) -> proc_macro2::TokenStream {
+    if values.is_empty() {
+        return quote! { Vec::<u8>::new() };
+    }
+
+    let head_size: usize = values.iter().map(|(_, kind, _)| head_size(kind)).sum();
+    let encode_values = values
+        .iter()
+        .map(|(value, kind, meta)| encode_value(value, kind, meta, &quote! { encoder }));
+
+    quote! {
+        substreams_ethereum::abi::Encoder::encode(#head_size, |encoder| {
+            #(#encode_values)*
+        })
+    }
+}
+
+/// Generates the expression of the value of type `kind` whose head is at `at` of `decoder`, a
+/// `substreams_ethereum::abi::Decoder`, errors are propagated with `?`.
+fn decode_value(
+    kind: &ParamType,
+    meta: &TypeMeta,
+    decoder: &proc_macro2::TokenStream,
+    at: &Position,
+) -> proc_macro2::TokenStream {
     match *kind {
-        ParamType::Address => {
-            quote! { #token.into_address().expect(INTERNAL_ERR).as_bytes().to_vec() }
+        ParamType::Address if meta.options.typed_scalars => {
+            quote! { substreams_ethereum::scalar::Address(#decoder.address(#at)?) }
         }
-        ParamType::Bytes => {
-            quote! { #token.into_bytes().expect(INTERNAL_ERR) }
+        ParamType::Address => quote! { #decoder.address(#at)?.to_vec() },
+        ParamType::Bytes => quote! { #decoder.bytes(#at)?.to_vec() },
+        ParamType::FixedBytes(32) if meta.options.typed_scalars => {
+            quote! { substreams_ethereum::scalar::H256(#decoder.fixed_bytes::<32>(#at)?) }
         }
-        ParamType::FixedBytes(size) => {
-            let size: syn::Index = size.into();
+        ParamType::Array(ref kind) => {
+            let stride = head_size(kind);
+            let decode_element = decode_result(
+                kind,
+                meta,
+                &quote! { elements },
+                &Position::runtime(quote! { i * #stride }),
+            );
             quote! {
                 {
-                    let mut result = [0u8; #size];
-                    let v = #token.into_fixed_bytes().expect(INTERNAL_ERR);
-                    result.copy_from_slice(&v);
-                    result
+                    let (len, elements) = #decoder.array(#at, #stride)?;
+                    (0..len)
+                        .map(|i| -> Result<_, substreams_ethereum::DecodeError> { #decode_element })
+                        .collect::<Result<Vec<_>, substreams_ethereum::DecodeError>>()?
                 }
             }
         }
-        ParamType::Int(_) => quote! {
-            {
-                let mut v = [0 as u8; 32];
-                #token.into_int().expect(INTERNAL_ERR).to_big_endian(v.as_mut_slice());
-                substreams::scalar::BigInt::from_signed_bytes_be(&v)
+        ParamType::FixedArray(ref kind, size) => match kind.is_dynamic() {
+            true => {
+                let decode_elements = (0..size)
+                    .map(|i| decode_value(kind, meta, &quote! { elements }, &Position::at(i * 32)));
+                quote! {
+                    {
+                        let elements = #decoder.tail(#at)?;
+                        [#(#decode_elements),*]
+                    }
+                }
+            }
+            false => {
+                let stride = head_size(kind);
+                let decode_elements =
+                    (0..size).map(|i| decode_value(kind, meta, decoder, &at.add(i * stride)));
+                quote! { [#(#decode_elements),*] }
             }
         },
-        ParamType::Uint(_) => quote! {
-                {
-                    let mut v = [0 as u8; 32];
-                    #token.into_uint().expect(INTERNAL_ERR).to_big_endian(v.as_mut_slice());
-                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+        ParamType::Tuple(ref types) if struct_ident(meta).is_none() => {
+            let components = types
+                .iter()
+                .enumerate()
+                .map(|(i, kind)| (kind, meta.component(i)));
+
+            match kind.is_dynamic() {
+                true => {
+                    let decode_fields =
+                        decode_tuple(components, &quote! { fields }, &Position::at(0));
+                    quote! {
+                        {
+                            let fields = #decoder.tail(#at)?;
+                            (#(#decode_fields,)*)
+                        }
+                    }
                 }
-        },
-        ParamType::Bool => quote! { #token.into_bool().expect(INTERNAL_ERR) },
-        ParamType::String => quote! { #token.into_string().expect(INTERNAL_ERR) },
-        ParamType::Array(ref kind) => {
-            let inner = quote! { inner };
-            let inner_loop = from_token(kind, &inner);
-            quote! {
-                #token.into_array().expect(INTERNAL_ERR).into_iter()
-                    .map(|#inner| #inner_loop)
-                    .collect()
-            }
-        }
-        ParamType::FixedArray(ref kind, size) => {
-            let inner = quote! { inner };
-            let inner_loop = from_token(kind, &inner);
-            let to_array = vec![quote! { iter.next().expect(INTERNAL_ERR) }; size];
-            quote! {
-                {
-                    let mut iter = #token.into_fixed_array().expect(INTERNAL_ERR).into_iter()
-                        .map(|#inner| #inner_loop);
-                    [#(#to_array),*]
+                false => {
+                    let decode_fields = decode_tuple(components, decoder, at);
+                    quote! { (#(#decode_fields,)*) }
                 }
             }
         }
-        ParamType::Tuple(ref types) => {
-            let conversion = types.iter().enumerate().map(|(i, t)| {
-                let inner = quote! { tuple_elements[#i].clone() };
-                let inner_conversion = from_token(t, &inner);
-                quote! { #inner_conversion }
-            });
-
-            quote! {
-                {
-                    let tuple_elements = #token.into_tuple().expect(INTERNAL_ERR);
-                    (#(#conversion,)*)
-                }
-            }
+        _ => {
+            let decode = decode_result(kind, meta, decoder, at);
+            quote! { #decode? }
         }
     }
 }
 
-fn decode_topic(
-    name: &String,
+/// Same as [decode_value] but the expression is a `Result<_, substreams_ethereum::DecodeError>`,
+/// avoiding an `Ok(...?)` for the values read by a single call.
+fn decode_result(
     kind: &ParamType,
-    data_token: &proc_macro2::TokenStream,
+    meta: &TypeMeta,
+    decoder: &proc_macro2::TokenStream,
+    at: &Position,
 ) -> proc_macro2::TokenStream {
-    let syntax_type = to_syntax_string(kind);
-    let error_msg = format!(
-        "unable to decode param '{}' from topic of type '{}': {{:?}}",
-        name, kind
-    );
-
-    match kind {
-        ParamType::Int(_) => {
-            quote! {
-                substreams::scalar::BigInt::from_signed_bytes_be(#data_token)
+    match *kind {
+        ParamType::FixedBytes(size) if size != 32 || !meta.options.typed_scalars => {
+            quote! { #decoder.fixed_bytes::<#size>(#at) }
+        }
+        // Enums whose variants are unknown are aliases of `u8` and decoded as such.
+        ParamType::Uint(size) if enum_ident(meta).is_some() => {
+            let name = enum_ident(meta);
+            let value = quote! { #decoder.uint::<u8>(#at, #size) };
+
+            match meta.enum_meta.as_ref().map(|e| e.variants.is_empty()) {
+                Some(false) => quote! {
+                    #value.and_then(<super::enums::#name as std::convert::TryFrom<u8>>::try_from)
+                },
+                _ => value,
             }
         }
+        ParamType::Int(size) if native_int(kind, meta).is_some() => {
+            let native = native_int(kind, meta);
+            quote! { #decoder.int::<#native>(#at, #size) }
+        }
+        ParamType::Uint(size) if native_int(kind, meta).is_some() => {
+            let native = native_int(kind, meta);
+            quote! { #decoder.uint::<#native>(#at, #size) }
+        }
+        ParamType::Int(_) => quote! { #decoder.big_int(#at) },
+        ParamType::Uint(_) => quote! { #decoder.big_uint(#at) },
+        ParamType::Bool => quote! { #decoder.bool(#at) },
+        ParamType::String => quote! { #decoder.string(#at) },
+        ParamType::Tuple(_) if struct_ident(meta).is_some() => {
+            let name = struct_ident(meta);
+            quote! { super::structs::#name::decode_tuple(#decoder, #at) }
+        }
         _ => {
-            let decode_topic = quote! {
-                        ethabi::decode(&[#syntax_type], #data_token)
-                        .map_err(|e| format!(#error_msg, e))?
-                        .pop()
-                        .expect(INTERNAL_ERR)
-            };
+            let decode = decode_value(kind, meta, decoder, at);
+            quote! { Ok(#decode) }
+        }
+    }
+}
 
-            from_token(kind, &decode_topic)
+/// A value decoded by the borrowed views of generated events, see [decode_borrowed].
+struct BorrowedValue {
+    /// The Rust type of the value, borrowing the decoded data for the `'a` lifetime.
+    kind: proc_macro2::TokenStream,
+    /// The `Result<_, substreams_ethereum::DecodeError>` expression of the value.
+    decode: proc_macro2::TokenStream,
+    /// The method call converting the value into its [rust_type], if any.
+    to_owned: proc_macro2::TokenStream,
+}
+
+/// Same as [decode_result] but untyped `address`, `bytes` and `string` values borrow the data
+/// of `decoder` instead of being copied, values of other types are decoded owned.
+fn decode_borrowed(
+    kind: &ParamType,
+    meta: &TypeMeta,
+    decoder: &proc_macro2::TokenStream,
+    at: &Position,
+) -> BorrowedValue {
+    match *kind {
+        ParamType::Address if !meta.options.typed_scalars => BorrowedValue {
+            kind: quote! { &'a [u8] },
+            decode: quote! { #decoder.word(#at).map(|word| &word[12..]) },
+            to_owned: quote! { .to_vec() },
+        },
+        ParamType::Bytes => BorrowedValue {
+            kind: quote! { &'a [u8] },
+            decode: quote! { #decoder.bytes(#at) },
+            to_owned: quote! { .to_vec() },
+        },
+        ParamType::String => BorrowedValue {
+            kind: quote! { std::borrow::Cow<'a, str> },
+            decode: quote! { #decoder.bytes(#at).map(String::from_utf8_lossy) },
+            to_owned: quote! { .into_owned() },
+        },
+        _ => BorrowedValue {
+            kind: rust_type(kind, meta),
+            decode: decode_result(kind, meta, decoder, at),
+            to_owned: quote! {},
+        },
+    }
+}
+
+/// Generates the expressions of the values of the tuple of `kinds` whose head starts at `at` of
+/// `decoder`, see [decode_value].
+fn decode_tuple<'a, I>(
+    kinds: I,
+    decoder: &proc_macro2::TokenStream,
+    at: &Position,
+) -> Vec<proc_macro2::TokenStream>
+where
+    I: IntoIterator<Item = (&'a ParamType, &'a TypeMeta)>,
+{
+    let mut offset = 0;
+    kinds
+        .into_iter()
+        .map(|(kind, meta)| {
+            let decode = decode_value(kind, meta, decoder, &at.add(offset));
+            offset += head_size(kind);
+            decode
+        })
+        .collect()
+}
+
+/// Generates the expression of the value of the indexed event parameter `name` found in the
+/// topic at `topic_index` of `log`.
+fn decode_topic(
+    name: &str,
+    kind: &ParamType,
+    meta: &TypeMeta,
+    topic_index: usize,
+) -> proc_macro2::TokenStream {
+    let kind_name = kind.to_string();
+    let decode = decode_value(kind, meta, &quote! { topic }, &Position::at(0));
+
+    quote! {
+        {
+            let topic = substreams_ethereum::abi::Decoder::topic(&log.topics[#topic_index], #name, #kind_name, #topic_index);
+            #decode
         }
     }
 }
@@ -435,28 +692,80 @@ fn param_names(inputs: &[Param]) -> Vec<syn::Ident> {
         .collect()
 }
 
-// fn get_template_names(kinds: &[proc_macro2::TokenStream]) -> Vec<syn::Ident> {
-//     kinds
-//         .iter()
-//         .enumerate()
-//         .map(|(index, _)| syn::Ident::new(&format!("T{}", index), Span::call_site()))
-//         .collect()
-// }
-
-fn get_output_kinds(outputs: &[Param]) -> proc_macro2::TokenStream {
+fn get_output_kinds(outputs: &[Param], meta: &EntryMeta) -> proc_macro2::TokenStream {
     match outputs.len() {
         0 => quote! {()},
         1 => {
-            let t = rust_type(&outputs[0].kind);
+            let t = rust_type(&outputs[0].kind, meta.output(0));
             quote! { #t }
         }
         _ => {
-            let outs: Vec<_> = outputs.iter().map(|param| rust_type(&param.kind)).collect();
+            let outs: Vec<_> = outputs
+                .iter()
+                .enumerate()
+                .map(|(i, param)| rust_type(&param.kind, meta.output(i)))
+                .collect();
             quote! { (#(#outs),*) }
         }
     }
 }
 
+/// Generates the arms of the `match` on the identifier of the entries (topic of events, method id
+/// of functions) used by the `Events` and `Functions` enums. Entries sharing the same identifier,
+/// like overloads differing only by their outputs, are tried in order.
+fn dispatch_arms(
+    enum_name: &syn::Ident,
+    id_const: &syn::Ident,
+    value: &proc_macro2::TokenStream,
+    entries: &[(&[u8], syn::Ident)],
+) -> Vec<proc_macro2::TokenStream> {
+    let mut groups: Vec<(&[u8], Vec<&syn::Ident>)> = Vec::new();
+    for (id, variant) in entries {
+        match groups.iter_mut().find(|(existing, _)| existing == id) {
+            Some((_, variants)) => variants.push(variant),
+            None => groups.push((id, vec![variant])),
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(_, variants)| {
+            let first = variants[0];
+            let others = &variants[1..];
+
+            quote! {
+                #first::#id_const => #first::match_and_decode(#value).map(#enum_name::#first)
+                    #(.or_else(|| #others::match_and_decode(#value).map(#enum_name::#others)))*,
+            }
+        })
+        .collect()
+}
+
+/// The canonical signature of an event, function or error, e.g.
+/// `Transfer(address,address,uint256)`.
+fn canonical_signature<'a, I: IntoIterator<Item = &'a ParamType>>(name: &str, kinds: I) -> String {
+    let types: Vec<_> = kinds.into_iter().map(ToString::to_string).collect();
+    format!("{}({})", name, types.join(","))
+}
+
+/// Generates the `SIGNATURE`, `PARAM_NAMES` and `PARAM_TYPES` constants of an event or
+/// function, the canonical signature is the one hashed into its topic or method id, e.g.
+/// `Transfer(address,address,uint256)`.
+fn signature_constants<'a, I>(name: &str, params: I) -> proc_macro2::TokenStream
+where
+    I: IntoIterator<Item = (&'a String, &'a ParamType)>,
+{
+    let (names, kinds): (Vec<_>, Vec<_>) = params.into_iter().unzip();
+    let signature = canonical_signature(name, kinds.iter().copied());
+    let types = kinds.iter().map(|kind| kind.to_string());
+
+    quote! {
+        pub const SIGNATURE: &'static str = #signature;
+        pub const PARAM_NAMES: &'static [&'static str] = &[#(#names),*];
+        pub const PARAM_TYPES: &'static [&'static str] = &[#(#types),*];
+    }
+}
+
 /// Convert input into a rust variable name.
 ///
 /// Avoid using keywords by escaping them.
@@ -471,8 +780,13 @@ fn rust_variable(name: &str) -> String {
 #[cfg(test)]
 mod tests {
     use ethabi::ParamType;
+    use quote::quote;
 
-    use crate::{fixed_data_size, min_data_size};
+    use crate::{
+        fixed_data_size,
+        meta::{TypeMeta, TypeOptions},
+        min_data_size, rust_type,
+    };
 
     #[test]
     fn from_firehose_types_to_ethabi_token() {
@@ -533,4 +847,82 @@ mod tests {
             assert_eq!(min_data_size(&actual), expected, "test case {}", name);
         }
     }
+
+    #[test]
+    fn it_rust_type_typed_scalars_works() {
+        let meta = TypeMeta {
+            options: TypeOptions {
+                typed_scalars: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let inputs = vec![
+            (
+                ParamType::Address,
+                quote! { substreams_ethereum::scalar::Address },
+            ),
+            (
+                ParamType::Array(Box::new(ParamType::Address)),
+                quote! { Vec<substreams_ethereum::scalar::Address> },
+            ),
+            (
+                ParamType::FixedBytes(32),
+                quote! { substreams_ethereum::scalar::H256 },
+            ),
+            (ParamType::FixedBytes(4), quote! { [u8; 4usize] }),
+        ];
+
+        for (kind, expected) in inputs {
+            assert_eq!(
+                rust_type(&kind, &meta).to_string(),
+                expected.to_string(),
+                "test case {}",
+                kind
+            );
+        }
+    }
+
+    #[test]
+    fn it_rust_type_native_ints_works() {
+        let native = TypeMeta::default();
+        let big_int = TypeMeta {
+            options: TypeOptions {
+                native_ints: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let inputs = vec![
+            (ParamType::Uint(8), &native, quote! { u8 }),
+            (ParamType::Int(24), &native, quote! { i32 }),
+            (ParamType::Uint(128), &native, quote! { u128 }),
+            (
+                ParamType::Uint(160),
+                &native,
+                quote! { substreams::scalar::BigInt },
+            ),
+            (
+                ParamType::Array(Box::new(ParamType::Int(64))),
+                &native,
+                quote! { Vec<i64> },
+            ),
+            (
+                ParamType::Uint(8),
+                &big_int,
+                quote! { substreams::scalar::BigInt },
+            ),
+        ];
+
+        for (kind, meta, expected) in inputs {
+            assert_eq!(
+                rust_type(&kind, meta).to_string(),
+                expected.to_string(),
+                "test case {}",
+                kind
+            );
+        }
+    }
 }
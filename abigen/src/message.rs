@@ -0,0 +1,404 @@
+use anyhow::format_err;
+use ethabi::ParamType;
+use heck::ToUpperCamelCase;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+use crate::{enum_ident, meta::TypeMeta, native_int, struct_ident};
+
+/// A parameter of a generated type, kept to generate the `prost::Message` version of the type
+/// and to find the structs and enums the type uses.
+#[derive(Debug, Clone)]
+pub(crate) struct Param {
+    name: syn::Ident,
+    kind: ParamType,
+    meta: TypeMeta,
+}
+
+impl Param {
+    pub(crate) fn new(name: &syn::Ident, kind: &ParamType, meta: &TypeMeta) -> Self {
+        Param {
+            name: name.clone(),
+            kind: kind.clone(),
+            meta: meta.clone(),
+        }
+    }
+
+    /// The generated structs and enums used by the parameter, as `(module, name)`. Structs
+    /// used by those structs are not included.
+    pub(crate) fn dependencies(&self) -> Vec<(&'static str, String)> {
+        fn collect(meta: &TypeMeta, dependencies: &mut Vec<(&'static str, String)>) {
+            if let Some(name) = &meta.struct_name {
+                dependencies.push(("structs", name.clone()));
+            } else if let Some(e) = &meta.enum_meta {
+                dependencies.push(("enums", e.name.clone()));
+            } else {
+                for (_, component) in meta.components.iter() {
+                    collect(component, dependencies);
+                }
+            }
+        }
+
+        let mut dependencies = Vec::new();
+        collect(&self.meta, &mut dependencies);
+        dependencies
+    }
+}
+
+/// Structure used to generate the `prost::Message` version of a generated event, function or
+/// struct, it lives in the `pb` module next to the type along with a `From` conversion.
+pub struct Message {
+    name: String,
+    fields: Vec<TokenStream>,
+    conversions: Vec<TokenStream>,
+    /// Fields of the `.proto` definition of the message, e.g. `repeated bytes owners = 1;`.
+    proto_fields: Vec<String>,
+}
+
+impl Message {
+    /// Fails when a parameter has no protobuf equivalent, like nested arrays and tuples that
+    /// are not Solidity structs.
+    pub(crate) fn new(name: &str, params: &[Param]) -> Result<Self, anyhow::Error> {
+        let mut fields = Vec::with_capacity(params.len());
+        let mut conversions = Vec::with_capacity(params.len());
+        let mut proto_fields = Vec::with_capacity(params.len());
+
+        for (i, param) in params.iter().enumerate() {
+            let field = &param.name;
+            let value = quote! { value.#field };
+            let (prost_type, label, conversion) = prost_field(&param.kind, &param.meta, &value)
+                .ok_or_else(|| {
+                    format_err!(
+                        "parameter '{}' of '{}' has type '{}' which has no protobuf equivalent",
+                        field,
+                        name,
+                        param.kind
+                    )
+                })?;
+            let tag = (i + 1).to_string();
+            let ProstType {
+                kind,
+                rust_kind,
+                proto,
+            } = prost_type;
+
+            fields.push(quote! {
+                #[prost(#kind, tag = #tag)]
+                pub #field: #rust_kind
+            });
+            conversions.push(quote! { #field: #conversion });
+            proto_fields.push(format!(
+                "{}{} {} = {};",
+                label,
+                proto,
+                field.to_string().trim_start_matches('_'),
+                tag
+            ));
+        }
+
+        Ok(Message {
+            name: name.to_upper_camel_case(),
+            fields,
+            conversions,
+            proto_fields,
+        })
+    }
+
+    /// Generates the `.proto` definition of the message, nested in the message of its module
+    /// hence indented by `indent`.
+    pub(crate) fn generate_proto(&self, indent: &str) -> String {
+        let mut proto = format!("{}message {} {{\n", indent, self.name);
+        for field in self.proto_fields.iter() {
+            proto.push_str(&format!("{}  {}\n", indent, field));
+        }
+        proto.push_str(&format!("{}}}\n", indent));
+        proto
+    }
+
+    /// Generates the `prost::Message` struct and its conversion from the generated type.
+    pub fn generate(&self) -> TokenStream {
+        let name = syn::Ident::new(&self.name, Span::call_site());
+        let fields = &self.fields;
+        let conversions = &self.conversions;
+
+        quote! {
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct #name {
+                #(#fields),*
+            }
+
+            impl From<super::#name> for #name {
+                fn from(value: super::#name) -> Self {
+                    #name {
+                        #(#conversions),*
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A field type of a `prost::Message`: its `prost` attribute kind, its Rust type and its name
+/// in the `.proto` schema.
+struct ProstType {
+    kind: TokenStream,
+    rust_kind: TokenStream,
+    proto: String,
+}
+
+impl ProstType {
+    /// A protobuf scalar whose Rust type is a primitive of the same name, like `uint32`.
+    fn primitive(name: &str, rust_kind: TokenStream) -> Self {
+        let ident = syn::Ident::new(name, Span::call_site());
+
+        ProstType {
+            kind: quote! { #ident },
+            rust_kind,
+            proto: name.to_string(),
+        }
+    }
+
+    fn bytes() -> Self {
+        ProstType {
+            kind: quote! { bytes = "vec" },
+            rust_kind: quote! { Vec<u8> },
+            proto: "bytes".to_string(),
+        }
+    }
+
+    fn string() -> Self {
+        ProstType::primitive("string", quote! { String })
+    }
+}
+
+/// The `prost` type of the field holding `value` of type `kind`, its label in the `.proto`
+/// schema and the expression converting `value` into it. Arrays are repeated fields and
+/// Solidity structs are optional messages.
+fn prost_field(
+    kind: &ParamType,
+    meta: &TypeMeta,
+    value: &TokenStream,
+) -> Option<(ProstType, &'static str, TokenStream)> {
+    match *kind {
+        ParamType::Array(ref inner) | ParamType::FixedArray(ref inner, _) => {
+            let element = quote! { inner };
+            let (inner_type, conversion) = prost_scalar(inner, meta, &element)?;
+            let conversion = match (conversion, kind) {
+                (Some(conversion), _) => {
+                    quote! { #value.into_iter().map(|#element| #conversion).collect() }
+                }
+                (None, ParamType::Array(_)) => quote! { #value },
+                (None, _) => quote! { #value.to_vec() },
+            };
+            let ProstType {
+                kind,
+                rust_kind,
+                proto,
+            } = inner_type;
+
+            Some((
+                ProstType {
+                    kind: quote! { #kind, repeated },
+                    rust_kind: quote! { Vec<#rust_kind> },
+                    proto,
+                },
+                "repeated ",
+                conversion,
+            ))
+        }
+        _ => {
+            let (prost_type, conversion) = prost_scalar(kind, meta, value)?;
+            let conversion = conversion.unwrap_or_else(|| value.clone());
+
+            match struct_ident(meta) {
+                Some(_) => {
+                    let ProstType {
+                        kind,
+                        rust_kind,
+                        proto,
+                    } = prost_type;
+
+                    Some((
+                        ProstType {
+                            kind: quote! { #kind, optional },
+                            rust_kind: quote! { Option<#rust_kind> },
+                            proto,
+                        },
+                        "",
+                        quote! { Some(#conversion) },
+                    ))
+                }
+                None => Some((prost_type, "", conversion)),
+            }
+        }
+    }
+}
+
+/// The `prost` type of a single value of type `kind` and the expression converting `value`
+/// into it, `None` when it is the value itself.
+fn prost_scalar(
+    kind: &ParamType,
+    meta: &TypeMeta,
+    value: &TokenStream,
+) -> Option<(ProstType, Option<TokenStream>)> {
+    let uint32 = || ProstType::primitive("uint32", quote! { u32 });
+    let int32 = || ProstType::primitive("int32", quote! { i32 });
+
+    let scalar = match *kind {
+        ParamType::Address if meta.options.typed_scalars => {
+            (ProstType::bytes(), Some(quote! { #value.to_vec() }))
+        }
+        ParamType::Address | ParamType::Bytes => (ProstType::bytes(), None),
+        ParamType::FixedBytes(_) => (ProstType::bytes(), Some(quote! { #value.to_vec() })),
+        ParamType::Uint(_) if enum_ident(meta).is_some() => {
+            let conversion = match meta.enum_meta.as_ref().map(|e| e.variants.is_empty()) {
+                Some(false) => quote! { u32::from(u8::from(#value)) },
+                _ => quote! { u32::from(#value) },
+            };
+
+            (uint32(), Some(conversion))
+        }
+        ParamType::Int(_) | ParamType::Uint(_) => match native_int(kind, meta) {
+            Some(native) => match native.to_string().as_str() {
+                "u8" | "u16" => (uint32(), Some(quote! { u32::from(#value) })),
+                "i8" | "i16" => (int32(), Some(quote! { i32::from(#value) })),
+                "u32" => (uint32(), None),
+                "i32" => (int32(), None),
+                "u64" => (ProstType::primitive("uint64", quote! { u64 }), None),
+                "i64" => (ProstType::primitive("int64", quote! { i64 }), None),
+                _ => (ProstType::string(), Some(quote! { #value.to_string() })),
+            },
+            None => (ProstType::string(), Some(quote! { #value.to_string() })),
+        },
+        ParamType::Bool => (ProstType::primitive("bool", quote! { bool }), None),
+        ParamType::String => (ProstType::string(), None),
+        ParamType::Tuple(_) => {
+            let name = struct_ident(meta)?;
+            let prost_type = ProstType {
+                kind: quote! { message },
+                rust_kind: quote! { super::super::structs::pb::#name },
+                proto: format!("Structs.{}", name),
+            };
+
+            (prost_type, Some(quote! { #value.into() }))
+        }
+        ParamType::Array(_) | ParamType::FixedArray(..) => return None,
+    };
+
+    Some(scalar)
+}
+
+#[cfg(test)]
+mod tests {
+    use ethabi::ParamType;
+    use quote::quote;
+
+    use super::{Message, Param};
+    use crate::{
+        assertions::assert_ast_eq,
+        meta::{TypeMeta, TypeOptions},
+    };
+
+    #[test]
+    fn test_message() {
+        let native = TypeMeta::default();
+        let big_int = TypeMeta {
+            options: TypeOptions {
+                native_ints: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let order = TypeMeta {
+            struct_name: Some("Order".to_string()),
+            ..Default::default()
+        };
+        let ident = |name: &str| syn::Ident::new(name, proc_macro2::Span::call_site());
+
+        let message = Message::new(
+            "Transfer",
+            &[
+                Param::new(&ident("from"), &ParamType::Address, &native),
+                Param::new(&ident("value"), &ParamType::Uint(256), &big_int),
+                Param::new(&ident("fee"), &ParamType::Uint(16), &native),
+                Param::new(
+                    &ident("hashes"),
+                    &ParamType::FixedArray(Box::new(ParamType::FixedBytes(32)), 2),
+                    &native,
+                ),
+                Param::new(
+                    &ident("order"),
+                    &ParamType::Tuple(vec![ParamType::Bool]),
+                    &order,
+                ),
+                Param::new(
+                    &ident("orders"),
+                    &ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Bool]))),
+                    &order,
+                ),
+            ],
+        )
+        .unwrap();
+
+        assert_ast_eq(
+            message.generate(),
+            quote! {
+                #[derive(Clone, PartialEq, ::prost::Message)]
+                pub struct Transfer {
+                    #[prost(bytes = "vec", tag = "1")]
+                    pub from: Vec<u8>,
+                    #[prost(string, tag = "2")]
+                    pub value: String,
+                    #[prost(uint32, tag = "3")]
+                    pub fee: u32,
+                    #[prost(bytes = "vec", repeated, tag = "4")]
+                    pub hashes: Vec<Vec<u8> >,
+                    #[prost(message, optional, tag = "5")]
+                    pub order: Option<super::super::structs::pb::Order>,
+                    #[prost(message, repeated, tag = "6")]
+                    pub orders: Vec<super::super::structs::pb::Order>
+                }
+                impl From<super::Transfer> for Transfer {
+                    fn from(value: super::Transfer) -> Self {
+                        Transfer {
+                            from: value.from,
+                            value: value.value.to_string(),
+                            fee: u32::from(value.fee),
+                            hashes: value.hashes.into_iter().map(|inner| inner.to_vec()).collect(),
+                            order: Some(value.order.into()),
+                            orders: value.orders.into_iter().map(|inner| inner.into()).collect()
+                        }
+                    }
+                }
+            },
+        );
+        assert_eq!(
+            message.generate_proto(""),
+            [
+                "message Transfer {",
+                "  bytes from = 1;",
+                "  string value = 2;",
+                "  uint32 fee = 3;",
+                "  repeated bytes hashes = 4;",
+                "  Structs.Order order = 5;",
+                "  repeated Structs.Order orders = 6;",
+                "}\n",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_message_unsupported_type() {
+        let nested = ParamType::Array(Box::new(ParamType::Array(Box::new(ParamType::Bool))));
+        let name = syn::Ident::new("flags", proc_macro2::Span::call_site());
+
+        let err = Message::new("Flags", &[Param::new(&name, &nested, &TypeMeta::default())])
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "parameter 'flags' of 'Flags' has type 'bool[][]' which has no protobuf equivalent"
+        );
+    }
+}
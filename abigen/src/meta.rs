@@ -0,0 +1,391 @@
+use std::collections::{BTreeMap, HashMap};
+
+use ethabi::{param_type::Reader, ParamType};
+use heck::ToUpperCamelCase;
+use serde_json::Value;
+
+/// Options of [crate::build::Abigen] changing the Rust type generated for parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TypeOptions {
+    /// Generates `substreams_ethereum::scalar::Address` for `address` and
+    /// `substreams_ethereum::scalar::H256` for `bytes32` instead of raw bytes.
+    pub(crate) typed_scalars: bool,
+    /// Generates native Rust integers for `int` and `uint` of up to 128 bits instead of
+    /// `substreams::scalar::BigInt`.
+    pub(crate) native_ints: bool,
+    /// Serializes bytes and big integers of the generated fields as strings with
+    /// `substreams_ethereum::abi::serde`.
+    pub(crate) serde: bool,
+}
+
+impl TypeOptions {
+    pub(crate) const DEFAULT: TypeOptions = TypeOptions {
+        typed_scalars: false,
+        native_ints: true,
+        serde: false,
+    };
+}
+
+impl Default for TypeOptions {
+    fn default() -> Self {
+        TypeOptions::DEFAULT
+    }
+}
+
+/// Solidity details of a parameter that `ethabi` drops when loading a JSON ABI, namely the
+/// names of tuple components and the `internalType` of the parameter.
+///
+/// The structure mirrors the parameter's `ethabi::ParamType`, an array shares the details of
+/// its element type so `components` of a `tuple[]` describes the tuple element.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct TypeMeta {
+    /// The ABI type as written in the JSON, e.g. `tuple[]` for an array of tuples.
+    pub(crate) kind: String,
+    /// The Solidity type, e.g. `struct SpentItem[]` for an array of `SpentItem` structs.
+    pub(crate) internal_type: Option<String>,
+    /// The named components of the tuple found at this level.
+    pub(crate) components: Vec<(String, TypeMeta)>,
+    /// The Rust struct generated for the tuple, resolved by [AbiMeta::resolve_structs].
+    pub(crate) struct_name: Option<String>,
+    /// The Solidity enum of the `uint8`, resolved by [AbiMeta::resolve_enums].
+    pub(crate) enum_meta: Option<EnumMeta>,
+    /// The generation options, set by [AbiMeta::set_options].
+    pub(crate) options: TypeOptions,
+}
+
+pub(crate) static EMPTY_META: TypeMeta = TypeMeta {
+    kind: String::new(),
+    internal_type: None,
+    components: Vec::new(),
+    struct_name: None,
+    enum_meta: None,
+    options: TypeOptions::DEFAULT,
+};
+
+impl TypeMeta {
+    fn parse(value: &Value) -> TypeMeta {
+        TypeMeta {
+            kind: value["type"].as_str().unwrap_or_default().to_string(),
+            internal_type: value["internalType"].as_str().map(ToString::to_string),
+            components: value["components"]
+                .as_array()
+                .map(|components| {
+                    components
+                        .iter()
+                        .map(|component| {
+                            (
+                                component["name"].as_str().unwrap_or_default().to_string(),
+                                TypeMeta::parse(component),
+                            )
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            struct_name: None,
+            enum_meta: None,
+            options: TypeOptions::default(),
+        }
+    }
+
+    /// Details of the tuple component at `index`, empty details if unknown.
+    pub(crate) fn component(&self, index: usize) -> &TypeMeta {
+        self.components
+            .get(index)
+            .map(|(_, meta)| meta)
+            .unwrap_or(&EMPTY_META)
+    }
+
+    /// The Solidity struct name of the tuple, `struct Lib.SpentItem[]` gives `Lib.SpentItem`.
+    ///
+    /// Only tuples whose components are all named can be turned into a struct.
+    fn solidity_struct(&self) -> Option<&str> {
+        if self.components.is_empty() || self.components.iter().any(|(name, _)| name.is_empty()) {
+            return None;
+        }
+
+        let name = self.internal_type.as_ref()?.strip_prefix("struct ")?;
+        Some(name.split('[').next().unwrap_or(name))
+    }
+
+    /// The Solidity enum name of the `uint8`, `enum Lib.ItemType[]` gives `ItemType`.
+    fn solidity_enum(&self) -> Option<&str> {
+        if !self.kind.starts_with("uint8") {
+            return None;
+        }
+
+        let name = self.internal_type.as_ref()?.strip_prefix("enum ")?;
+        let name = name.split('[').next().unwrap_or(name);
+        Some(name.rsplit('.').next().unwrap_or(name))
+    }
+
+    /// The canonical ABI type with tuples expanded, `(uint8,uint256)[]` for `tuple[]`.
+    fn canonical(&self) -> String {
+        match self.kind.strip_prefix("tuple") {
+            Some(suffix) => {
+                let components: Vec<_> = self
+                    .components
+                    .iter()
+                    .map(|(_, meta)| meta.canonical())
+                    .collect();
+
+                format!("({}){}", components.join(","), suffix)
+            }
+            None => self.kind.clone(),
+        }
+    }
+
+    /// Identifies the struct of the tuple: its components' names and types.
+    fn shape(&self) -> String {
+        let components: Vec<_> = self
+            .components
+            .iter()
+            .map(|(name, meta)| format!("{} {}", meta.canonical(), name))
+            .collect();
+
+        components.join(",")
+    }
+
+    pub(crate) fn param_type(&self) -> Result<ParamType, ethabi::Error> {
+        Reader::read(&self.canonical())
+    }
+
+    fn visit_mut<F: FnMut(&mut TypeMeta)>(&mut self, f: &mut F) {
+        for (_, component) in self.components.iter_mut() {
+            component.visit_mut(f);
+        }
+
+        f(self);
+    }
+
+    fn visit_tuples_mut<F: FnMut(&mut TypeMeta)>(&mut self, f: &mut F) {
+        for (_, component) in self.components.iter_mut() {
+            component.visit_tuples_mut(f);
+        }
+
+        if !self.components.is_empty() {
+            f(self);
+        }
+    }
+}
+
+/// Details of one ABI entry (event, function, error or constructor).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct EntryMeta {
+    pub(crate) inputs: Vec<TypeMeta>,
+    pub(crate) outputs: Vec<TypeMeta>,
+}
+
+impl EntryMeta {
+    pub(crate) fn input(&self, index: usize) -> &TypeMeta {
+        self.inputs.get(index).unwrap_or(&EMPTY_META)
+    }
+
+    pub(crate) fn output(&self, index: usize) -> &TypeMeta {
+        self.outputs.get(index).unwrap_or(&EMPTY_META)
+    }
+
+    fn metas_mut(&mut self) -> impl Iterator<Item = &mut TypeMeta> {
+        self.inputs.iter_mut().chain(self.outputs.iter_mut())
+    }
+}
+
+pub(crate) static EMPTY_ENTRY: EntryMeta = EntryMeta {
+    inputs: Vec::new(),
+    outputs: Vec::new(),
+};
+
+/// A Solidity struct found in the ABI along with the Rust name chosen for it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct StructMeta {
+    pub(crate) name: String,
+    pub(crate) fields: Vec<(String, TypeMeta)>,
+}
+
+/// A Solidity enum found in the ABI, its `variants` are empty unless declared through
+/// [crate::build::Abigen::enum_variants] as the JSON ABI does not list them.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct EnumMeta {
+    pub(crate) name: String,
+    pub(crate) variants: Vec<String>,
+}
+
+/// Details of all entries of a JSON ABI, keyed by entry type (`event`, `function`, ...) and
+/// name. Overloaded entries are kept in ABI order which is the same order used by `ethabi`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct AbiMeta {
+    entries: BTreeMap<(String, String), Vec<EntryMeta>>,
+    options: TypeOptions,
+    enum_variants: BTreeMap<String, Vec<String>>,
+}
+
+impl AbiMeta {
+    pub(crate) fn parse(bytes: &[u8]) -> Result<AbiMeta, anyhow::Error> {
+        let value: Value = serde_json::from_slice(bytes)?;
+
+        let mut entries: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for entry in value.as_array().into_iter().flatten() {
+            let kind = entry["type"].as_str().unwrap_or("function").to_string();
+            let name = entry["name"].as_str().unwrap_or_default().to_string();
+            let params = |key: &str| -> Vec<TypeMeta> {
+                entry[key]
+                    .as_array()
+                    .map(|params| params.iter().map(TypeMeta::parse).collect())
+                    .unwrap_or_default()
+            };
+
+            entries.entry((kind, name)).or_default().push(EntryMeta {
+                inputs: params("inputs"),
+                outputs: params("outputs"),
+            });
+        }
+
+        Ok(AbiMeta {
+            entries,
+            options: TypeOptions::default(),
+            enum_variants: BTreeMap::new(),
+        })
+    }
+
+    pub(crate) fn options(&self) -> TypeOptions {
+        self.options
+    }
+
+    /// Sets the generation options of every parameter, must be called before
+    /// [AbiMeta::resolve_structs] for struct fields to get them.
+    pub(crate) fn set_options(&mut self, options: TypeOptions) {
+        self.options = options;
+        for meta in self
+            .entries
+            .values_mut()
+            .flatten()
+            .flat_map(EntryMeta::metas_mut)
+        {
+            meta.visit_mut(&mut |meta| meta.options = options);
+        }
+    }
+
+    /// Sets the variants of the Solidity enums keyed by enum name, without qualifier.
+    pub(crate) fn set_enum_variants(&mut self, variants: BTreeMap<String, Vec<String>>) {
+        self.enum_variants = variants;
+    }
+
+    /// Assigns its Solidity enum to every `uint8` declared as such and returns the
+    /// deduplicated list of enums to generate, sorted by name. Must be called before
+    /// [AbiMeta::resolve_structs] for struct fields to get them.
+    pub(crate) fn resolve_enums(&mut self) -> Vec<EnumMeta> {
+        let mut enums: BTreeMap<String, EnumMeta> = BTreeMap::new();
+        let enum_variants = &self.enum_variants;
+        for meta in self
+            .entries
+            .values_mut()
+            .flatten()
+            .flat_map(EntryMeta::metas_mut)
+        {
+            meta.visit_mut(&mut |meta| {
+                if let Some(name) = meta.solidity_enum() {
+                    let name = name.to_upper_camel_case();
+                    let enum_meta = EnumMeta {
+                        variants: enum_variants.get(&name).cloned().unwrap_or_default(),
+                        name: name.clone(),
+                    };
+
+                    enums.entry(name).or_insert_with(|| enum_meta.clone());
+                    meta.enum_meta = Some(enum_meta);
+                }
+            });
+        }
+
+        enums.into_values().collect()
+    }
+
+    /// Drops the details of the entries of type `kind` named `name` for which `keep` is false.
+    pub(crate) fn retain<F: FnMut(&str, &str) -> bool>(&mut self, mut keep: F) {
+        self.entries.retain(|(kind, name), _| keep(kind, name));
+    }
+
+    /// Details of the `index`-th overload of entry `name`, empty details if unknown.
+    pub(crate) fn entry(&self, kind: &str, name: &str, index: usize) -> &EntryMeta {
+        self.entries
+            .get(&(kind.to_string(), name.to_string()))
+            .and_then(|entries| entries.get(index))
+            .unwrap_or(&EMPTY_ENTRY)
+    }
+
+    /// Assigns a Rust struct name to every tuple declared as a Solidity struct and returns
+    /// the deduplicated list of structs to generate, sorted by name.
+    ///
+    /// A struct is named after its Solidity name without the contract/library qualifier.
+    /// When two different structs end up with the same name, the qualified name is used
+    /// instead, suffixed by a counter if still ambiguous.
+    pub(crate) fn resolve_structs(&mut self) -> Vec<StructMeta> {
+        let mut definitions: BTreeMap<(String, String), TypeMeta> = BTreeMap::new();
+        for meta in self
+            .entries
+            .values_mut()
+            .flatten()
+            .flat_map(EntryMeta::metas_mut)
+        {
+            meta.visit_tuples_mut(&mut |tuple| {
+                if let Some(name) = tuple.solidity_struct() {
+                    definitions
+                        .entry((name.to_string(), tuple.shape()))
+                        .or_insert_with(|| tuple.clone());
+                }
+            });
+        }
+
+        let mut names: HashMap<(String, String), String> = HashMap::new();
+        let mut shapes_by_name: HashMap<String, String> = HashMap::new();
+        for (solidity_name, shape) in definitions.keys() {
+            let short_name = solidity_name
+                .rsplit('.')
+                .next()
+                .unwrap_or(solidity_name)
+                .to_upper_camel_case();
+
+            let qualified_name = solidity_name.to_upper_camel_case();
+            let name = std::iter::once(short_name)
+                .chain(std::iter::once(qualified_name.clone()))
+                .chain((2..).map(|counter| format!("{}{}", qualified_name, counter)))
+                .find(|candidate| match shapes_by_name.get(candidate) {
+                    Some(existing) => existing == shape,
+                    None => true,
+                })
+                .expect("candidates are infinite");
+
+            shapes_by_name.insert(name.clone(), shape.clone());
+            names.insert((solidity_name.clone(), shape.clone()), name);
+        }
+
+        let mut resolve = |tuple: &mut TypeMeta| {
+            if let Some(solidity_name) = tuple.solidity_struct() {
+                let key = (solidity_name.to_string(), tuple.shape());
+                tuple.struct_name = names.get(&key).cloned();
+            }
+        };
+
+        for meta in self
+            .entries
+            .values_mut()
+            .flatten()
+            .flat_map(EntryMeta::metas_mut)
+        {
+            meta.visit_tuples_mut(&mut resolve);
+        }
+
+        let mut structs: BTreeMap<String, StructMeta> = BTreeMap::new();
+        for (key, mut tuple) in definitions {
+            let name = names[&key].clone();
+            for (_, component) in tuple.components.iter_mut() {
+                component.visit_tuples_mut(&mut resolve);
+            }
+
+            structs.entry(name.clone()).or_insert(StructMeta {
+                name,
+                fields: tuple.components,
+            });
+        }
+
+        structs.into_values().collect()
+    }
+}
@@ -0,0 +1,339 @@
+use anyhow::format_err;
+use serde_json::{json, Map, Value};
+
+/// Converts human-readable Solidity fragments, like
+/// `event Transfer(address indexed from, address indexed to, uint256 value)` or
+/// `function balanceOf(address) view returns (uint256)`, into the JSON ABI they describe.
+///
+/// Supported fragments are `constructor`, `function`, `event`, `error`, `fallback` and
+/// `receive`. Parameters are a type, tuples being written `tuple(...)` or `(...)`, optionally
+/// followed by `indexed`, a data location and a name.
+pub(crate) fn parse_signatures<S: AsRef<str>>(signatures: &[S]) -> Result<Vec<u8>, anyhow::Error> {
+    let entries = signatures
+        .iter()
+        .map(|signature| {
+            let signature = signature.as_ref();
+            Parser::new(signature)
+                .entry()
+                .map_err(|e| format_err!("invalid signature '{}': {}", signature, e))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(serde_json::to_vec(&entries)?)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Open,
+    Close,
+    OpenBracket,
+    CloseBracket,
+    Comma,
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn new(signature: &str) -> Self {
+        let mut tokens = Vec::new();
+        let mut word = String::new();
+
+        for c in signature.trim().trim_end_matches(';').chars() {
+            let token = match c {
+                '(' => Some(Token::Open),
+                ')' => Some(Token::Close),
+                '[' => Some(Token::OpenBracket),
+                ']' => Some(Token::CloseBracket),
+                ',' => Some(Token::Comma),
+                c if c.is_whitespace() => None,
+                c => {
+                    word.push(c);
+                    continue;
+                }
+            };
+
+            if !word.is_empty() {
+                tokens.push(Token::Word(std::mem::take(&mut word)));
+            }
+            tokens.extend(token);
+        }
+        if !word.is_empty() {
+            tokens.push(Token::Word(word));
+        }
+
+        Parser {
+            tokens,
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), anyhow::Error> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            Some(token) => Err(format_err!("expected {:?}, found {:?}", expected, token)),
+            None => Err(format_err!(
+                "expected {:?}, found end of signature",
+                expected
+            )),
+        }
+    }
+
+    fn word(&mut self) -> Result<String, anyhow::Error> {
+        match self.next() {
+            Some(Token::Word(word)) => Ok(word),
+            Some(token) => Err(format_err!("expected a word, found {:?}", token)),
+            None => Err(format_err!("expected a word, found end of signature")),
+        }
+    }
+
+    /// Consumes the next token if it is the word `word`.
+    fn accept(&mut self, word: &str) -> bool {
+        if self.peek() == Some(&Token::Word(word.to_string())) {
+            self.position += 1;
+            return true;
+        }
+
+        false
+    }
+
+    /// Parses a whole fragment into its JSON ABI entry.
+    fn entry(&mut self) -> Result<Value, anyhow::Error> {
+        let kind = self.word()?;
+        let mut entry = Map::new();
+        entry.insert("type".to_string(), json!(kind));
+
+        match kind.as_str() {
+            "function" | "event" | "error" => {
+                entry.insert("name".to_string(), json!(self.word()?));
+            }
+            "constructor" | "fallback" | "receive" => {}
+            _ => return Err(format_err!("unknown fragment kind '{}'", kind)),
+        }
+
+        // `fallback` and `receive` may omit their empty parameters list
+        let inputs = match self.peek() {
+            Some(Token::Open) => self.params(kind == "event")?,
+            _ if kind == "fallback" || kind == "receive" => Vec::new(),
+            _ => return Err(format_err!("expected parameters")),
+        };
+        if kind != "fallback" && kind != "receive" {
+            entry.insert("inputs".to_string(), Value::Array(inputs));
+        }
+
+        let mut state_mutability = "nonpayable";
+        let mut outputs = Vec::new();
+        let mut anonymous = false;
+        while let Some(token) = self.next() {
+            match token {
+                Token::Word(word) => match word.as_str() {
+                    "view" | "pure" | "payable" | "nonpayable" if kind != "event" => {
+                        state_mutability = match word.as_str() {
+                            "view" => "view",
+                            "pure" => "pure",
+                            "payable" => "payable",
+                            _ => "nonpayable",
+                        };
+                    }
+                    "constant" if kind == "function" => state_mutability = "view",
+                    "returns" if kind == "function" => outputs = self.params(false)?,
+                    "anonymous" if kind == "event" => anonymous = true,
+                    "external" | "public" | "internal" | "private" | "virtual" | "override" => {}
+                    _ => return Err(format_err!("unexpected '{}'", word)),
+                },
+                token => return Err(format_err!("unexpected {:?}", token)),
+            }
+        }
+
+        match kind.as_str() {
+            "function" => {
+                entry.insert("outputs".to_string(), Value::Array(outputs));
+                entry.insert("stateMutability".to_string(), json!(state_mutability));
+            }
+            "constructor" | "fallback" | "receive" => {
+                entry.insert("stateMutability".to_string(), json!(state_mutability));
+            }
+            "event" => {
+                entry.insert("anonymous".to_string(), json!(anonymous));
+            }
+            _ => {}
+        }
+
+        Ok(Value::Object(entry))
+    }
+
+    /// Parses a parenthesized list of parameters, `indexed` is accepted for event ones.
+    fn params(&mut self, event: bool) -> Result<Vec<Value>, anyhow::Error> {
+        self.expect(Token::Open)?;
+
+        let mut params = Vec::new();
+        if self.peek() == Some(&Token::Close) {
+            self.position += 1;
+            return Ok(params);
+        }
+
+        loop {
+            params.push(self.param(event)?);
+
+            match self.next() {
+                Some(Token::Comma) => continue,
+                Some(Token::Close) => return Ok(params),
+                Some(token) => return Err(format_err!("expected ',' or ')', found {:?}", token)),
+                None => return Err(format_err!("expected ')', found end of signature")),
+            }
+        }
+    }
+
+    fn param(&mut self, event: bool) -> Result<Value, anyhow::Error> {
+        let mut param = Map::new();
+
+        let components = match self.peek() {
+            Some(Token::Open) => Some(self.params(false)?),
+            Some(Token::Word(word)) if word == "tuple" => {
+                self.position += 1;
+                Some(self.params(false)?)
+            }
+            _ => None,
+        };
+
+        let mut kind = match components {
+            Some(_) => "tuple".to_string(),
+            None => match self.word()?.as_str() {
+                "uint" => "uint256".to_string(),
+                "int" => "int256".to_string(),
+                "byte" => "bytes1".to_string(),
+                "address" => {
+                    self.accept("payable");
+                    "address".to_string()
+                }
+                word => word.to_string(),
+            },
+        };
+
+        while self.peek() == Some(&Token::OpenBracket) {
+            self.position += 1;
+            kind.push('[');
+            if let Some(Token::Word(size)) = self.peek() {
+                kind.push_str(size);
+                self.position += 1;
+            }
+            self.expect(Token::CloseBracket)?;
+            kind.push(']');
+        }
+
+        let indexed = event && self.accept("indexed");
+        while self.accept("memory") || self.accept("calldata") || self.accept("storage") {}
+
+        let name = match self.peek() {
+            Some(Token::Word(_)) => self.word()?,
+            _ => String::new(),
+        };
+
+        param.insert("name".to_string(), json!(name));
+        param.insert("type".to_string(), json!(kind));
+        if let Some(components) = components {
+            param.insert("components".to_string(), Value::Array(components));
+        }
+        if event {
+            param.insert("indexed".to_string(), json!(indexed));
+        }
+
+        Ok(Value::Object(param))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::parse_signatures;
+
+    fn parse(signatures: &[&str]) -> Value {
+        serde_json::from_slice(&parse_signatures(signatures).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_signatures() {
+        assert_eq!(
+            parse(&[
+                "event Transfer(address indexed from, address indexed to, uint256 value)",
+                "function balanceOf(address) view returns (uint256)",
+                "function fill((address maker, uint amount)[] calldata orders, bytes32 salt) external payable",
+                "error Unauthorized(address caller)",
+                "constructor(string memory name)",
+            ]),
+            json!([
+                {
+                    "type": "event",
+                    "name": "Transfer",
+                    "inputs": [
+                        { "name": "from", "type": "address", "indexed": true },
+                        { "name": "to", "type": "address", "indexed": true },
+                        { "name": "value", "type": "uint256", "indexed": false }
+                    ],
+                    "anonymous": false
+                },
+                {
+                    "type": "function",
+                    "name": "balanceOf",
+                    "inputs": [{ "name": "", "type": "address" }],
+                    "outputs": [{ "name": "", "type": "uint256" }],
+                    "stateMutability": "view"
+                },
+                {
+                    "type": "function",
+                    "name": "fill",
+                    "inputs": [
+                        {
+                            "name": "orders",
+                            "type": "tuple[]",
+                            "components": [
+                                { "name": "maker", "type": "address" },
+                                { "name": "amount", "type": "uint256" }
+                            ]
+                        },
+                        { "name": "salt", "type": "bytes32" }
+                    ],
+                    "outputs": [],
+                    "stateMutability": "payable"
+                },
+                {
+                    "type": "error",
+                    "name": "Unauthorized",
+                    "inputs": [{ "name": "caller", "type": "address" }]
+                },
+                {
+                    "type": "constructor",
+                    "inputs": [{ "name": "name", "type": "string" }],
+                    "stateMutability": "nonpayable"
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_signatures() {
+        let err = parse_signatures(&["event Transfer(address indexed from"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid signature 'event Transfer(address indexed from': expected ')', found end of signature"
+        );
+
+        assert!(parse_signatures(&["struct Order(address maker)"]).is_err());
+        assert!(parse_signatures(&["function transfer(address to) returns"]).is_err());
+        assert!(parse_signatures(&["event Paused() view"]).is_err());
+    }
+}
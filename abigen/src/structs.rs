@@ -0,0 +1,270 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+use crate::{
+    decode_tuple, encode_value, head_size, message::Param, meta::StructMeta, rust_variable,
+    struct_field, Position,
+};
+
+/// Structure used to generate the Rust struct of a Solidity struct, an ABI tuple whose
+/// components are all named.
+pub struct Struct {
+    /// Struct name.
+    pub(crate) name: String,
+
+    /// Whether the struct holds dynamic values, it is then encoded in the tail of the tuple
+    /// holding it.
+    dynamic: bool,
+    /// Size of the head of the fields.
+    head_size: usize,
+    fields: Vec<TokenStream>,
+    decoded_fields: Vec<TokenStream>,
+    encode_fields: Vec<TokenStream>,
+    /// Fields of the struct, used to generate its `prost::Message` version and to find the
+    /// structs and enums it uses.
+    pub(crate) params: Vec<Param>,
+    /// Attributes added to the generated type, like derives.
+    pub(crate) attributes: TokenStream,
+}
+
+impl<'a> TryFrom<&'a StructMeta> for Struct {
+    type Error = ethabi::Error;
+
+    fn try_from(s: &'a StructMeta) -> Result<Self, Self::Error> {
+        let names: Vec<_> = s
+            .fields
+            .iter()
+            .map(|(name, _)| syn::Ident::new(&rust_variable(name), Span::call_site()))
+            .collect();
+
+        let kinds = s
+            .fields
+            .iter()
+            .map(|(_, meta)| meta.param_type())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let fields = names
+            .iter()
+            .zip(s.fields.iter().zip(kinds.iter()))
+            .map(|(name, ((_, meta), kind))| struct_field(name, kind, meta))
+            .collect();
+
+        let params = names
+            .iter()
+            .zip(s.fields.iter().zip(kinds.iter()))
+            .map(|(name, ((_, meta), kind))| Param::new(name, kind, meta))
+            .collect();
+
+        let dynamic = kinds.iter().any(ethabi::ParamType::is_dynamic);
+
+        // Fields of a dynamic struct are read from its tail, those of a static one in place.
+        let at = match dynamic {
+            true => Position::at(0),
+            false => Position::runtime(quote! { at }),
+        };
+        let decoded_fields = decode_tuple(
+            kinds.iter().zip(s.fields.iter().map(|(_, meta)| meta)),
+            &quote! { decoder },
+            &at,
+        )
+        .into_iter()
+        .zip(names.iter())
+        .map(|(decode_field, name)| {
+            quote! {
+                #name: #decode_field
+            }
+        })
+        .collect();
+
+        let encode_fields = names
+            .iter()
+            .zip(s.fields.iter().zip(kinds.iter()))
+            .map(|(name, ((_, meta), kind))| {
+                encode_value(&quote! { self.#name }, kind, meta, &quote! { encoder })
+            })
+            .collect();
+
+        Ok(Struct {
+            name: s.name.clone(),
+            dynamic,
+            head_size: kinds.iter().map(head_size).sum(),
+            fields,
+            decoded_fields,
+            encode_fields,
+            params,
+            attributes: TokenStream::new(),
+        })
+    }
+}
+
+impl Struct {
+    /// Generates the Rust struct representing a Solidity struct.
+    pub fn generate(&self) -> TokenStream {
+        let name = syn::Ident::new(&self.name, Span::call_site());
+        let fields = &self.fields;
+        let decoded_fields = &self.decoded_fields;
+        let encode_fields = &self.encode_fields;
+        let attributes = &self.attributes;
+        let head_size = self.head_size;
+
+        let (decoder, encode_head_size, encode_tuple) = match self.dynamic {
+            true => (
+                quote! { let decoder = decoder.tail(at)?; },
+                32,
+                quote! {
+                    encoder.tuple(#head_size, |encoder| {
+                        #(#encode_fields)*
+                    });
+                },
+            ),
+            false => (quote! {}, head_size, quote! { #(#encode_fields)* }),
+        };
+
+        quote! {
+            #[derive(Debug, Clone, PartialEq)]
+            #attributes
+            pub struct #name {
+                #(#fields),*
+            }
+
+            impl #name {
+                pub fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+                    Self::decode_tuple(substreams_ethereum::abi::Decoder::new(data), 0)
+                }
+
+                pub fn encode(&self) -> Vec<u8> {
+                    substreams_ethereum::abi::Encoder::encode(#encode_head_size, |encoder| self.encode_tuple(encoder))
+                }
+
+                /// Decodes the struct whose head is at `at` of `decoder`.
+                pub fn decode_tuple(decoder: substreams_ethereum::abi::Decoder, at: usize) -> Result<Self, substreams_ethereum::DecodeError> {
+                    #decoder
+
+                    Ok(Self {
+                        #(#decoded_fields),*
+                    })
+                }
+
+                /// Writes the struct with `encoder`.
+                pub fn encode_tuple(&self, encoder: &mut substreams_ethereum::abi::Encoder) {
+                    #encode_tuple
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assertions::assert_ast_eq, meta::AbiMeta};
+
+    use super::Struct;
+    use quote::quote;
+
+    #[test]
+    fn test_struct_from_event_tuple_array() {
+        let mut meta = AbiMeta::parse(
+            br#"[{
+                "anonymous": false,
+                "inputs": [{
+                    "components": [
+                        { "internalType": "enum ItemType", "name": "itemType", "type": "uint8" },
+                        { "internalType": "address", "name": "token", "type": "address" }
+                    ],
+                    "indexed": false,
+                    "internalType": "struct SpentItem[]",
+                    "name": "offer",
+                    "type": "tuple[]"
+                }],
+                "name": "OrderFulfilled",
+                "type": "event"
+            }]"#,
+        )
+        .unwrap();
+
+        let structs = meta.resolve_structs();
+        assert_eq!(structs.len(), 1);
+        assert_eq!(
+            meta.entry("event", "OrderFulfilled", 0)
+                .input(0)
+                .struct_name,
+            Some("SpentItem".to_string())
+        );
+
+        let s = Struct::try_from(&structs[0]).unwrap();
+
+        assert_ast_eq(
+            s.generate(),
+            quote! {
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct SpentItem {
+                    pub item_type: u8,
+                    pub token: Vec<u8>
+                }
+                impl SpentItem {
+                    pub fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+                        Self::decode_tuple(substreams_ethereum::abi::Decoder::new(data), 0)
+                    }
+                    pub fn encode(&self) -> Vec<u8> {
+                        substreams_ethereum::abi::Encoder::encode(64usize, |encoder| self.encode_tuple(encoder))
+                    }
+                    /// Decodes the struct whose head is at `at` of `decoder`.
+                    pub fn decode_tuple(
+                        decoder: substreams_ethereum::abi::Decoder,
+                        at: usize
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        Ok(Self {
+                            item_type: decoder.uint::<u8>(at, 8usize)?,
+                            token: decoder.address(at + 32usize)?.to_vec()
+                        })
+                    }
+                    /// Writes the struct with `encoder`.
+                    pub fn encode_tuple(&self, encoder: &mut substreams_ethereum::abi::Encoder) {
+                        encoder.word(substreams_ethereum::abi::encode_uint(self.item_type));
+                        encoder.address(self.token.as_slice());
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_struct_name_conflicts() {
+        let mut meta = AbiMeta::parse(
+            br#"[{
+                "inputs": [
+                    {
+                        "components": [{ "internalType": "uint256", "name": "amount", "type": "uint256" }],
+                        "internalType": "struct A.Order",
+                        "name": "first",
+                        "type": "tuple"
+                    },
+                    {
+                        "components": [{ "internalType": "address", "name": "maker", "type": "address" }],
+                        "internalType": "struct B.Order",
+                        "name": "second",
+                        "type": "tuple"
+                    },
+                    {
+                        "components": [{ "internalType": "uint256", "name": "amount", "type": "uint256" }],
+                        "internalType": "struct C.Order",
+                        "name": "third",
+                        "type": "tuple"
+                    }
+                ],
+                "name": "fill",
+                "outputs": [],
+                "type": "function"
+            }]"#,
+        )
+        .unwrap();
+
+        let names: Vec<_> = meta.resolve_structs().into_iter().map(|s| s.name).collect();
+        assert_eq!(names, vec!["BOrder".to_string(), "Order".to_string()]);
+
+        let entry = meta.entry("function", "fill", 0);
+        assert_eq!(entry.input(0).struct_name, Some("Order".to_string()));
+        assert_eq!(entry.input(1).struct_name, Some("BOrder".to_string()));
+        assert_eq!(entry.input(2).struct_name, Some("Order".to_string()));
+    }
+}
@@ -0,0 +1,205 @@
+use num_bigint::Sign;
+use substreams::scalar::BigInt;
+use tiny_keccak::{Hasher, Keccak};
+
+use crate::DecodeError;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+mod decoder;
+mod encoder;
+
+pub use decoder::Decoder;
+pub use encoder::Encoder;
+
+/// Computes the Keccak-256 hash of `data`.
+pub fn keccak256<T: AsRef<[u8]>>(data: T) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    hasher.update(data.as_ref());
+
+    let mut output = [0u8; 32];
+    hasher.finalize(&mut output);
+    output
+}
+
+/// Converts the `uint<bits>` value encoded in `word` into the native integer `T`, fails if the
+/// value does not fit in `bits` bits.
+pub fn decode_uint<T: TryFrom<u128>>(word: &[u8; 32], bits: usize) -> Result<T, DecodeError> {
+    let out_of_range = || DecodeError::IntegerOutOfRange {
+        kind: format!("uint{}", bits),
+    };
+
+    if word[..16].iter().any(|byte| *byte != 0) {
+        return Err(out_of_range());
+    }
+
+    let value = u128::from_be_bytes(low_bytes(word));
+    if bits < 128 && value >> bits != 0 {
+        return Err(out_of_range());
+    }
+
+    T::try_from(value).map_err(|_| out_of_range())
+}
+
+/// Converts the `int<bits>` value encoded in `word` into the native integer `T`, fails if the
+/// value does not fit in `bits` bits.
+pub fn decode_int<T: TryFrom<i128>>(word: &[u8; 32], bits: usize) -> Result<T, DecodeError> {
+    let out_of_range = || DecodeError::IntegerOutOfRange {
+        kind: format!("int{}", bits),
+    };
+
+    // The value fits when all the bits above its sign bit are copies of it.
+    let value = i128::from_be_bytes(low_bytes(word));
+    let sign = if value < 0 { 0xff } else { 0x00 };
+    if word[..16].iter().any(|byte| *byte != sign) {
+        return Err(out_of_range());
+    }
+
+    let high = value >> (bits.min(128) - 1);
+    if high != 0 && high != -1 {
+        return Err(out_of_range());
+    }
+
+    T::try_from(value).map_err(|_| out_of_range())
+}
+
+/// Encodes a native unsigned integer into its word.
+pub fn encode_uint<T: Into<u128>>(value: T) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[16..].copy_from_slice(&value.into().to_be_bytes());
+    word
+}
+
+/// Encodes a native signed integer into its word, negative values are sign-extended to
+/// 256 bits.
+pub fn encode_int<T: Into<i128>>(value: T) -> [u8; 32] {
+    let value = value.into();
+    let mut word = [if value < 0 { 0xff } else { 0x00 }; 32];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// Encodes a `uint256` big integer into its word, panics if `value` is negative or does not fit
+/// in 256 bits.
+pub fn encode_big_uint(value: &BigInt) -> [u8; 32] {
+    let bytes = match value.to_bytes_be() {
+        (Sign::Minus, _) => panic!("negative numbers are not supported"),
+        (_, bytes) => bytes,
+    };
+
+    right_align(&bytes, 0x00)
+}
+
+/// Encodes an `int256` big integer into its word, panics if `value` does not fit in 256 bits.
+pub fn encode_big_int(value: &BigInt) -> [u8; 32] {
+    let bytes = value.to_signed_bytes_be();
+    let sign = match bytes.first() {
+        Some(byte) if byte & 0x80 == 0x80 => 0xff,
+        _ => 0x00,
+    };
+
+    right_align(&bytes, sign)
+}
+
+fn right_align(bytes: &[u8], fill: u8) -> [u8; 32] {
+    assert!(bytes.len() <= 32, "integer does not fit in 256 bits");
+
+    let mut word = [fill; 32];
+    word[32 - bytes.len()..].copy_from_slice(bytes);
+    word
+}
+
+fn low_bytes(word: &[u8; 32]) -> [u8; 16] {
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&word[16..]);
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use substreams::{hex, scalar::BigInt};
+
+    use super::{
+        decode_int, decode_uint, encode_big_int, encode_big_uint, encode_int, encode_uint,
+        keccak256,
+    };
+    use crate::DecodeError;
+
+    #[test]
+    fn it_keccak256() {
+        assert_eq!(
+            keccak256("Transfer(address,address,uint256)"),
+            hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+        );
+    }
+
+    #[test]
+    fn it_encode_decode_native_ints() {
+        assert_eq!(
+            encode_int(-2i32),
+            hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe")
+        );
+        assert_eq!(decode_int::<i32>(&encode_int(-2i32), 24), Ok(-2));
+        assert_eq!(
+            encode_uint(1000u16),
+            hex!("00000000000000000000000000000000000000000000000000000000000003e8")
+        );
+        assert_eq!(
+            decode_uint::<u128>(&encode_uint(u128::MAX), 128),
+            Ok(u128::MAX)
+        );
+        assert_eq!(
+            decode_int::<i128>(&encode_int(i128::MIN), 256),
+            Ok(i128::MIN)
+        );
+    }
+
+    #[test]
+    fn it_decode_native_ints_out_of_range() {
+        assert_eq!(
+            decode_uint::<u32>(&encode_uint(256u64), 8),
+            Err(DecodeError::IntegerOutOfRange {
+                kind: "uint8".to_string()
+            })
+        );
+        assert_eq!(decode_uint::<u8>(&encode_uint(255u64), 8), Ok(255));
+
+        assert_eq!(decode_int::<i8>(&encode_int(-128), 8), Ok(-128));
+        assert_eq!(
+            decode_int::<i8>(&encode_int(-129), 8),
+            Err(DecodeError::IntegerOutOfRange {
+                kind: "int8".to_string()
+            })
+        );
+        assert_eq!(
+            decode_int::<i32>(&encode_int(8388608), 24),
+            Err(DecodeError::IntegerOutOfRange {
+                kind: "int24".to_string()
+            })
+        );
+        assert_eq!(
+            decode_uint::<u128>(
+                &hex!("0000000000000000000000000000000100000000000000000000000000000000"),
+                256
+            ),
+            Err(DecodeError::IntegerOutOfRange {
+                kind: "uint256".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn it_encode_big_ints() {
+        assert_eq!(
+            encode_big_uint(&BigInt::from(1000u64)),
+            encode_uint(1000u64)
+        );
+        assert_eq!(encode_big_uint(&BigInt::zero()), [0u8; 32]);
+        assert_eq!(
+            encode_big_int(&BigInt::from(-9809887317731i64)),
+            hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffff713f526b11d")
+        );
+        assert_eq!(encode_big_int(&BigInt::from(128)), encode_int(128));
+    }
+}
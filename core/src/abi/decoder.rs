@@ -0,0 +1,259 @@
+use std::fmt;
+
+use substreams::scalar::BigInt;
+
+use super::{decode_int, decode_uint};
+use crate::DecodeError;
+
+/// Reads the values of ABI encoded data, the generated bindings decode each of their
+/// parameters with it.
+///
+/// Positions given to the methods are the byte offsets of the value heads from the start of
+/// the decoded tuple. Values of static types are read in place while the head of dynamic
+/// types (`bytes`, `string`, arrays and tuples holding them) is the offset of their tail, also
+/// relative to the start of the tuple.
+#[derive(Debug, Clone, Copy)]
+pub struct Decoder<'a> {
+    data: &'a [u8],
+    /// Offset of `data` in the whole input, reported in errors.
+    offset: usize,
+    topic: Option<Topic>,
+}
+
+/// The indexed event parameter decoded from a topic.
+#[derive(Debug, Clone, Copy)]
+struct Topic {
+    name: &'static str,
+    kind: &'static str,
+    index: usize,
+}
+
+impl<'a> Decoder<'a> {
+    /// Decoder of the values encoded in `data`.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self::with_offset(data, 0)
+    }
+
+    /// Decoder of the values encoded in `data`, found at byte `offset` of the input, like the
+    /// arguments following the method id of a call.
+    pub fn with_offset(data: &'a [u8], offset: usize) -> Self {
+        Decoder {
+            data,
+            offset,
+            topic: None,
+        }
+    }
+
+    /// Decoder of the topic at `index` of a log, holding the indexed parameter `name` of
+    /// Solidity type `kind`, its errors are [DecodeError::InvalidTopic].
+    pub fn topic(data: &'a [u8], name: &'static str, kind: &'static str, index: usize) -> Self {
+        Decoder {
+            data,
+            offset: 0,
+            topic: Some(Topic { name, kind, index }),
+        }
+    }
+
+    /// The 32 bytes word at `at`.
+    pub fn word(&self, at: usize) -> Result<&'a [u8; 32], DecodeError> {
+        self.slice(at, 32)
+            .map(|word| word.try_into().expect("slice has 32 bytes"))
+    }
+
+    pub fn address(&self, at: usize) -> Result<[u8; 20], DecodeError> {
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&self.word(at)?[12..]);
+        Ok(address)
+    }
+
+    pub fn bool(&self, at: usize) -> Result<bool, DecodeError> {
+        let word = self.word(at)?;
+        if word[..31].iter().any(|byte| *byte != 0) || word[31] > 1 {
+            return Err(self.error(at, "invalid boolean"));
+        }
+
+        Ok(word[31] == 1)
+    }
+
+    /// The `bytes<N>` value at `at`, left aligned in its word.
+    pub fn fixed_bytes<const N: usize>(&self, at: usize) -> Result<[u8; N], DecodeError> {
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(self.slice(at, N)?);
+        Ok(bytes)
+    }
+
+    /// The `uint<bits>` value at `at` as the native integer `T`, see [super::decode_uint].
+    pub fn uint<T: TryFrom<u128>>(&self, at: usize, bits: usize) -> Result<T, DecodeError> {
+        decode_uint(self.word(at)?, bits)
+    }
+
+    /// The `int<bits>` value at `at` as the native integer `T`, see [super::decode_int].
+    pub fn int<T: TryFrom<i128>>(&self, at: usize, bits: usize) -> Result<T, DecodeError> {
+        decode_int(self.word(at)?, bits)
+    }
+
+    pub fn big_uint(&self, at: usize) -> Result<BigInt, DecodeError> {
+        Ok(BigInt::from_unsigned_bytes_be(self.word(at)?))
+    }
+
+    pub fn big_int(&self, at: usize) -> Result<BigInt, DecodeError> {
+        Ok(BigInt::from_signed_bytes_be(self.word(at)?))
+    }
+
+    /// The `bytes` value whose head is at `at`, borrowed from the input.
+    pub fn bytes(&self, at: usize) -> Result<&'a [u8], DecodeError> {
+        let tail = self.tail(at)?;
+        let len = tail.length(0)?;
+        tail.slice(32, len)
+    }
+
+    /// The `string` value whose head is at `at`, invalid UTF-8 sequences are replaced by
+    /// `U+FFFD` as `ethabi` did.
+    pub fn string(&self, at: usize) -> Result<String, DecodeError> {
+        Ok(String::from_utf8_lossy(self.bytes(at)?).into_owned())
+    }
+
+    /// The decoder of the dynamic tuple or fixed array whose head is at `at`.
+    pub fn tail(&self, at: usize) -> Result<Decoder<'a>, DecodeError> {
+        let offset = self.length(at)?;
+        if offset > self.data.len() {
+            return Err(self.error(at, format!("offset {} out of bounds", offset)));
+        }
+
+        Ok(Decoder {
+            data: &self.data[offset..],
+            offset: self.offset + offset,
+            topic: self.topic,
+        })
+    }
+
+    /// The length and the decoder of the elements of the dynamic array whose head is at `at`,
+    /// each element has a head of `stride` bytes.
+    pub fn array(&self, at: usize, stride: usize) -> Result<(usize, Decoder<'a>), DecodeError> {
+        let tail = self.tail(at)?;
+        let len = tail.length(0)?;
+        let elements = tail.tail_at(32);
+
+        // Checked before the elements are collected, a forged length must not allocate.
+        match len.checked_mul(stride) {
+            Some(size) if size <= elements.data.len() => Ok((len, elements)),
+            _ => Err(tail.error(0, format!("array length {} out of bounds", len))),
+        }
+    }
+
+    /// The word at `at` as an offset or a length.
+    fn length(&self, at: usize) -> Result<usize, DecodeError> {
+        let word = self.word(at)?;
+        let invalid = || self.error(at, "invalid offset or length");
+
+        if word[..24].iter().any(|byte| *byte != 0) {
+            return Err(invalid());
+        }
+
+        let mut value = [0u8; 8];
+        value.copy_from_slice(&word[24..]);
+        usize::try_from(u64::from_be_bytes(value)).map_err(|_| invalid())
+    }
+
+    fn slice(&self, at: usize, len: usize) -> Result<&'a [u8], DecodeError> {
+        at.checked_add(len)
+            .and_then(|end| self.data.get(at..end))
+            .ok_or_else(|| {
+                self.error(
+                    at,
+                    format!(
+                        "out of bounds, expected {} bytes but {} remain",
+                        len,
+                        self.data.len().saturating_sub(at)
+                    ),
+                )
+            })
+    }
+
+    /// The decoder of the data following byte `at`, which is in bounds.
+    fn tail_at(&self, at: usize) -> Decoder<'a> {
+        Decoder {
+            data: &self.data[at..],
+            offset: self.offset + at,
+            topic: self.topic,
+        }
+    }
+
+    fn error<R: fmt::Display>(&self, at: usize, reason: R) -> DecodeError {
+        match self.topic {
+            Some(Topic { name, kind, index }) => DecodeError::topic(name, kind, index, reason),
+            None => DecodeError::data(self.offset + at, reason),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use substreams::{hex, scalar::BigInt};
+
+    use super::Decoder;
+    use crate::DecodeError;
+
+    #[test]
+    fn it_decode_static_values() {
+        let data = hex!("000000000000000000000000ab07a50ad459b41fe065f7bbab866d5390e9f7050000000000000000000000000000000000000000000000000000000000000001ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff85abcd000000000000000000000000000000000000000000000000000000000000");
+        let decoder = Decoder::new(&data);
+
+        assert_eq!(
+            decoder.address(0),
+            Ok(hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705"))
+        );
+        assert_eq!(decoder.bool(32), Ok(true));
+        assert_eq!(decoder.int::<i8>(64, 8), Ok(-123));
+        assert_eq!(decoder.big_int(64), Ok(BigInt::from(-123)));
+        assert_eq!(decoder.fixed_bytes::<2>(96), Ok(hex!("abcd")));
+        assert_eq!(
+            decoder.bool(64),
+            Err(DecodeError::data(64, "invalid boolean"))
+        );
+    }
+
+    #[test]
+    fn it_decode_dynamic_values() {
+        // (string, uint8[]) = ("hello", [1, 2])
+        let data = hex!("00000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000568656c6c6f000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002");
+        let decoder = Decoder::new(&data);
+
+        assert_eq!(decoder.string(0), Ok("hello".to_string()));
+
+        let (len, elements) = decoder.array(32, 32).unwrap();
+        let values = (0..len)
+            .map(|i| elements.uint::<u8>(i * 32, 8))
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(values, Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn it_decode_out_of_bounds() {
+        // A string of 5 bytes whose data is missing
+        let data = hex!("00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000005");
+
+        assert_eq!(
+            Decoder::with_offset(&data, 4).string(0),
+            Err(DecodeError::data(
+                68,
+                "out of bounds, expected 5 bytes but 0 remain"
+            ))
+        );
+        assert!(matches!(
+            Decoder::new(&data).address(64),
+            Err(DecodeError::InvalidData { offset: 64, .. })
+        ));
+        assert!(matches!(
+            Decoder::topic(&data[..4], "from", "address", 1).address(0),
+            Err(DecodeError::InvalidTopic { index: 1, .. })
+        ));
+
+        // An array claiming more elements than the data holds
+        let data = hex!("0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000b0000000000000000000000000000000000000000000000000000000000000001");
+        assert!(matches!(
+            Decoder::new(&data).array(0, 32),
+            Err(DecodeError::InvalidData { offset: 32, .. })
+        ));
+    }
+}
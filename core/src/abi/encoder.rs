@@ -0,0 +1,160 @@
+/// Writes the values of a tuple in the ABI encoding, the generated bindings encode each of
+/// their parameters with it.
+///
+/// Values are written in order into the head of the tuple, whose size is known upfront. Values
+/// of static types are written in place while the head of dynamic types (`bytes`, `string`,
+/// arrays and tuples holding them) is the offset of their tail, appended after the head.
+#[derive(Debug)]
+pub struct Encoder {
+    head: Vec<u8>,
+    tail: Vec<u8>,
+    head_size: usize,
+}
+
+impl Encoder {
+    /// Encodes the tuple whose head has `head_size` bytes, its values are written by `f`.
+    pub fn encode<F: FnOnce(&mut Encoder)>(head_size: usize, f: F) -> Vec<u8> {
+        let mut encoder = Encoder::new(head_size);
+        f(&mut encoder);
+        encoder.finish()
+    }
+
+    fn new(head_size: usize) -> Self {
+        Encoder {
+            head: Vec::with_capacity(head_size),
+            tail: Vec::new(),
+            head_size,
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        debug_assert_eq!(self.head.len(), self.head_size, "head size mismatch");
+
+        self.head.append(&mut self.tail);
+        self.head
+    }
+
+    /// Writes a 32 bytes word, like the encoding of an integer by [super::encode_uint].
+    pub fn word(&mut self, word: [u8; 32]) {
+        self.head.extend_from_slice(&word);
+    }
+
+    /// Writes an address, panics if it does not have 20 bytes.
+    pub fn address(&mut self, address: &[u8]) {
+        assert_eq!(address.len(), 20, "address must have 20 bytes");
+
+        self.head.extend_from_slice(&[0u8; 12]);
+        self.head.extend_from_slice(address);
+    }
+
+    pub fn bool(&mut self, value: bool) {
+        let mut word = [0u8; 32];
+        word[31] = value as u8;
+        self.word(word);
+    }
+
+    /// Writes a `bytes<N>` value, left aligned in its word.
+    pub fn fixed_bytes(&mut self, bytes: &[u8]) {
+        assert!(bytes.len() <= 32, "fixed bytes must have at most 32 bytes");
+
+        let mut word = [0u8; 32];
+        word[..bytes.len()].copy_from_slice(bytes);
+        self.word(word);
+    }
+
+    pub fn bytes(&mut self, bytes: &[u8]) {
+        self.offset();
+        self.tail
+            .extend_from_slice(&super::encode_uint(bytes.len() as u128));
+        self.tail.extend_from_slice(bytes);
+        self.tail.resize(self.tail.len() + padding(bytes.len()), 0);
+    }
+
+    pub fn string(&mut self, value: &str) {
+        self.bytes(value.as_bytes());
+    }
+
+    /// Writes a dynamic array of `len` elements, each with a head of `stride` bytes, the
+    /// elements are written by `f`.
+    pub fn array<F: FnOnce(&mut Encoder)>(&mut self, len: usize, stride: usize, f: F) {
+        self.offset();
+        self.tail
+            .extend_from_slice(&super::encode_uint(len as u128));
+        self.tail.extend(Encoder::encode(len * stride, f));
+    }
+
+    /// Writes a dynamic tuple or fixed array whose head has `head_size` bytes, its values are
+    /// written by `f`. Static ones are written in place, value after value.
+    pub fn tuple<F: FnOnce(&mut Encoder)>(&mut self, head_size: usize, f: F) {
+        self.offset();
+        self.tail.extend(Encoder::encode(head_size, f));
+    }
+
+    /// Writes the offset of the next dynamic value appended to the tail.
+    fn offset(&mut self) {
+        let offset = self.head_size + self.tail.len();
+        self.word(super::encode_uint(offset as u128));
+    }
+}
+
+fn padding(len: usize) -> usize {
+    (32 - len % 32) % 32
+}
+
+#[cfg(test)]
+mod tests {
+    use substreams::hex;
+
+    use super::Encoder;
+    use crate::abi::{encode_int, encode_uint, Decoder};
+
+    #[test]
+    fn it_encode_static_values() {
+        let data = Encoder::encode(128, |encoder| {
+            encoder.address(&hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705"));
+            encoder.bool(true);
+            encoder.word(encode_int(-123i8));
+            encoder.fixed_bytes(&hex!("abcd"));
+        });
+
+        assert_eq!(data, hex!("000000000000000000000000ab07a50ad459b41fe065f7bbab866d5390e9f7050000000000000000000000000000000000000000000000000000000000000001ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff85abcd000000000000000000000000000000000000000000000000000000000000"));
+    }
+
+    #[test]
+    fn it_encode_dynamic_values() {
+        // (string, uint8[]) = ("hello", [1, 2])
+        let data = Encoder::encode(64, |encoder| {
+            encoder.string("hello");
+            encoder.array(2, 32, |elements| {
+                elements.word(encode_uint(1u8));
+                elements.word(encode_uint(2u8));
+            });
+        });
+
+        assert_eq!(data, hex!("00000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000568656c6c6f000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002"));
+    }
+
+    #[test]
+    fn it_encode_decode_nested_tuples() {
+        // ((string, bytes)[2], uint256) = ([("a", 0x01), ("b", 0x0203)], 7)
+        let data = Encoder::encode(64, |encoder| {
+            encoder.tuple(64, |elements| {
+                for (value, bytes) in [("a", &[1u8][..]), ("b", &[2u8, 3u8][..])] {
+                    elements.tuple(64, |fields| {
+                        fields.string(value);
+                        fields.bytes(bytes);
+                    });
+                }
+            });
+            encoder.word(encode_uint(7u8));
+        });
+
+        let decoder = Decoder::new(&data);
+        let elements = decoder.tail(0).unwrap();
+        let second = elements.tail(32).unwrap();
+        assert_eq!(second.string(0), Ok("b".to_string()));
+        assert_eq!(second.bytes(32), Ok(&[2u8, 3u8][..]));
+        assert_eq!(elements.tail(0).unwrap().string(0), Ok("a".to_string()));
+        assert_eq!(decoder.uint::<u8>(32, 256), Ok(7));
+    }
+}
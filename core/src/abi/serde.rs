@@ -0,0 +1,235 @@
+//! Serde helpers of the bindings generated with `Abigen::serde`, values are serialized the way
+//! JSON consumers of Ethereum data expect them: bytes, addresses and hashes as `0x` prefixed
+//! hexadecimal strings and big integers as decimal strings.
+//!
+//! Use it on fields with `#[serde(with = "substreams_ethereum::abi::serde")]`, it applies to
+//! nested arrays and tuples of those values too.
+
+use ::serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use substreams::{scalar::BigInt, Hex};
+
+use crate::scalar::{hex_digit, Address, ScalarError, TopicHash, H256};
+
+/// Serializes `value` as described in the [module](self) documentation.
+pub fn serialize<T: AbiSerde, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    value.serialize_abi(serializer)
+}
+
+/// Deserializes a value serialized by [serialize].
+pub fn deserialize<'de, T: AbiSerde, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    T::deserialize_abi(deserializer)
+}
+
+/// Types of ABI values that can be serialized by this module.
+pub trait AbiSerde: Sized {
+    fn serialize_abi<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize_abi<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+
+    /// Serializes a sequence of values, `u8` overrides it to serialize bytes as hexadecimal.
+    fn serialize_abi_slice<S: Serializer>(
+        values: &[Self],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(values.iter().map(Abi))
+    }
+
+    /// Deserializes a sequence of values serialized by [AbiSerde::serialize_abi_slice].
+    fn deserialize_abi_vec<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Self>, D::Error> {
+        let values = Vec::<Owned<Self>>::deserialize(deserializer)?;
+        Ok(values.into_iter().map(|value| value.0).collect())
+    }
+}
+
+/// Serializes the wrapped value with [AbiSerde].
+struct Abi<'a, T>(&'a T);
+
+impl<T: AbiSerde> Serialize for Abi<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_abi(serializer)
+    }
+}
+
+/// Deserializes the wrapped value with [AbiSerde].
+struct Owned<T>(T);
+
+impl<'de, T: AbiSerde> Deserialize<'de> for Owned<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize_abi(deserializer).map(Owned)
+    }
+}
+
+macro_rules! plain {
+    ($($ty:ty),*) => {
+        $(
+            impl AbiSerde for $ty {
+                fn serialize_abi<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.serialize(serializer)
+                }
+
+                fn deserialize_abi<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    <$ty>::deserialize(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+plain!(bool, String, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl AbiSerde for u8 {
+    fn serialize_abi<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize(serializer)
+    }
+
+    fn deserialize_abi<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u8::deserialize(deserializer)
+    }
+
+    fn serialize_abi_slice<S: Serializer>(
+        values: &[Self],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{}", Hex(values)))
+    }
+
+    fn deserialize_abi_vec<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Self>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        let digits = value.strip_prefix("0x").unwrap_or(&value).as_bytes();
+        if digits.len() % 2 != 0 {
+            return Err(D::Error::custom("odd number of hexadecimal digits"));
+        }
+
+        digits
+            .chunks(2)
+            .map(|pair| Ok((hex_digit(pair[0])? << 4) | hex_digit(pair[1])?))
+            .collect::<Result<_, ScalarError>>()
+            .map_err(D::Error::custom)
+    }
+}
+
+impl<T: AbiSerde> AbiSerde for Vec<T> {
+    fn serialize_abi<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        T::serialize_abi_slice(self, serializer)
+    }
+
+    fn deserialize_abi<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize_abi_vec(deserializer)
+    }
+}
+
+impl<T: AbiSerde, const N: usize> AbiSerde for [T; N] {
+    fn serialize_abi<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        T::serialize_abi_slice(self, serializer)
+    }
+
+    fn deserialize_abi<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = T::deserialize_abi_vec(deserializer)?;
+        let len = values.len();
+        values
+            .try_into()
+            .map_err(|_| D::Error::invalid_length(len, &format!("{} elements", N).as_str()))
+    }
+}
+
+impl AbiSerde for BigInt {
+    fn serialize_abi<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+
+    fn deserialize_abi<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value
+            .parse::<num_bigint::BigInt>()
+            .map(BigInt::from)
+            .map_err(D::Error::custom)
+    }
+}
+
+macro_rules! fixed_bytes {
+    ($($ty:ty),*) => {
+        $(
+            impl AbiSerde for $ty {
+                fn serialize_abi<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_str(&self.to_string())
+                }
+
+                fn deserialize_abi<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    String::deserialize(deserializer)?
+                        .parse()
+                        .map_err(D::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+fixed_bytes!(Address, H256, TopicHash);
+
+macro_rules! tuple {
+    ($($ty:ident $value:ident $index:tt),+) => {
+        impl<$($ty: AbiSerde),+> AbiSerde for ($($ty,)+) {
+            fn serialize_abi<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                ($(Abi(&self.$index),)+).serialize(serializer)
+            }
+
+            fn deserialize_abi<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let ($($value,)+) = <($(Owned<$ty>,)+)>::deserialize(deserializer)?;
+                Ok(($($value.0,)+))
+            }
+        }
+    };
+}
+
+tuple!(T0 t0 0);
+tuple!(T0 t0 0, T1 t1 1);
+tuple!(T0 t0 0, T1 t1 1, T2 t2 2);
+tuple!(T0 t0 0, T1 t1 1, T2 t2 2, T3 t3 3);
+tuple!(T0 t0 0, T1 t1 1, T2 t2 2, T3 t3 3, T4 t4 4);
+tuple!(T0 t0 0, T1 t1 1, T2 t2 2, T3 t3 3, T4 t4 4, T5 t5 5);
+tuple!(T0 t0 0, T1 t1 1, T2 t2 2, T3 t3 3, T4 t4 4, T5 t5 5, T6 t6 6);
+tuple!(T0 t0 0, T1 t1 1, T2 t2 2, T3 t3 3, T4 t4 4, T5 t5 5, T6 t6 6, T7 t7 7);
+
+#[cfg(test)]
+mod tests {
+    use substreams::{hex, scalar::BigInt};
+
+    use crate::scalar::Address;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Values {
+        #[serde(with = "super")]
+        bytes: Vec<u8>,
+        #[serde(with = "super")]
+        amounts: Vec<BigInt>,
+        #[serde(with = "super")]
+        pair: ([u8; 2], Address),
+    }
+
+    #[test]
+    fn it_serialize_deserialize_abi_values() {
+        let values = Values {
+            bytes: hex!("00ff").to_vec(),
+            amounts: vec![BigInt::from(-1), BigInt::from(1000u64)],
+            pair: (
+                hex!("abcd"),
+                Address(hex!("0000000000000000000000000000000000000001")),
+            ),
+        };
+
+        let json = serde_json::to_string(&values).unwrap();
+        assert_eq!(
+            json,
+            r#"{"bytes":"0x00ff","amounts":["-1","1000"],"pair":["0xabcd","0x0000000000000000000000000000000000000001"]}"#
+        );
+        assert_eq!(serde_json::from_str::<Values>(&json).unwrap(), values);
+        assert!(serde_json::from_str::<Values>(
+            r#"{"bytes":"0x0","amounts":[],"pair":["0xabcd","0x00"]}"#
+        )
+        .is_err());
+    }
+}
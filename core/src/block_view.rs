@@ -1,7 +1,12 @@
 use prost_types::Timestamp;
 
 use crate::pb::eth::v2::{Call, Log};
-use crate::{pb::eth::v2 as pb, Event};
+use crate::{
+    pb::eth::v2 as pb,
+    revert::RevertReason,
+    scalar::{Address, ScalarError},
+    ContractEvents, Event,
+};
 
 impl pb::Block {
     /// Iterates over successful transactions
@@ -27,21 +32,37 @@ impl pb::Block {
     /// A convenience for handlers that process a single type of event. Returns an iterator over
     /// pairs of `(event, log)`.
     ///
-    /// If you need to process multiple event types in a single handler, try something like:
+    /// If you need to process multiple event types of a contract in a single handler, see
+    /// [pb::Block::contract_events].
+    pub fn events<'a, E: Event>(
+        &'a self,
+        addresses: &'a [&[u8]],
+    ) -> impl Iterator<Item = (E, LogView)> {
+        self.logs().filter_map(|log| {
+            if !addresses.contains(&log.address()) {
+                return None;
+            }
+
+            E::match_and_decode(log).map(|e| (e, log))
+        })
+    }
+
+    /// A convenience for handlers that process several types of events of a contract. Returns an
+    /// iterator over pairs of `(event, log)` where `event` is the `Events` enum generated for the
+    /// contract, for example:
     /// ```ignore
-    /// for log in block.logs() {
-    ///     if !addresses.contains(&log.address()) {
-    ///        continue;
-    ///     }
-    ///
-    ///     if let Some(event) = E1::match_and_decode(log) {
-    ///         // Process events of type E1
-    ///     } else if let Some(event) = E2::match_and_decode(log) {
-    ///         // Process events of type E2
+    /// for (event, log) in block.contract_events::<erc20::events::Events>(&addresses) {
+    ///     match event {
+    ///         erc20::events::Events::Transfer(transfer) => {
+    ///             // Process transfer events
+    ///         }
+    ///         erc20::events::Events::Approval(approval) => {
+    ///             // Process approval events
+    ///         }
     ///     }
     /// }
     /// ```
-    pub fn events<'a, E: Event>(
+    pub fn contract_events<'a, E: ContractEvents>(
         &'a self,
         addresses: &'a [&[u8]],
     ) -> impl Iterator<Item = (E, LogView)> {
@@ -90,6 +111,16 @@ pub struct CallView<'a> {
 }
 
 impl CallView<'_> {
+    /// The address of the called account, fails if it does not have 20 bytes.
+    pub fn typed_address(&self) -> Result<Address, ScalarError> {
+        Address::try_from(self.call.address.as_slice())
+    }
+
+    /// The address of the caller, fails if it does not have 20 bytes.
+    pub fn typed_caller(&self) -> Result<Address, ScalarError> {
+        Address::try_from(self.call.caller.as_slice())
+    }
+
     pub fn parent(&self) -> Option<&Call> {
         return self
             .transaction
@@ -97,6 +128,16 @@ impl CallView<'_> {
             .iter()
             .find(|call| call.index == self.call.parent_index);
     }
+
+    /// Decodes the revert data of the call, `None` when the call did not revert or its revert
+    /// data is empty or malformed. See `failure_reason` for the reason recorded by the node.
+    pub fn revert_reason(&self) -> Option<RevertReason> {
+        if !self.call.status_reverted {
+            return None;
+        }
+
+        RevertReason::decode(&self.call.return_data)
+    }
 }
 
 impl AsRef<pb::Call> for CallView<'_> {
@@ -113,6 +154,17 @@ impl pb::TransactionTrace {
         })
     }
 
+    /// The address of the sender, fails if it does not have 20 bytes.
+    pub fn typed_from(&self) -> Result<Address, ScalarError> {
+        Address::try_from(self.from.as_slice())
+    }
+
+    /// The address of the recipient, or of the created contract, fails if it does not have 20
+    /// bytes.
+    pub fn typed_to(&self) -> Result<Address, ScalarError> {
+        Address::try_from(self.to.as_slice())
+    }
+
     pub fn receipt(&self) -> ReceiptView {
         ReceiptView {
             transaction: self,
@@ -154,6 +206,20 @@ impl pb::TransactionTrace {
         res.into_iter()
     }
 
+    /// Decodes the revert data of the transaction, `None` when it did not revert or its revert
+    /// data is empty or malformed. The revert data of the root call is used when the
+    /// transaction one is not available.
+    pub fn revert_reason(&self) -> Option<RevertReason> {
+        if self.status != pb::TransactionTraceStatus::Reverted as i32 {
+            return None;
+        }
+
+        match (self.return_data.is_empty(), self.calls.first()) {
+            (true, Some(root)) => RevertReason::decode(&root.return_data),
+            _ => RevertReason::decode(&self.return_data),
+        }
+    }
+
     // TODO: Call view, filtering out failed calls
     // pub fn calls: Vec<CallView> { }
 }
@@ -184,6 +250,11 @@ impl<'a> LogView<'a> {
         &self.log.address
     }
 
+    /// The address of the contract that emitted the log, fails if it does not have 20 bytes.
+    pub fn typed_address(self) -> Result<Address, ScalarError> {
+        Address::try_from(self.log.address.as_slice())
+    }
+
     pub fn topics(self) -> &'a Vec<Vec<u8>> {
         &self.log.topics
     }
@@ -215,9 +286,12 @@ impl AsRef<pb::Log> for LogView<'_> {
 mod tests {
     use std::vec;
 
+    use substreams::hex;
+
     use crate::{
-        block_view::CallView,
-        pb::eth::v2::{Call, Log, TransactionTrace},
+        block_view::{CallView, LogView},
+        pb::eth::v2::{Call, Log, TransactionReceipt, TransactionTrace},
+        scalar::{Address, ScalarError},
     };
 
     #[test]
@@ -262,4 +336,65 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn typed_addresses() {
+        let from = hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705");
+        let to = hex!("bc4ca0eda7647a8ab7c2061c2e118a18a936f13d");
+
+        let trace = TransactionTrace {
+            from: from.to_vec(),
+            to: to.to_vec(),
+            calls: vec![Call {
+                caller: from.to_vec(),
+                address: to.to_vec(),
+                ..Default::default()
+            }],
+            receipt: Some(TransactionReceipt {
+                logs: vec![Log {
+                    address: to.to_vec(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(trace.typed_from(), Ok(Address(from)));
+        assert_eq!(trace.typed_to(), Ok(Address(to)));
+
+        let call = trace.calls().next().unwrap();
+        assert_eq!(call.typed_caller(), Ok(Address(from)));
+        assert_eq!(call.typed_address(), Ok(Address(to)));
+
+        let log: LogView = trace.receipt().logs().next().unwrap();
+        assert_eq!(log.typed_address(), Ok(Address(to)));
+    }
+
+    #[test]
+    fn typed_address_invalid_length() {
+        let call = Call {
+            address: hex!("ab07a50a").to_vec(),
+            ..Default::default()
+        };
+        let trace = TransactionTrace {
+            calls: vec![call],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            trace.calls().next().unwrap().typed_address(),
+            Err(ScalarError::InvalidLength {
+                expected: 20,
+                actual: 4
+            })
+        );
+        assert_eq!(
+            trace.typed_from(),
+            Err(ScalarError::InvalidLength {
+                expected: 20,
+                actual: 0
+            })
+        );
+    }
 }
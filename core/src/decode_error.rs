@@ -0,0 +1,86 @@
+use std::fmt;
+
+/// Error returned when decoding an event, a function call, its output, a custom error or a
+/// struct fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input has less than the `expected` minimum amount of bytes.
+    DataTooShort { expected: usize, actual: usize },
+    /// The log does not have the `expected` amount of topics of the event.
+    InvalidTopicCount { expected: usize, actual: usize },
+    /// The call is not a contract creation, only those carry constructor arguments.
+    NotContractCreation,
+    /// The indexed parameter `name` of Solidity type `kind` could not be decoded from the
+    /// topic at `index`.
+    InvalidTopic {
+        name: String,
+        kind: String,
+        index: usize,
+        reason: String,
+    },
+    /// The ABI encoded values starting at byte `offset` of the input could not be decoded.
+    InvalidData { offset: usize, reason: String },
+    /// A value does not fit in its declared Solidity integer type `kind`, e.g. `uint24`.
+    IntegerOutOfRange { kind: String },
+    /// The `value` is not one of the variants of the Solidity enum `name`.
+    UnknownEnumVariant { name: String, value: u8 },
+}
+
+impl DecodeError {
+    /// Error for the ABI encoded values starting at byte `offset` of the input.
+    pub fn data<R: fmt::Display>(offset: usize, reason: R) -> Self {
+        DecodeError::InvalidData {
+            offset,
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Error for the indexed parameter `name` of Solidity type `kind` found in topic `index`.
+    pub fn topic<R: fmt::Display>(name: &str, kind: &str, index: usize, reason: R) -> Self {
+        DecodeError::InvalidTopic {
+            name: name.to_string(),
+            kind: kind.to_string(),
+            index,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::DataTooShort { expected, actual } => write!(
+                f,
+                "data too short, expected at least {} bytes but got {}",
+                expected, actual
+            ),
+            DecodeError::InvalidTopicCount { expected, actual } => write!(
+                f,
+                "invalid topic count, expected {} topics but got {}",
+                expected, actual
+            ),
+            DecodeError::NotContractCreation => write!(f, "call is not a contract creation"),
+            DecodeError::InvalidTopic {
+                name,
+                kind,
+                index,
+                reason,
+            } => write!(
+                f,
+                "unable to decode param '{}' from topic {} of type '{}': {}",
+                name, index, kind, reason
+            ),
+            DecodeError::InvalidData { offset, reason } => {
+                write!(f, "unable to decode data at offset {}: {}", offset, reason)
+            }
+            DecodeError::IntegerOutOfRange { kind } => {
+                write!(f, "integer value out of range for type '{}'", kind)
+            }
+            DecodeError::UnknownEnumVariant { name, value } => {
+                write!(f, "unknown variant {} of enum '{}'", value, name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
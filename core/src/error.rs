@@ -0,0 +1,32 @@
+use crate::{pb::eth::v2::Call, DecodeError};
+
+pub trait Error: Sized {
+    const NAME: &'static str;
+
+    fn match_revert(data: &[u8]) -> bool;
+    fn decode(data: &[u8]) -> Result<Self, DecodeError>;
+    fn encode(&self) -> Vec<u8>;
+
+    /// Attempts to match and decode the revert data (`return_data`) of a reverted call.
+    /// If the call did not revert or `Self::match_revert(data)` is `false`, returns `None`.
+    /// If it matches, but decoding fails, logs the decoding error and returns `None`.
+    fn match_and_decode(call: impl AsRef<Call>) -> Option<Self> {
+        let call = call.as_ref();
+        if !call.status_reverted || !Self::match_revert(&call.return_data) {
+            return None;
+        }
+
+        match Self::decode(&call.return_data) {
+            Ok(error) => Some(error),
+            Err(err) => {
+                substreams::log::info!(
+                    "Call for error `{}` at index {} matched but failed to decode with error: {}",
+                    Self::NAME,
+                    call.index,
+                    err
+                );
+                None
+            }
+        }
+    }
+}
@@ -1,10 +1,28 @@
-use crate::pb::eth::v2::Log;
+use crate::{pb::eth::v2::Log, DecodeError};
 
 pub trait Event: Sized {
     const NAME: &'static str;
+    /// Keccak-256 hash of [Event::SIGNATURE], the first topic of the logs of the event unless
+    /// it is anonymous.
+    const TOPIC_ID: [u8; 32];
+    /// Canonical signature of the event, e.g. `Transfer(address,address,uint256)`.
+    const SIGNATURE: &'static str;
+    /// Names of the event parameters as declared in the ABI, empty for unnamed ones.
+    const PARAM_NAMES: &'static [&'static str];
+    /// Canonical types of the event parameters, e.g. `["address", "address", "uint256"]`.
+    const PARAM_TYPES: &'static [&'static str];
+
+    /// Whether the event is declared `anonymous`. Such events do not carry their signature as
+    /// first topic so `match_log` can only check the amount of topics and the data size, they
+    /// should be matched only on logs emitted by the expected contract address.
+    fn is_anonymous() -> bool {
+        false
+    }
 
     fn match_log(log: &Log) -> bool;
-    fn decode(log: &Log) -> Result<Self, String>;
+    fn decode(log: &Log) -> Result<Self, DecodeError>;
+    /// Encodes the event into the log emitted by the contract at `address`.
+    fn encode(&self, address: Vec<u8>) -> Log;
 
     /// Attempts to match and decode the log.
     /// If `Self::match_log(log)` is `false`, returns `None`.
@@ -30,6 +48,17 @@ pub trait Event: Sized {
     }
 }
 
+/// Implemented by the `Events` enum generated for a contract, it has one variant per event of
+/// the contract and decodes a log into the event matching its signature.
+pub trait ContractEvents: Sized {
+    /// Attempts to match and decode the log into one of the contract's events, dispatching on
+    /// the first topic. Anonymous events are never matched.
+    fn match_and_decode(log: impl AsRef<Log>) -> Option<Self>;
+
+    /// Name of the decoded event.
+    fn name(&self) -> &'static str;
+}
+
 impl AsRef<Log> for Log {
     fn as_ref(&self) -> &Self {
         self
@@ -1,10 +1,19 @@
-use crate::pb::eth::v2::Call;
+use crate::{pb::eth::v2::Call, DecodeError};
 
 pub trait Function: Sized {
     const NAME: &'static str;
+    /// First 4 bytes of the Keccak-256 hash of [Function::SIGNATURE], prefixing the input of
+    /// the calls to the function.
+    const METHOD_ID: [u8; 4];
+    /// Canonical signature of the function, e.g. `transfer(address,uint256)`.
+    const SIGNATURE: &'static str;
+    /// Names of the function inputs as declared in the ABI, empty for unnamed ones.
+    const PARAM_NAMES: &'static [&'static str];
+    /// Canonical types of the function inputs, e.g. `["address", "uint256"]`.
+    const PARAM_TYPES: &'static [&'static str];
 
     fn match_call(log: &Call) -> bool;
-    fn decode(log: &Call) -> Result<Self, String>;
+    fn decode(log: &Call) -> Result<Self, DecodeError>;
     fn encode(&self) -> Vec<u8>;
 
     /// Attempts to match and decode the call.
@@ -31,6 +40,17 @@ pub trait Function: Sized {
     }
 }
 
+/// Implemented by the `Functions` enum generated for a contract, it has one variant per function
+/// of the contract and decodes a call into the function matching its method id.
+pub trait ContractFunctions: Sized {
+    /// Attempts to match and decode the call into one of the contract's functions, dispatching
+    /// on the first 4 bytes of the call input.
+    fn match_and_decode(call: impl AsRef<Call>) -> Option<Self>;
+
+    /// Name of the decoded function.
+    fn name(&self) -> &'static str;
+}
+
 impl AsRef<Call> for Call {
     fn as_ref(&self) -> &Self {
         self
@@ -1,12 +1,20 @@
+/// Helpers to encode and decode values the way the Ethereum ABI does.
+pub mod abi;
 pub mod pb;
 pub mod rpc;
 
 /// Helpers to deal with block sources.
 pub mod block_view;
-pub use event::Event;
-pub use function::Function;
+pub use decode_error::DecodeError;
+pub use error::Error;
+pub use event::{ContractEvents, Event};
+pub use function::{ContractFunctions, Function};
+/// Helpers to decode the revert data of reverted calls.
+pub mod revert;
 pub mod scalar;
 
+mod decode_error;
+mod error;
 mod event;
 mod externs;
 mod function;
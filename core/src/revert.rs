@@ -0,0 +1,261 @@
+use std::fmt;
+
+use crate::abi::{encode_uint, Decoder};
+
+/// Selector of the `Error(string)` revert data of `revert("reason")` and
+/// `require(condition, "reason")`.
+pub const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Selector of the `Panic(uint256)` revert data of failed `assert` and runtime errors.
+pub const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// The reason a call reverted, decoded from its revert data (the `return_data` of a reverted
+/// `Call`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RevertReason {
+    /// The call reverted with a message, encoded as `Error(string)`.
+    Error(String),
+    /// The call failed an `assert` or hit a runtime error, encoded as `Panic(uint256)`.
+    Panic(PanicCode),
+    /// The call reverted with a Solidity custom error, decode it with the
+    /// [crate::Error] generated for the contract.
+    Custom { selector: [u8; 4], data: Vec<u8> },
+}
+
+impl RevertReason {
+    /// Decodes the revert `data`, `None` when it is empty like for `revert()`, shorter than a
+    /// selector or when the `Error(string)` or `Panic(uint256)` payload is malformed.
+    pub fn decode(data: &[u8]) -> Option<RevertReason> {
+        let selector: [u8; 4] = data.get(0..4)?.try_into().ok()?;
+        let payload = &data[4..];
+
+        match selector {
+            ERROR_SELECTOR => Decoder::new(payload)
+                .string(0)
+                .ok()
+                .map(RevertReason::Error),
+            PANIC_SELECTOR => Decoder::new(payload)
+                .word(0)
+                .ok()
+                .map(|code| RevertReason::Panic(PanicCode::from(*code))),
+            selector => Some(RevertReason::Custom {
+                selector,
+                data: payload.to_vec(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for RevertReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RevertReason::Error(message) => write!(f, "reverted: {}", message),
+            RevertReason::Panic(code) => write!(f, "panicked: {}", code),
+            RevertReason::Custom { selector, .. } => write!(
+                f,
+                "reverted with custom error 0x{}",
+                substreams::Hex(selector)
+            ),
+        }
+    }
+}
+
+/// The code of a `Panic(uint256)` revert, as listed in the Solidity documentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PanicCode {
+    /// `0x00`, generic compiler inserted panic.
+    Generic,
+    /// `0x01`, failed `assert`.
+    Assert,
+    /// `0x11`, arithmetic overflow or underflow outside of an `unchecked` block.
+    ArithmeticOverflow,
+    /// `0x12`, division or modulo by zero.
+    DivisionByZero,
+    /// `0x21`, conversion of a value out of range into an enum.
+    InvalidEnumValue,
+    /// `0x22`, access to an incorrectly encoded storage byte array.
+    InvalidStorageByteArray,
+    /// `0x31`, `pop()` on an empty array.
+    PopEmptyArray,
+    /// `0x32`, array or slice index out of bounds.
+    ArrayOutOfBounds,
+    /// `0x41`, allocation of too much memory or of a too large array.
+    OutOfMemory,
+    /// `0x51`, call of a zero-initialized function variable.
+    UninitializedFunction,
+    /// A code not listed in the Solidity documentation, as the 32 bytes word of the revert
+    /// data since it can be any `uint256`.
+    Unknown([u8; 32]),
+}
+
+impl PanicCode {
+    /// The code found in the `Panic(uint256)` revert data, as a 32 bytes word.
+    pub fn code(&self) -> [u8; 32] {
+        let code: u8 = match self {
+            PanicCode::Generic => 0x00,
+            PanicCode::Assert => 0x01,
+            PanicCode::ArithmeticOverflow => 0x11,
+            PanicCode::DivisionByZero => 0x12,
+            PanicCode::InvalidEnumValue => 0x21,
+            PanicCode::InvalidStorageByteArray => 0x22,
+            PanicCode::PopEmptyArray => 0x31,
+            PanicCode::ArrayOutOfBounds => 0x32,
+            PanicCode::OutOfMemory => 0x41,
+            PanicCode::UninitializedFunction => 0x51,
+            PanicCode::Unknown(code) => return *code,
+        };
+
+        encode_uint(code)
+    }
+}
+
+impl From<u64> for PanicCode {
+    fn from(code: u64) -> Self {
+        PanicCode::from(encode_uint(code))
+    }
+}
+
+impl From<[u8; 32]> for PanicCode {
+    fn from(code: [u8; 32]) -> Self {
+        // Listed codes fit in the last byte of the word
+        if code[..31].iter().any(|byte| *byte != 0) {
+            return PanicCode::Unknown(code);
+        }
+
+        match code[31] {
+            0x00 => PanicCode::Generic,
+            0x01 => PanicCode::Assert,
+            0x11 => PanicCode::ArithmeticOverflow,
+            0x12 => PanicCode::DivisionByZero,
+            0x21 => PanicCode::InvalidEnumValue,
+            0x22 => PanicCode::InvalidStorageByteArray,
+            0x31 => PanicCode::PopEmptyArray,
+            0x32 => PanicCode::ArrayOutOfBounds,
+            0x41 => PanicCode::OutOfMemory,
+            0x51 => PanicCode::UninitializedFunction,
+            _ => PanicCode::Unknown(code),
+        }
+    }
+}
+
+impl fmt::Display for PanicCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            PanicCode::Generic => "generic panic",
+            PanicCode::Assert => "assertion failed",
+            PanicCode::ArithmeticOverflow => "arithmetic overflow",
+            PanicCode::DivisionByZero => "division by zero",
+            PanicCode::InvalidEnumValue => "invalid enum value",
+            PanicCode::InvalidStorageByteArray => "invalid storage byte array",
+            PanicCode::PopEmptyArray => "pop on empty array",
+            PanicCode::ArrayOutOfBounds => "array index out of bounds",
+            PanicCode::OutOfMemory => "out of memory",
+            PanicCode::UninitializedFunction => "uninitialized function",
+            PanicCode::Unknown(_) => "unknown panic",
+        };
+
+        let code = self.code();
+        let start = code.iter().position(|byte| *byte != 0).unwrap_or(31);
+        write!(f, "{} (0x{})", description, substreams::Hex(&code[start..]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use substreams::hex;
+
+    use super::{PanicCode, RevertReason};
+
+    #[test]
+    fn it_decode_error_string() {
+        // require(false, "Ownable: caller is not the owner")
+        let data = hex!("08c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000204f776e61626c653a2063616c6c6572206973206e6f7420746865206f776e6572");
+
+        let reason = RevertReason::decode(&data).unwrap();
+        assert_eq!(
+            reason,
+            RevertReason::Error("Ownable: caller is not the owner".to_string())
+        );
+        assert_eq!(
+            reason.to_string(),
+            "reverted: Ownable: caller is not the owner"
+        );
+    }
+
+    #[test]
+    fn it_decode_panic() {
+        let data = hex!("4e487b710000000000000000000000000000000000000000000000000000000000000011");
+
+        let reason = RevertReason::decode(&data).unwrap();
+        assert_eq!(reason, RevertReason::Panic(PanicCode::ArithmeticOverflow));
+        assert_eq!(reason.to_string(), "panicked: arithmetic overflow (0x11)");
+
+        let data = hex!("4e487b7100000000000000000000000000000000000000000000000000000000000000ff");
+        assert_eq!(
+            RevertReason::decode(&data),
+            Some(RevertReason::Panic(PanicCode::Unknown(hex!(
+                "00000000000000000000000000000000000000000000000000000000000000ff"
+            ))))
+        );
+
+        // Any `uint256` is a valid code, even when wider than native integers
+        let data = hex!("4e487b71ff00000000000000000000000000000000000000000000000000000000000001");
+        let reason = RevertReason::decode(&data).unwrap();
+        assert_eq!(
+            reason,
+            RevertReason::Panic(PanicCode::Unknown(hex!(
+                "ff00000000000000000000000000000000000000000000000000000000000001"
+            )))
+        );
+        assert_eq!(
+            reason.to_string(),
+            "panicked: unknown panic (0xff00000000000000000000000000000000000000000000000000000000000001)"
+        );
+        assert_eq!(PanicCode::from(0x12u64), PanicCode::DivisionByZero);
+    }
+
+    #[test]
+    fn it_decode_custom_error() {
+        let data = hex!("cf4791810000000000000000000000000000000000000000000000000000000000000001");
+
+        assert_eq!(
+            RevertReason::decode(&data),
+            Some(RevertReason::Custom {
+                selector: hex!("cf479181"),
+                data: data[4..].to_vec(),
+            })
+        );
+    }
+
+    #[test]
+    fn it_decode_revert_reason_of_calls() {
+        use crate::pb::eth::v2::{Call, TransactionTrace, TransactionTraceStatus};
+
+        let data = hex!("4e487b710000000000000000000000000000000000000000000000000000000000000012");
+        let mut trx = TransactionTrace {
+            status: TransactionTraceStatus::Reverted as i32,
+            calls: vec![Call {
+                status_reverted: true,
+                return_data: data.to_vec(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let expected = Some(RevertReason::Panic(PanicCode::DivisionByZero));
+        assert_eq!(trx.revert_reason(), expected);
+        assert_eq!(trx.calls().next().unwrap().revert_reason(), expected);
+
+        trx.status = TransactionTraceStatus::Succeeded as i32;
+        trx.calls[0].status_reverted = false;
+        assert_eq!(trx.revert_reason(), None);
+        assert_eq!(trx.calls().next().unwrap().revert_reason(), None);
+    }
+
+    #[test]
+    fn it_decode_invalid_revert_data() {
+        assert_eq!(RevertReason::decode(&[]), None);
+        assert_eq!(RevertReason::decode(&hex!("08c379")), None);
+        assert_eq!(RevertReason::decode(&hex!("08c379a000")), None);
+    }
+}
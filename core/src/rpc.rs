@@ -1,9 +1,9 @@
 use crate::pb::eth::rpc::{RpcCall, RpcCalls, RpcResponse, RpcResponses};
-use crate::Function;
+use crate::{DecodeError, Function};
 use substreams::proto;
 
 pub trait RPCDecodable<R> {
-    fn output(data: &[u8]) -> Result<R, String>;
+    fn output(data: &[u8]) -> Result<R, DecodeError>;
 }
 
 pub struct RpcBatch {
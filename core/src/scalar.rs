@@ -1,5 +1,10 @@
-use crate::pb::eth::v2 as pb;
-use substreams::scalar::{BigDecimal, BigInt};
+use std::{fmt, str::FromStr};
+
+use crate::{abi::keccak256, pb::eth::v2 as pb};
+use substreams::{
+    scalar::{BigDecimal, BigInt},
+    Hex,
+};
 
 impl Into<BigInt> for pb::BigInt {
     fn into(self) -> BigInt {
@@ -46,10 +51,247 @@ impl pb::BigInt {
     }
 }
 
+/// Error returned when an [Address] or a [H256] cannot be built from bytes or parsed from an
+/// hexadecimal string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScalarError {
+    /// The value has `actual` bytes (or hexadecimal characters when parsing) instead of
+    /// `expected`.
+    InvalidLength { expected: usize, actual: usize },
+    /// The string contains a character that is not hexadecimal.
+    InvalidCharacter(char),
+}
+
+impl fmt::Display for ScalarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScalarError::InvalidLength { expected, actual } => {
+                write!(f, "invalid length, expected {} but got {}", expected, actual)
+            }
+            ScalarError::InvalidCharacter(c) => write!(f, "invalid hexadecimal character {:?}", c),
+        }
+    }
+}
+
+impl std::error::Error for ScalarError {}
+
+macro_rules! fixed_bytes {
+    ($(#[$attr:meta])* $name:ident, $len:expr) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(pub [u8; $len]);
+
+        impl $name {
+            /// Amount of bytes of the value.
+            pub const LEN: usize = $len;
+
+            /// The value whose bytes are all zero.
+            pub const ZERO: $name = $name([0u8; $len]);
+
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.0
+            }
+
+            pub fn to_vec(&self) -> Vec<u8> {
+                self.0.to_vec()
+            }
+
+            pub fn is_zero(&self) -> bool {
+                self == &Self::ZERO
+            }
+        }
+
+        impl fmt::Display for $name {
+            /// Formats the value as lower case hexadecimal prefixed by `0x`.
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "0x{}", Hex(&self.0))
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}({})", stringify!($name), self)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ScalarError;
+
+            /// Parses the hexadecimal representation of the value, the `0x` prefix is optional
+            /// and the case is ignored.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let digits = s.strip_prefix("0x").unwrap_or(s).as_bytes();
+                if digits.len() != 2 * $len {
+                    return Err(ScalarError::InvalidLength {
+                        expected: 2 * $len,
+                        actual: digits.len(),
+                    });
+                }
+
+                let mut bytes = [0u8; $len];
+                for (byte, pair) in bytes.iter_mut().zip(digits.chunks(2)) {
+                    *byte = (hex_digit(pair[0])? << 4) | hex_digit(pair[1])?;
+                }
+
+                Ok($name(bytes))
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl From<[u8; $len]> for $name {
+            fn from(bytes: [u8; $len]) -> Self {
+                $name(bytes)
+            }
+        }
+
+        impl From<$name> for [u8; $len] {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl From<$name> for Vec<u8> {
+            fn from(value: $name) -> Self {
+                value.0.to_vec()
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = ScalarError;
+
+            fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+                <[u8; $len]>::try_from(bytes)
+                    .map($name)
+                    .map_err(|_| ScalarError::InvalidLength {
+                        expected: $len,
+                        actual: bytes.len(),
+                    })
+            }
+        }
+
+        impl TryFrom<Vec<u8>> for $name {
+            type Error = ScalarError;
+
+            fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+                $name::try_from(bytes.as_slice())
+            }
+        }
+
+        impl PartialEq<[u8]> for $name {
+            fn eq(&self, other: &[u8]) -> bool {
+                self.0[..] == *other
+            }
+        }
+
+        impl PartialEq<&[u8]> for $name {
+            fn eq(&self, other: &&[u8]) -> bool {
+                self.0[..] == **other
+            }
+        }
+
+        impl PartialEq<Vec<u8>> for $name {
+            fn eq(&self, other: &Vec<u8>) -> bool {
+                self.0[..] == other[..]
+            }
+        }
+
+        impl PartialEq<$name> for [u8] {
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.0[..]
+            }
+        }
+
+        impl PartialEq<$name> for &[u8] {
+            fn eq(&self, other: &$name) -> bool {
+                **self == other.0[..]
+            }
+        }
+
+        impl PartialEq<$name> for Vec<u8> {
+            fn eq(&self, other: &$name) -> bool {
+                self[..] == other.0[..]
+            }
+        }
+    };
+}
+
+fixed_bytes!(
+    /// A 20 bytes Ethereum address, compares equal to the raw bytes found in blocks like
+    /// `LogView::address()`.
+    Address,
+    20
+);
+
+fixed_bytes!(
+    /// A 32 bytes value, typically a hash or a `bytes32`.
+    H256,
+    32
+);
+
+fixed_bytes!(
+    /// The topic of an indexed event parameter of type `string`, `bytes`, array or tuple, logs
+    /// only record the Keccak-256 hash of such values.
+    TopicHash,
+    32
+);
+
+impl TopicHash {
+    /// Whether the topic is the hash of `value`, the bytes of a `string` or `bytes` parameter.
+    ///
+    /// For an array or tuple parameter, `value` is the in place encoding Solidity hashes: its
+    /// elements padded to 32 bytes and concatenated without offsets nor lengths, e.g. the
+    /// words of [crate::abi::encode_uint] for an `uint256[]`.
+    pub fn matches<T: AsRef<[u8]>>(&self, value: T) -> bool {
+        keccak256(value) == self.0
+    }
+}
+
+impl Address {
+    /// Formats the address with the mixed case checksum of EIP-55, prefixed by `0x`.
+    pub fn to_checksum(&self) -> String {
+        let lower = Hex(&self.0).to_string();
+        let hash = keccak256(&lower);
+
+        let checksummed: String = lower
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+                if nibble >= 8 {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            })
+            .collect();
+
+        format!("0x{}", checksummed)
+    }
+}
+
+pub(crate) fn hex_digit(c: u8) -> Result<u8, ScalarError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(ScalarError::InvalidCharacter(c as char)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::pb::eth::v2 as pb;
-    use crate::scalar::{to_option_bigint, to_option_decimal, to_option_decimal_with_decimal};
+    use crate::scalar::{
+        to_option_bigint, to_option_decimal, to_option_decimal_with_decimal, Address, ScalarError,
+        TopicHash, H256,
+    };
+    use std::str::FromStr;
+    use substreams::hex;
 
     #[test]
     fn zero_into_bigint() {
@@ -141,6 +383,87 @@ mod tests {
         assert_eq!(to_option_decimal(v), None);
     }
 
+    #[test]
+    fn address_from_str_and_display() {
+        let address = Address::from_str("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap();
+        assert_eq!(address, Address(hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed")));
+        assert_eq!(
+            address.to_string(),
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+        );
+        assert_eq!(
+            address.to_checksum(),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+        assert_eq!(
+            Address::from_str("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+            Ok(address)
+        );
+
+        assert_eq!(
+            Address::from_str("0x5aae"),
+            Err(ScalarError::InvalidLength {
+                expected: 40,
+                actual: 4
+            })
+        );
+        assert_eq!(
+            Address::from_str("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaeg"),
+            Err(ScalarError::InvalidCharacter('g'))
+        );
+    }
+
+    #[test]
+    fn address_compares_with_bytes() {
+        let bytes = hex!("fb6916095ca1df60bb79ce92ce3ea74c37c5d359").to_vec();
+        let address = Address::try_from(bytes.as_slice()).unwrap();
+
+        assert!(address == bytes);
+        assert!(bytes.as_slice() == address);
+        assert_eq!(Vec::from(address), bytes);
+        assert_eq!(
+            Address::try_from(&bytes[1..]),
+            Err(ScalarError::InvalidLength {
+                expected: 20,
+                actual: 19
+            })
+        );
+        assert!(Address::ZERO.is_zero());
+    }
+
+    #[test]
+    fn h256_from_str_and_display() {
+        let value = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+        let hash = H256::from_str(value).unwrap();
+
+        assert_eq!(
+            hash,
+            H256(hex!(
+                "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+            ))
+        );
+        assert_eq!(hash.to_string(), value);
+        assert_eq!(format!("{:?}", hash), format!("H256({})", value));
+    }
+
+    #[test]
+    fn topic_hash_matches() {
+        use crate::abi::encode_uint;
+
+        let hash = TopicHash(hex!(
+            "1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8"
+        ));
+        assert!(hash.matches("hello"));
+        assert!(!hash.matches("world"));
+
+        let values = [encode_uint(1u8), encode_uint(2u8)].concat();
+        let hash = TopicHash(hex!(
+            "e90b7bceb6e7df5418fb78d8ee546e97c83a08bbccc01a0644d599ccd2a7c2e0"
+        ));
+        assert!(hash.matches(&values));
+        assert!(!hash.matches(&values[..32]));
+    }
+
     pub fn new_pb_bigint(value: u32) -> pb::BigInt {
         let v = num_bigint::BigInt::new(num_bigint::Sign::Plus, vec![value]);
         let (_, bytes) = v.to_bytes_be();
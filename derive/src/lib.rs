@@ -24,10 +24,16 @@ pub fn ethabi_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 
 fn impl_ethabi_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::TokenStream> {
     let options = get_options(&ast.attrs, "ethabi_contract_options")?;
-    let path = get_option(&options, "path")?;
+    let signatures = get_repeated_option(&options, "signature")?;
 
-    substreams_ethereum_abigen::generate_abi_code(path)
-        .map_err(|e| Error::Other(Cow::Owned(format!("{}", e))))
+    let code = if signatures.is_empty() {
+        let path = get_option(&options, "path")?;
+        substreams_ethereum_abigen::generate_abi_code(path)
+    } else {
+        substreams_ethereum_abigen::generate_abi_code_from_signatures(&signatures)
+    };
+
+    code.map_err(|e| Error::Other(Cow::Owned(format!("{}", e))))
 }
 
 fn get_options(attrs: &[syn::Attribute], name: &str) -> Result<Vec<syn::NestedMeta>> {
@@ -55,6 +61,18 @@ fn get_option(options: &[syn::NestedMeta], name: &str) -> Result<String> {
     str_value_of_meta_item(item, name)
 }
 
+fn get_repeated_option(options: &[syn::NestedMeta], name: &str) -> Result<Vec<String>> {
+    options
+        .iter()
+        .flat_map(|nested| match *nested {
+            syn::NestedMeta::Meta(ref meta) => Some(meta),
+            _ => None,
+        })
+        .filter(|meta| meta.path().is_ident(name))
+        .map(|meta| str_value_of_meta_item(meta, name))
+        .collect()
+}
+
 fn str_value_of_meta_item(item: &syn::Meta, name: &str) -> Result<String> {
     if let syn::Meta::NameValue(ref name_value) = *item {
         if let syn::Lit::Str(ref value) = name_value.lit {